@@ -1,5 +1,12 @@
 # German
 
+# Numbers
+
+decimal_separator = ,
+# Separates the number from the unit symbol: a no-break space (DIN 5008).
+unit_separator = {"\u00A0"}
+
+
 # SI Prefixes
 
 quecto = Quekto
@@ -37,11 +44,48 @@ kilogram = Kilogramm
 meter = Meter
 mol = Mol
 second = Sekunde
+celsius = Grad Celsius
+fahrenheit = Grad Fahrenheit
 gram = Gramm
 tonne = Tonne
+pound = Pfund
+ounce = Unze
+stone = Stone
 astronomical_unit = Astronomische Einheit
 lightyear = Lichtjahr
 parsec = Parsec
+inch = Zoll
+foot = Fuß
+yard = Yard
+mile = Meile
+minute = Minute
+hour = Stunde
+day = Tag
+year = Jahr
 pascal = Pascal
 bar = Bar
+atmosphere = Atmosphäre
+millimeter_mercury = Millimeter Quecksilbersäule
+torr = Torr
+psi = Pfund pro Quadratzoll
 sievert = Sievert
+gray = Gray
+becquerel = Becquerel
+lumen = Lumen
+lux = Lux
+volt = Volt
+watt = Watt
+joule = Joule
+electronvolt = Elektronenvolt
+calorie = Kalorie
+coulomb = Coulomb
+radian = Radiant
+degree = Grad
+gradian = Gon
+liter = Liter
+hertz = Hertz
+bit = Bit
+byte = Byte
+one = Eins
+percent = Prozent
+per_mille = Promille
@@ -1,5 +1,12 @@
 # American English
 
+# Numbers
+
+decimal_separator = .
+# Separates the number from the unit symbol: a normal space.
+unit_separator = {" "}
+
+
 # SI Prefixes
 
 quecto = quecto
@@ -37,11 +44,48 @@ kilogram = kilogram
 meter = meter
 mol = mol
 second = second
+celsius = degree Celsius
+fahrenheit = degree Fahrenheit
 gram = gram
 tonne = tonne
+pound = pound
+ounce = ounce
+stone = stone
 astronomical_unit = astronomical unit
 lightyear = lightyear
 parsec = parsec
+inch = inch
+foot = foot
+yard = yard
+mile = mile
+minute = minute
+hour = hour
+day = day
+year = year
 pascal = pascal
 bar = bar
+atmosphere = atmosphere
+millimeter_mercury = millimeter of mercury
+torr = torr
+psi = pound per square inch
 sievert = sievert
+gray = gray
+becquerel = becquerel
+lumen = lumen
+lux = lux
+volt = volt
+watt = watt
+joule = joule
+electronvolt = electronvolt
+calorie = calorie
+coulomb = coulomb
+radian = radian
+degree = degree
+gradian = gradian
+liter = liter
+hertz = hertz
+bit = bit
+byte = byte
+one = one
+percent = percent
+per_mille = per mille
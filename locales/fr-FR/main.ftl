@@ -0,0 +1,91 @@
+# French
+
+# Numbers
+
+decimal_separator = ,
+# Separates the number from the unit symbol: a narrow no-break space.
+unit_separator = {"\u202F"}
+
+
+# SI Prefixes
+
+quecto = quecto
+ronto = ronto
+yocto = yocto
+zepto = zepto
+atto = atto
+femto = femto
+pico = pico
+nano = nano
+micro = micro
+milli = milli
+centi = centi
+deci = déci
+deca = déca
+hecto = hecto
+kilo = kilo
+mega = méga
+giga = giga
+tera = téra
+peta = péta
+exa = exa
+zetta = zetta
+yotta = yotta
+ronna = ronna
+quetta = quetta
+
+
+# SI Units
+
+ampere = ampère
+candela = candela
+kelvin = kelvin
+kilogram = kilogramme
+meter = mètre
+mol = mole
+second = seconde
+celsius = degré Celsius
+fahrenheit = degré Fahrenheit
+gram = gramme
+tonne = tonne
+pound = livre
+ounce = once
+stone = stone
+astronomical_unit = unité astronomique
+lightyear = année-lumière
+parsec = parsec
+inch = pouce
+foot = pied
+yard = yard
+mile = mile
+minute = minute
+hour = heure
+day = jour
+year = année
+pascal = pascal
+bar = bar
+atmosphere = atmosphère
+millimeter_mercury = millimètre de mercure
+torr = torr
+psi = livre par pouce carré
+sievert = sievert
+gray = gray
+becquerel = becquerel
+lumen = lumen
+lux = lux
+volt = volt
+watt = watt
+joule = joule
+electronvolt = électronvolt
+calorie = calorie
+coulomb = coulomb
+radian = radian
+degree = degré
+gradian = grade
+liter = litre
+hertz = hertz
+bit = bit
+byte = octet
+one = un
+percent = pour cent
+per_mille = pour mille
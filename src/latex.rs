@@ -12,6 +12,7 @@ use std::fmt;
 #[cfg( feature = "i18n" )] use unic_langid::LanguageIdentifier;
 
 #[cfg( feature = "i18n" )] use crate::DisplayLocale;
+use crate::Unit;
 
 
 
@@ -62,6 +63,25 @@ pub trait LatexSym: Latex {
 
 
 
+//=============================================================================
+// Enums
+
+
+/// Representing the notation of numbers in LaTeX commands generated by `to_latex`.
+#[derive( Clone, Copy, PartialEq, Eq, Default, Debug )]
+pub enum Notation {
+	/// Using the prefix as part of the unit, e.g. `\qty{99}{\milli\ampere}`.
+	#[default]
+	Prefixed,
+	/// Using exponents of ten that are a multiple of 3 instead of the prefix, e.g. `\qty{99e-3}{\ampere}`.
+	Engineering,
+	/// Using exponents of ten with a mantissa between 1 and 10 instead of the prefix, e.g. `\qty{9.9e-2}{\ampere}`.
+	Scientific,
+}
+
+
+
+
 //=============================================================================
 // Structs
 
@@ -71,6 +91,12 @@ pub trait LatexSym: Latex {
 pub struct TexOptions {
 	pub drop_zero_decimal: Option<bool>,
 	pub minimum_decimal_digits: Option<u8>,
+	pub declare_units: Option<bool>,
+	pub notation: Option<Notation>,
+	/// The symbol between the mantissa and the power of ten, e.g. `\cdot` resulting in "9.9·10⁻³". Is passed to `{siunitx}` as `exponent-product` option.
+	pub exponent_product: Option<String>,
+	/// Using the command names of `{siunitx}` version 2 like `\SI` instead of `\qty`.
+	pub legacy_commands: Option<bool>,
 }
 
 impl TexOptions {
@@ -93,13 +119,100 @@ impl TexOptions {
 		self.minimum_decimal_digits = Some( digits );
 		self
 	}
+
+	pub fn declare_units( mut self, sw: bool ) -> Self {
+		self.declare_units = Some( sw );
+		self
+	}
+
+	pub fn notation( mut self, notation: Notation ) -> Self {
+		self.notation = Some( notation );
+		self
+	}
+
+	pub fn exponent_product( mut self, product: &str ) -> Self {
+		self.exponent_product = Some( product.to_string() );
+		self
+	}
+
+	pub fn legacy_commands( mut self, sw: bool ) -> Self {
+		self.legacy_commands = Some( sw );
+		self
+	}
+
+	/// Returns the `{siunitx}` command printing a quantity: `\SI` if `legacy_commands` is active, `\qty` otherwise.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::TexOptions;
+	/// assert_eq!( TexOptions::new().qty_command(), r"\qty" );
+	/// assert_eq!( TexOptions::new().legacy_commands( true ).qty_command(), r"\SI" );
+	/// ```
+	pub fn qty_command( &self ) -> &'static str {
+		match self.legacy_commands {
+			Some( true ) => r"\SI",
+			_ => r"\qty",
+		}
+	}
+
+	/// Returns the LaTeX preamble code required to compile the output of `to_latex` and `to_latex_sym`.
+	///
+	/// If `declare_units` is active, this contains a `\DeclareSIUnit` line for every unit whose command is not provided by the `{siunitx}` package (like `\lightyear` or `\parsec`). Otherwise an empty string is returned.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::TexOptions;
+	/// assert!( TexOptions::new().declare_units( true ).preamble().contains( r"\DeclareSIUnit{\parsec}{pc}" ) );
+	/// assert_eq!( TexOptions::new().preamble(), "" );
+	/// ```
+	pub fn preamble( &self ) -> String {
+		match self.declare_units {
+			Some( x ) if x => Unit::LATEX_UNDECLARED.iter()
+				.filter_map( |x| x.latex_declaration() )
+				.collect::<Vec<String>>()
+				.join( "\n" ),
+			_ => "".to_string(),
+		}
+	}
+
+	/// Returns `mantissa` formatted as number within LaTeX commands. `minimum_decimal_digits` is applied first, afterwards a decimal part consisting only of zeros is removed if `drop_zero_decimal` is active.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::TexOptions;
+	/// let options = TexOptions::new().minimum_decimal_digits( 2 ).drop_zero_decimal( true );
+	///
+	/// assert_eq!( options.format_mantissa( 9.0 ), "9" );
+	/// assert_eq!( options.format_mantissa( 9.5 ), "9.50" );
+	/// assert_eq!( TexOptions::new().minimum_decimal_digits( 2 ).format_mantissa( 9.0 ), "9.00" );
+	/// ```
+	pub fn format_mantissa( &self, mantissa: f64 ) -> String {
+		let res = match self.minimum_decimal_digits {
+			Some( x ) => format!( "{:.1$}", mantissa, x as usize ),
+			None => mantissa.to_string(),
+		};
+
+		match ( self.drop_zero_decimal, res.split_once( '.' ) ) {
+			( Some( true ), Some( ( int, dec ) ) ) if dec.chars().all( |x| x == '0' ) => int.to_string(),
+			_ => res,
+		}
+	}
 }
 
 impl fmt::Display for TexOptions {
 	fn fmt( &self, f: &mut fmt::Formatter ) -> fmt::Result {
-		match self.drop_zero_decimal {
-			Some( x ) if x => write!( f, "[drop-zero-decimal]" ),
-			_ => write!( f, "" ),
+		let mut opts = Vec::new();
+
+		if let Some( true ) = self.drop_zero_decimal {
+			opts.push( "drop-zero-decimal".to_string() );
+		}
+		if let Some( x ) = &self.exponent_product {
+			opts.push( format!( "exponent-product={}", x ) );
+		}
+
+		match opts.is_empty() {
+			true => write!( f, "" ),
+			false => write!( f, "[{}]", opts.join( "," ) ),
 		}
 	}
 }
@@ -123,6 +236,19 @@ mod tests {
 		};
 		let opts_from_builder = TexOptions::new().drop_zero_decimal( true );
 		assert_eq!( opts, opts_from_builder );
+
+		let opts = TexOptions {
+			notation: Some( Notation::Scientific ),
+			..Default::default()
+		};
+		assert_eq!( opts, TexOptions::new().notation( Notation::Scientific ) );
+
+		let opts = TexOptions {
+			exponent_product: Some( r"\times".to_string() ),
+			legacy_commands: Some( true ),
+			..Default::default()
+		};
+		assert_eq!( opts, TexOptions::new().exponent_product( r"\times" ).legacy_commands( true ) );
 	}
 
 	#[test]
@@ -134,5 +260,48 @@ mod tests {
 				.to_string(),
 			"[drop-zero-decimal]".to_string()
 		);
+		assert_eq!( TexOptions::new().exponent_product( r"\cdot" ).to_string(), r"[exponent-product=\cdot]" );
+		assert_eq!(
+			TexOptions::new()
+				.exponent_product( r"\times" )
+				.drop_zero_decimal( true )
+				.legacy_commands( true )
+				.to_string(),
+			r"[drop-zero-decimal,exponent-product=\times]"
+		);
+		assert_eq!( TexOptions::new().legacy_commands( true ).to_string(), "" );
+	}
+
+	#[test]
+	fn options_format_mantissa() {
+		assert_eq!( TexOptions::new().format_mantissa( 9.0 ), "9" );
+		assert_eq!( TexOptions::new().format_mantissa( 9.25 ), "9.25" );
+		assert_eq!( TexOptions::new().drop_zero_decimal( true ).format_mantissa( 9.25 ), "9.25" );
+
+		let options = TexOptions::new().minimum_decimal_digits( 1 );
+		assert_eq!( options.format_mantissa( 9.0 ), "9.0" );
+		assert_eq!( options.format_mantissa( -9.0 ), "-9.0" );
+
+		let options = TexOptions::new().minimum_decimal_digits( 3 ).drop_zero_decimal( true );
+		assert_eq!( options.format_mantissa( 9.0 ), "9" );
+		assert_eq!( options.format_mantissa( -9.0 ), "-9" );
+		assert_eq!( options.format_mantissa( 9.1 ), "9.100" );
+		assert_eq!( options.format_mantissa( 9.0001 ), "9" );
+
+		let options = TexOptions::new().minimum_decimal_digits( 3 ).drop_zero_decimal( false );
+		assert_eq!( options.format_mantissa( 9.0 ), "9.000" );
+	}
+
+	#[test]
+	fn options_preamble() {
+		assert_eq!( TexOptions::new().preamble(), "".to_string() );
+		assert_eq!( TexOptions::new().declare_units( false ).preamble(), "".to_string() );
+
+		let preamble = TexOptions::new().declare_units( true ).preamble();
+		assert!( preamble.contains( r"\DeclareSIUnit{\lightyear}{ly}" ) );
+		assert!( preamble.contains( r"\DeclareSIUnit{\parsec}{pc}" ) );
+		assert!( preamble.contains( r"\DeclareSIUnit{\mol}{mol}" ) );
+		assert!( preamble.contains( r"\DeclareSIUnit{\fahrenheit}{\degree F}" ) );
+		assert!( !preamble.contains( r"\meter" ) );
 	}
 }
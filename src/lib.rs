@@ -22,27 +22,82 @@
 
 #[cfg( feature = "i18n" )] use std::fmt;
 
+use thiserror::Error;
+
 #[cfg( feature = "i18n" )] use unic_langid::LanguageIdentifier;
 
 mod prefix;
 pub use crate::prefix::PrefixError;
 pub use crate::prefix::Prefix;
+pub use crate::prefix::PrefixSet;
+pub use crate::prefix::BinaryPrefix;
+pub use crate::prefix::RoundMode;
 
 mod number;
+pub use crate::number::NumError;
 pub use crate::number::Num;
+pub use crate::number::NumFormat;
+pub use crate::number::OrderedNum;
+pub use crate::number::{CLEAN_DISPLAY_DECIMALS, display_rounding, set_display_rounding};
 
 mod unit;
-use crate::unit::PhysicalQuantity;
+pub use crate::unit::PhysicalQuantity;
 pub use crate::unit::UnitError;
 pub use crate::unit::Unit;
 
 mod quantity;
+pub use crate::quantity::QtyError;
 pub use crate::quantity::Qty;
+pub use crate::quantity::QtyBuilder;
+pub use crate::quantity::UnitLens;
+pub use crate::quantity::OrderedQty;
+pub use crate::quantity::{group_by_quantity, merge_equal_units, sort_by_base_value};
+#[cfg( feature = "serde" )] pub use crate::quantity::QtyRecord;
+
+#[cfg( feature = "serde" )] pub mod serde_base;
+#[cfg( feature = "serde" )] pub mod serde_sym;
+#[cfg( all( feature = "serde", feature = "tex" ) )] pub mod serde_siunitx;
+
+#[cfg( feature = "postgres" )] mod postgres;
 
 #[cfg( feature = "tex" )] mod latex;
 #[cfg( feature = "tex" )] pub use crate::latex::{Latex, LatexSym};
 #[cfg( all( feature = "i18n", feature = "tex" ) )] pub use crate::latex::LatexLocale;
-#[cfg( feature = "tex" )] pub use crate::latex::TexOptions;
+#[cfg( feature = "tex" )] pub use crate::latex::{Notation, TexOptions};
+
+
+
+
+//=============================================================================
+// Errors
+
+
+/// Representing all errors of this crate to allow chaining operations returning different error types with `?`.
+///
+/// # Example
+/// ```
+/// # use sinum::{Qty, Num, Unit, Prefix, SinumError};
+/// fn to_kilometer( qty: &Qty ) -> Result<Qty, SinumError> {
+///     Ok( qty.to_unit( &Unit::Meter )?.to_prefix( Prefix::Kilo ).shortened()? )
+/// }
+///
+/// assert_eq!( to_kilometer( &Qty::new( 2500.0.into(), &Unit::Meter ) ).unwrap().to_string(), "2.5 km" );
+/// assert!( matches!( to_kilometer( &Qty::new( 1.0.into(), &Unit::Second ) ), Err( SinumError::Unit( _ ) ) ) );
+/// ```
+#[derive( Error, Debug )]
+pub enum SinumError {
+	#[error( transparent )]
+	Prefix( #[from] PrefixError ),
+
+	#[error( transparent )]
+	Num( #[from] NumError ),
+
+	#[error( transparent )]
+	Unit( #[from] UnitError ),
+
+	#[error( transparent )]
+	Qty( #[from] QtyError ),
+}
 
 
 
@@ -82,3 +137,33 @@ fluent_templates::static_loader! {
 		fallback_language: "en-US",
 	};
 }
+
+
+
+
+//=============================================================================
+// Testing
+
+
+#[cfg( test )]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn sinum_error_from() {
+		let err: SinumError = "xyz".parse::<Prefix>().unwrap_err().into();
+		assert!( matches!( err, SinumError::Prefix( PrefixError::TryFromStr( _ ) ) ) );
+
+		let err: SinumError = "xyz".parse::<Num>().unwrap_err().into();
+		assert!( matches!( err, SinumError::Num( NumError::ParseFailure( _ ) ) ) );
+
+		let err: SinumError = Qty::new( 1.0.into(), &Unit::Meter ).to_unit( &Unit::Second ).unwrap_err().into();
+		assert!( matches!( err, SinumError::Unit( UnitError::UnitMismatch( _ ) ) ) );
+
+		let err: SinumError = Qty::try_new_checked( f64::NAN, &Unit::Meter ).unwrap_err().into();
+		assert!( matches!( err, SinumError::Qty( QtyError::NotFinite( _ ) ) ) );
+
+		let err: SinumError = Qty::new( 1e40.into(), &Unit::Meter ).shortened().unwrap_err().into();
+		assert_eq!( err.to_string(), "There is no SI prefix for `39`" );
+	}
+}
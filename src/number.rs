@@ -8,18 +8,56 @@
 
 
 use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+use std::iter::Sum;
 use std::ops::{Add, Sub, Mul, MulAssign, Div, Neg};
 use std::fmt;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU8, Ordering as AtomicOrdering};
 
+#[cfg( feature = "i18n" )] use fluent_templates::Loader;
 #[cfg( feature = "serde" )]
 use serde::{Serialize, Deserialize};
+use thiserror::Error;
+#[cfg( feature = "i18n" )] use unic_langid::LanguageIdentifier;
 
+#[cfg( feature = "i18n" )] use crate::DisplayLocale;
+#[cfg( feature = "i18n" )] use crate::LOCALES;
 use crate::PrefixError;
 use crate::{Prefix, Qty, Unit};
 
 
 
 
+//=============================================================================
+// Errors
+
+
+#[derive( Error, Debug )]
+pub enum NumError {
+	#[error( "Cannot convert to Num from: `{0}`" )]
+	ParseFailure( String ),
+}
+
+
+
+
+//=============================================================================
+// Constants
+
+
+/// The number of decimal places the mantissa is rounded to in "clean display" mode. This avoids output like "0.100000000012" at the cost of precision.
+pub const CLEAN_DISPLAY_DECIMALS: u8 = 6;
+
+/// Marks `DISPLAY_DECIMALS` as not rounding at all.
+const NO_ROUNDING: u8 = u8::MAX;
+
+/// The number of decimal places the mantissa is rounded to by the `Display` implementations of `Num` and `Qty`.
+static DISPLAY_DECIMALS: AtomicU8 = AtomicU8::new( NO_ROUNDING );
+
+
+
+
 //=============================================================================
 // Structs
 
@@ -65,6 +103,24 @@ impl Num {
 		}
 	}
 
+	/// Returns a `NumFormat` writing `self` with the mantissa rounded to exactly `digits` decimal places (see `to_string_precision()`). This allows for formatting many numbers with the same precision, e.g. in the columns of a table, without changing the crate-wide `Display` behavior.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Num, Prefix};
+	/// let x = Num::new( 9.99912 ).with_prefix( Prefix::Kilo );
+	///
+	/// assert_eq!( x.with_precision( 3 ).to_string(), "9.999 k" );
+	/// assert_eq!( format!( "{:>9}", x.with_precision( 2 ) ), "  10.00 k" );
+	/// assert_eq!( x.to_string(), "9.99912 k" );
+	/// ```
+	pub fn with_precision( self, digits: u8 ) -> NumFormat {
+		NumFormat {
+			num: self,
+			precision: digits,
+		}
+	}
+
 	/// Creates a new `Qty` from `self` by applying `unit`.
 	///
 	/// # Example
@@ -103,10 +159,10 @@ impl Num {
 	/// Creates a new `Num` from `self` with a reduced numbers of digits of the mantissa (see `mantissa()`) required to represent the number:
 	///
 	/// * No more than 3 digits in front of the decimal point.
-	///     (1234 → 1.234 k)
+	///   (1234 → 1.234 k)
 	///
 	/// * No zero in front of the decimal point.
-	///     (0.001 → 1.0 m)
+	///   (0.001 → 1.0 m)
 	///
 	/// # Example
 	/// ```
@@ -145,6 +201,71 @@ impl Num {
 		Ok( self.to_prefix( prefix_new ) )
 	}
 
+	/// Creates a new `Num` from `self` with the prefix chosen so that the absolute value of the mantissa lies between 1 (inclusive) and 1000 (exclusive), like `shortened()`. In contrast to `shortened()` this never fails: Values beyond the range of the SI prefixes use `Prefix::Quetta` or `Prefix::Quecto` respectively. Negative numbers are normalized by their absolute value.
+	///
+	/// A zero results in `Num::new( 0.0 )`. Infinite and NaN values are returned without prefix.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Num, Prefix};
+	/// assert_eq!( Num::new( 1_000_000.0 ).normalized().to_string(), "1 M" );
+	/// assert_eq!( Num::new( 0.0005 ).normalized().to_string(), "500 µ" );
+	/// assert_eq!( Num::new( 1e40 ).normalized().prefix(), Prefix::Quetta );
+	/// ```
+	pub fn normalized( self ) -> Self {
+		let value = self.as_f64();
+		if value == 0.0 || !value.is_finite() {
+			return Self::new( value );
+		}
+
+		let exp = ( value.abs().log10() / 3.0 ).floor() * 3.0;
+		let exp = exp.clamp( Prefix::MIN_EXP as f64, Prefix::MAX_EXP as f64 ) as i8;
+
+		// Every multiple of 3 within the range of the prefixes has a prefix.
+		self.to_prefix( Prefix::try_from( exp ).unwrap() )
+	}
+
+	/// Creates a new `Num` from `self` with the mantissa rounded to the nearest integer if it deviates from this integer by no more than `tolerance`. Otherwise `self` is returned unchanged. The prefix is kept in any case.
+	///
+	/// This is meant to clean up floating point errors accumulated by arithmetic operations.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Num, Prefix};
+	/// let num = Num::new( 2.9999999 ).with_prefix( Prefix::Kilo );
+	///
+	/// assert_eq!( num.snap_to_int_mantissa( 1e-6 ), Num::new( 3.0 ).with_prefix( Prefix::Kilo ) );
+	/// assert_eq!( num.snap_to_int_mantissa( 1e-9 ), num );
+	/// ```
+	pub fn snap_to_int_mantissa( self, tolerance: f64 ) -> Self {
+		let rounded = self.mantissa.round();
+
+		if ( self.mantissa - rounded ).abs() <= tolerance {
+			return Self {
+				mantissa: rounded,
+				prefix: self.prefix,
+			};
+		}
+
+		self
+	}
+
+	/// Creates a new `Num` from `self` rounded to `figures` significant figures first and shortened (see `shortened()`) afterwards. This way, rounding up to the next prefix is possible (999.96 → 1 k at 2 significant figures) whereas `shortened()` alone would keep the prefix.
+	///
+	/// A `figures` of zero is treated like 1.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Num, Prefix};
+	/// assert_eq!( Num::new( 999.96 ).round_then_shorten( 2 ).unwrap(), Num::new( 1.0 ).with_prefix( Prefix::Kilo ) );
+	/// assert_eq!( Num::new( 999.96 ).round_then_shorten( 2 ).unwrap().to_string(), "1 k" );
+	/// assert_eq!( Num::new( 999.96 ).round_then_shorten( 4 ).unwrap().to_string(), "1 k" );
+	/// assert_eq!( Num::new( 999.96 ).round_then_shorten( 5 ).unwrap().to_string(), "999.96" );
+	/// ```
+	pub fn round_then_shorten( self, figures: u8 ) -> Result<Self, PrefixError> {
+		Self::new( round_significant( self.as_f64(), figures ) ).shortened()
+	}
+
 	/// Returns the mantissa of the `Num`. The Mantissa is the number displayed before the prefix.
 	///
 	/// # Example
@@ -184,6 +305,45 @@ impl Num {
 		self.mantissa * self.prefix.as_f64()
 	}
 
+	/// Returns `true` if the numeric value of `self` is NaN.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Num, Prefix};
+	/// assert!( ( Num::new( 0.0 ) / 0.0 ).is_nan() );
+	/// assert!( !Num::new( 1.0 ).with_prefix( Prefix::Kilo ).is_nan() );
+	/// ```
+	pub fn is_nan( &self ) -> bool {
+		self.as_f64().is_nan()
+	}
+
+	/// Returns `true` if the numeric value of `self` is positive or negative infinity.
+	///
+	/// Note that a prefix might push a finite mantissa beyond the range of `f64`.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Num, Prefix};
+	/// assert!( ( Num::new( 1.0 ) / 0.0 ).is_infinite() );
+	/// assert!( Num::new( 1e300 ).with_prefix( Prefix::Quetta ).is_infinite() );
+	/// assert!( !Num::new( 1.0 ).is_infinite() );
+	/// ```
+	pub fn is_infinite( &self ) -> bool {
+		self.as_f64().is_infinite()
+	}
+
+	/// Returns `true` if the numeric value of `self` is neither infinite nor NaN.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Num, Prefix};
+	/// assert!( Num::new( 1.0 ).with_prefix( Prefix::Kilo ).is_finite() );
+	/// assert!( !( Num::new( 1.0 ) / 0.0 ).is_finite() );
+	/// ```
+	pub fn is_finite( &self ) -> bool {
+		self.as_f64().is_finite()
+	}
+
 	/// Computes the absolute value of `self`.
 	///
 	/// # Example
@@ -235,6 +395,94 @@ impl Num {
 		Self::new( val ).to_prefix( self.prefix() )
 	}
 
+	/// Returns the nearest integer to the mantissa, keeping the prefix. Half-way cases are rounded away from zero, like `f64::round()`.
+	///
+	/// The mantissa is rounded at the current prefix, not the numeric value returned by `as_f64()`: 1.7 k is rounded to 2 k. Use `to_prefix()` first to round at another prefix.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Num, Prefix};
+	/// assert_eq!( Num::new( 1.7 ).with_prefix( Prefix::Kilo ).round(), Num::new( 2.0 ).with_prefix( Prefix::Kilo ) );
+	/// assert_eq!( Num::new( -1.5 ).with_prefix( Prefix::Kilo ).round(), Num::new( -2.0 ).with_prefix( Prefix::Kilo ) );
+	/// ```
+	pub fn round( self ) -> Self {
+		Self {
+			mantissa: self.mantissa.round(),
+			prefix: self.prefix,
+		}
+	}
+
+	/// Returns the largest integer less than or equal to the mantissa, keeping the prefix.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Num, Prefix};
+	/// assert_eq!( Num::new( 1.7 ).with_prefix( Prefix::Kilo ).floor(), Num::new( 1.0 ).with_prefix( Prefix::Kilo ) );
+	/// assert_eq!( Num::new( -1.2 ).with_prefix( Prefix::Kilo ).floor(), Num::new( -2.0 ).with_prefix( Prefix::Kilo ) );
+	/// ```
+	pub fn floor( self ) -> Self {
+		Self {
+			mantissa: self.mantissa.floor(),
+			prefix: self.prefix,
+		}
+	}
+
+	/// Returns the smallest integer greater than or equal to the mantissa, keeping the prefix.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Num, Prefix};
+	/// assert_eq!( Num::new( 1.2 ).with_prefix( Prefix::Kilo ).ceil(), Num::new( 2.0 ).with_prefix( Prefix::Kilo ) );
+	/// assert_eq!( Num::new( -1.7 ).with_prefix( Prefix::Kilo ).ceil(), Num::new( -1.0 ).with_prefix( Prefix::Kilo ) );
+	/// ```
+	pub fn ceil( self ) -> Self {
+		Self {
+			mantissa: self.mantissa.ceil(),
+			prefix: self.prefix,
+		}
+	}
+
+	/// Returns the integer part of the mantissa, keeping the prefix. This rounds towards zero.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Num, Prefix};
+	/// assert_eq!( Num::new( 1.7 ).with_prefix( Prefix::Kilo ).trunc(), Num::new( 1.0 ).with_prefix( Prefix::Kilo ) );
+	/// assert_eq!( Num::new( -1.7 ).with_prefix( Prefix::Kilo ).trunc(), Num::new( -1.0 ).with_prefix( Prefix::Kilo ) );
+	/// ```
+	pub fn trunc( self ) -> Self {
+		Self {
+			mantissa: self.mantissa.trunc(),
+			prefix: self.prefix,
+		}
+	}
+
+	/// Restricts the number to the interval [`min`, `max`], comparing the numeric values. If the number lies outside of the interval, the respective bound is returned using the prefix of `self`.
+	///
+	/// # Panics
+	/// Panics if `min` > `max`, `min` is NaN, or `max` is NaN. This mirrors `f64::clamp()`.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Num, Prefix};
+	/// let min = Num::new( 1.0 ).with_prefix( Prefix::Kilo );
+	/// let max = Num::new( 2.0 ).with_prefix( Prefix::Kilo );
+	///
+	/// assert_eq!( Num::new( 1500.0 ).clamp( min, max ), Num::new( 1500.0 ) );
+	/// assert_eq!( Num::new( 2500.0 ).clamp( min, max ), Num::new( 2000.0 ) );
+	/// assert_eq!( Num::new( 2500.0 ).clamp( min, max ).prefix(), Prefix::Nothing );
+	/// ```
+	pub fn clamp( self, min: Self, max: Self ) -> Self {
+		let ( val_min, val_max ) = ( min.as_f64(), max.as_f64() );
+		assert!( val_min <= val_max, "min > max, or either was NaN. min = {:?}, max = {:?}", min, max );
+
+		match self.as_f64() {
+			x if x < val_min => min.to_prefix( self.prefix ),
+			x if x > val_max => max.to_prefix( self.prefix ),
+			_ => self,
+		}
+	}
+
 	/// Returns a string representation of the number with engineering notation.
 	/// Engineering notation is similar to scientific notation (using exponents of ten) but the exponents are always a multiple of 3.
 	///
@@ -246,11 +494,59 @@ impl Num {
 	/// assert_eq!( x.to_string_eng(), "2×10^-3" );
 	/// ```
 	pub fn to_string_eng( &self ) -> String {
+		if let Some( x ) = to_string_non_finite( self.mantissa ) {
+			return x.to_string();
+		}
+
 		match self.prefix {
 			Prefix::Nothing => self.mantissa.to_string(),
 			_ => format!( "{}×10^{}", self.mantissa, self.prefix.exp() )
 		}
 	}
+
+	/// Returns a string representation of the number with the mantissa rounded to exactly `digits` decimal places. Other than `to_string_rounded()`, trailing zeros are kept to allow for aligned columns in tables.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Num, Prefix};
+	/// assert_eq!( Num::new( 1.23456789 ).to_string_precision( 3 ), "1.235" );
+	/// assert_eq!( Num::new( 9.99912 ).with_prefix( Prefix::Kilo ).to_string_precision( 3 ), "9.999 k" );
+	/// assert_eq!( Num::new( 2.5 ).to_string_precision( 2 ), "2.50" );
+	/// ```
+	pub fn to_string_precision( &self, digits: u8 ) -> String {
+		if let Some( x ) = to_string_non_finite( self.mantissa ) {
+			return x.to_string();
+		}
+
+		match self.prefix {
+			Prefix::Nothing => format!( "{:.*}", digits as usize, self.mantissa ),
+			_ => format!( "{:.*} {}", digits as usize, self.mantissa, self.prefix.to_string_sym() )
+		}
+	}
+
+	/// Returns a string representation of the number like `to_string()` but with the mantissa rounded to at most `decimals` decimal places, overriding the crate-wide setting of `set_display_rounding()`. If `decimals` is `None`, the mantissa is not rounded at all.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Num, Prefix, CLEAN_DISPLAY_DECIMALS};
+	/// let x = Num::new( 1.23456789 ).with_prefix( Prefix::Kilo );
+	///
+	/// assert_eq!( x.to_string_rounded( None ), "1.23456789 k" );
+	/// assert_eq!( x.to_string_rounded( Some( CLEAN_DISPLAY_DECIMALS ) ), "1.234568 k" );
+	/// assert_eq!( Num::new( 2.5 ).to_string_rounded( Some( 3 ) ), "2.5" );
+	/// ```
+	pub fn to_string_rounded( &self, decimals: Option<u8> ) -> String {
+		if let Some( x ) = to_string_non_finite( self.mantissa ) {
+			return x.to_string();
+		}
+
+		let mantissa = round_decimals( self.mantissa, decimals );
+
+		match self.prefix {
+			Prefix::Nothing => format!( "{}", mantissa ),
+			_ => format!( "{} {}", mantissa, self.prefix.to_string_sym() )
+		}
+	}
 }
 
 impl PartialEq for Num {
@@ -571,6 +867,102 @@ impl Neg for Num {
 	}
 }
 
+impl Add<&Num> for &Num {
+	type Output = Num;
+
+	/// The addition operator `+` for references, without consuming the operands. Behaves identical to adding owned `Num`s.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Num, Prefix};
+	/// let a = Num::new( 1.0 ).with_prefix( Prefix::Kilo );
+	/// let b = Num::new( 500.0 );
+	///
+	/// assert_eq!( &a + &b, Num::new( 1.5 ).with_prefix( Prefix::Kilo ) );
+	/// assert_eq!( -&a, Num::new( -1000.0 ) );
+	/// ```
+	fn add( self, other: &Num ) -> Self::Output {
+		*self + *other
+	}
+}
+
+impl Sub<&Num> for &Num {
+	type Output = Num;
+
+	/// The subtraction operator `-` for references, without consuming the operands. Behaves identical to subtracting owned `Num`s.
+	fn sub( self, other: &Num ) -> Self::Output {
+		*self - *other
+	}
+}
+
+impl Mul<&Num> for &Num {
+	type Output = Num;
+
+	/// The multiplication operator `*` for references, without consuming the operands. Behaves identical to multiplying owned `Num`s.
+	fn mul( self, other: &Num ) -> Self::Output {
+		*self * *other
+	}
+}
+
+impl Div<&Num> for &Num {
+	type Output = Num;
+
+	/// The division operator `/` for references, without consuming the operands. Behaves identical to dividing owned `Num`s.
+	fn div( self, other: &Num ) -> Self::Output {
+		*self / *other
+	}
+}
+
+impl Mul<f64> for &Num {
+	type Output = Num;
+
+	/// The multiplication operator `*` for a reference, without consuming the `Num`. Behaves identical to multiplying an owned `Num`.
+	fn mul( self, other: f64 ) -> Self::Output {
+		*self * other
+	}
+}
+
+impl Div<f64> for &Num {
+	type Output = Num;
+
+	/// The division operator `/` for a reference, without consuming the `Num`. Behaves identical to dividing an owned `Num`.
+	fn div( self, other: f64 ) -> Self::Output {
+		*self / other
+	}
+}
+
+impl Neg for &Num {
+	type Output = Num;
+
+	/// The negation operator `-` for a reference, without consuming the `Num`. Behaves identical to negating an owned `Num`.
+	fn neg( self ) -> Self::Output {
+		-*self
+	}
+}
+
+impl Sum for Num {
+	/// Sums up all `Num`s of `iter`, starting from zero. The resulting `Num` will keep the highest prefix of all parts.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Num, Prefix};
+	/// let nums = vec![ Num::new( 2.0 ).with_prefix( Prefix::Kilo ), Num::new( 500.0 ) ];
+	/// let sum: Num = nums.iter().copied().sum();
+	///
+	/// assert_eq!( sum, Num::new( 2500.0 ) );
+	/// assert_eq!( sum.prefix(), Prefix::Kilo );
+	/// ```
+	fn sum<I: Iterator<Item = Self>>( iter: I ) -> Self {
+		iter.fold( Self::new( 0.0 ), |acc, x| acc + x )
+	}
+}
+
+impl<'a> Sum<&'a Num> for Num {
+	fn sum<I: Iterator<Item = &'a Self>>( iter: I ) -> Self {
+		iter.copied().sum()
+	}
+}
+
 impl From<f32> for Num {
 	/// Creates a new `Num` from `item`. This is similar to `Num::new()` but expecting `f32`.
 	///
@@ -605,18 +997,282 @@ impl From<f64> for Num {
 	}
 }
 
+impl FromStr for Num {
+	type Err = NumError;
+
+	/// Parses a number like "9.9", "-2e-3" or "∞" into a `Num` without a prefix.
+	///
+	/// Like Rust numeric literals, the number might start with a "+" sign and contain underscores to group digits ("1_000"). An underscore has to follow a digit.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::Num;
+	/// assert_eq!( "9.9".parse::<Num>().unwrap(), Num::new( 9.9 ) );
+	/// assert_eq!( "+1_000".parse::<Num>().unwrap(), Num::new( 1000.0 ) );
+	/// assert_eq!( "-∞".parse::<Num>().unwrap(), Num::new( f64::NEG_INFINITY ) );
+	/// assert!( "_1".parse::<Num>().is_err() );
+	/// ```
+	fn from_str( s: &str ) -> Result<Self, Self::Err> {
+		let err = || NumError::ParseFailure( s.to_string() );
+
+		let mantissa = match s {
+			"∞" | "+∞" => f64::INFINITY,
+			"-∞" => f64::NEG_INFINITY,
+			x => {
+				let mut digits = String::with_capacity( x.len() );
+				let mut prev = None;
+				for c in x.chars() {
+					match c {
+						'_' if prev.is_some_and( |p: char| p.is_ascii_digit() || p == '_' ) => {},
+						'_' => return Err( err() ),
+						_ => digits.push( c ),
+					}
+					prev = Some( c );
+				}
+				digits.parse::<f64>().map_err( |_| err() )?
+			},
+		};
+
+		Ok( Self::new( mantissa ) )
+	}
+}
+
 impl fmt::Display for Num {
+	/// Writes the number with the mantissa rounded as set by `set_display_rounding()`. By default, the mantissa is not rounded at all.
+	///
+	/// Infinite numbers are written as "∞" or "-∞" and NaN as "NaN", omitting the prefix. Use `is_finite()` to guard against those values before displaying them.
+	///
+	/// The alternate flag (`{:#}`) writes the number with engineering notation like `to_string_eng()` instead.
+	///
+	/// A precision given to the formatter (like `{:.2}`) rounds the mantissa to exactly this number of decimal places like `to_string_precision()`, overriding `set_display_rounding()`. A width (like `{:10}`) pads the number, which is aligned to the right unless specified otherwise.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Num, Prefix};
+	/// assert_eq!( Num::new( f64::INFINITY ).with_prefix( Prefix::Kilo ).to_string(), "∞" );
+	/// assert_eq!( Num::new( f64::NAN ).to_string(), "NaN" );
+	/// assert_eq!( format!( "{:#}", Num::new( 2.0 ).with_prefix( Prefix::Milli ) ), "2×10^-3" );
+	/// assert_eq!( format!( "{:8.2}", Num::new( 1.2345 ).with_prefix( Prefix::Kilo ) ), "  1.23 k" );
+	/// ```
 	fn fmt( &self, f: &mut fmt::Formatter ) -> fmt::Result {
-		// Avoiding print output like "0.100000000012".
-		let mantissa_rounded = ( self.mantissa * 1e6 ).round() / 1e6;
+		let res = match ( f.alternate(), f.precision() ) {
+			( true, _ ) => self.to_string_eng(),
+			( false, Some( x ) ) => self.to_string_precision( x.min( u8::MAX as usize ) as u8 ),
+			( false, None ) => self.to_string_rounded( display_rounding() ),
+		};
 
-		match self.prefix {
-			Prefix::Nothing => write!( f, "{}", mantissa_rounded ),
-			_ => write!( f, "{} {}", mantissa_rounded, self.prefix.to_string_sym() )
-		}
+		pad( f, &res )
+	}
+}
+
+#[cfg( feature = "i18n" )]
+impl DisplayLocale for Num {
+	/// Writes the number like `to_string()` but using the decimal separator of the language specified by `locale`.
+	///
+	/// # Example
+	/// ```
+	/// # use unic_langid::langid;
+	/// # use sinum::{DisplayLocale, Num, Prefix};
+	/// let x = Num::new( 9.9 ).with_prefix( Prefix::Kilo );
+	///
+	/// assert_eq!( x.to_string_locale( &langid!( "en-US" ) ), "9.9 k" );
+	/// assert_eq!( x.to_string_locale( &langid!( "de-DE" ) ), "9,9 k" );
+	/// ```
+	fn to_string_locale( &self, locale: &LanguageIdentifier ) -> String {
+		self.to_string().replace( '.', &LOCALES.lookup( locale, "decimal_separator" ) )
+	}
+}
+
+
+/// Wraps a `Num` to provide equality, a total order and hashing based on its numeric value (see `Num::as_f64()`), so "1 k" and "1000" are considered identical. This allows for using numbers as keys in a `HashSet` or a `BTreeMap`.
+///
+/// Floating point values are compared by their exact representation: numbers differing only by rounding errors like `0.1 + 0.2` and `0.3` are considered different. Positive and negative zero are considered identical, as are all NaN values. NaN is ordered above positive infinity.
+///
+/// # Example
+/// ```
+/// # use std::collections::HashSet;
+/// # use sinum::{Num, OrderedNum, Prefix};
+/// let set: HashSet<OrderedNum> = [
+///     Num::new( 1.0 ).with_prefix( Prefix::Kilo ),
+///     Num::new( 1000.0 ),
+///     Num::new( 2.0 ),
+/// ].into_iter().map( OrderedNum ).collect();
+///
+/// assert_eq!( set.len(), 2 );
+/// ```
+#[derive( Clone, Copy, Debug )]
+pub struct OrderedNum( pub Num );
+
+impl OrderedNum {
+	/// Returns the numeric value `self` is compared and hashed by.
+	fn key( &self ) -> f64 {
+		canonical( self.0.as_f64() )
+	}
+}
+
+impl From<Num> for OrderedNum {
+	fn from( item: Num ) -> Self {
+		Self( item )
+	}
+}
+
+impl PartialEq for OrderedNum {
+	fn eq( &self, other: &Self ) -> bool {
+		self.key().to_bits() == other.key().to_bits()
+	}
+}
+
+impl Eq for OrderedNum {}
+
+impl PartialOrd for OrderedNum {
+	fn partial_cmp( &self, other: &Self ) -> Option<Ordering> {
+		Some( self.cmp( other ) )
+	}
+}
+
+impl Ord for OrderedNum {
+	fn cmp( &self, other: &Self ) -> Ordering {
+		self.key().total_cmp( &other.key() )
+	}
+}
+
+impl Hash for OrderedNum {
+	fn hash<H: Hasher>( &self, state: &mut H ) {
+		self.key().to_bits().hash( state );
+	}
+}
+
+
+
+/// Writes a `Num` with a fixed number of decimal places of the mantissa. Create it with `Num::with_precision()`.
+///
+/// The `Display` implementation behaves like `Num::to_string_precision()`, keeping trailing zeros. The width, fill and alignment of the formatter are respected, the precision of the formatter is ignored in favor of the precision of `self`.
+#[derive( Clone, Copy, Debug )]
+pub struct NumFormat {
+	num: Num,
+	precision: u8,
+}
+
+impl NumFormat {
+	/// Returns the formatted number.
+	pub fn num( &self ) -> Num {
+		self.num
+	}
+
+	/// Returns the number of decimal places the mantissa is rounded to.
+	pub fn precision( &self ) -> u8 {
+		self.precision
 	}
 }
 
+impl fmt::Display for NumFormat {
+	fn fmt( &self, f: &mut fmt::Formatter ) -> fmt::Result {
+		pad( f, &self.num.to_string_precision( self.precision ) )
+	}
+}
+
+
+
+
+//=============================================================================
+// Functions
+
+
+/// Sets the number of decimal places the mantissa is rounded to by the `Display` implementations of `Num` and `Qty` for the whole program. `None` disables rounding, which is the default, `Some( CLEAN_DISPLAY_DECIMALS )` restores the "clean display" of earlier versions avoiding output like "0.100000000012".
+///
+/// Use `Num::to_string_rounded()` or `Qty::to_string_rounded()` to override this setting for a single string.
+///
+/// # Example
+/// ```
+/// # use sinum::{Num, CLEAN_DISPLAY_DECIMALS, set_display_rounding, display_rounding};
+/// let x = Num::new( 1.23456789 );
+/// assert_eq!( display_rounding(), None );
+/// assert_eq!( x.to_string(), "1.23456789" );
+///
+/// set_display_rounding( Some( CLEAN_DISPLAY_DECIMALS ) );
+/// assert_eq!( display_rounding(), Some( 6 ) );
+/// assert_eq!( x.to_string(), "1.234568" );
+/// ```
+pub fn set_display_rounding( decimals: Option<u8> ) {
+	DISPLAY_DECIMALS.store( decimals.unwrap_or( NO_ROUNDING ), AtomicOrdering::Relaxed );
+}
+
+/// Returns the number of decimal places the mantissa is rounded to by the `Display` implementations of `Num` and `Qty`. See `set_display_rounding()`.
+pub fn display_rounding() -> Option<u8> {
+	match DISPLAY_DECIMALS.load( AtomicOrdering::Relaxed ) {
+		NO_ROUNDING => None,
+		x => Some( x ),
+	}
+}
+
+/// Writes `text` into `f`, padded to the width of `f` with its fill character. Without an explicit alignment, `text` is aligned to the right like numbers. In contrast to `fmt::Formatter::pad()`, the precision of `f` is not applied to `text`.
+pub(crate) fn pad( f: &mut fmt::Formatter, text: &str ) -> fmt::Result {
+	let len = text.chars().count();
+	let diff = match f.width() {
+		Some( x ) if x > len => x - len,
+		_ => return f.write_str( text ),
+	};
+
+	let ( before, after ) = match f.align() {
+		Some( fmt::Alignment::Left ) => ( 0, diff ),
+		Some( fmt::Alignment::Center ) => ( diff / 2, diff - diff / 2 ),
+		_ => ( diff, 0 ),
+	};
+	let fill = f.fill().to_string();
+
+	f.write_str( &fill.repeat( before ) )?;
+	f.write_str( text )?;
+	f.write_str( &fill.repeat( after ) )
+}
+
+/// Returns `val` with a unique representation of values that are considered identical: negative zero becomes positive zero and every NaN becomes `f64::NAN`.
+pub(crate) fn canonical( val: f64 ) -> f64 {
+	match val {
+		x if x.is_nan() => f64::NAN,
+		// Matches negative zero as well.
+		0.0 => 0.0,
+		x => x,
+	}
+}
+
+/// Returns the string representing the non-finite `val` ("∞", "-∞" or "NaN") or `None` if `val` is finite.
+pub(crate) fn to_string_non_finite( val: f64 ) -> Option<&'static str> {
+	match val {
+		x if x.is_nan() => Some( "NaN" ),
+		f64::INFINITY => Some( "∞" ),
+		f64::NEG_INFINITY => Some( "-∞" ),
+		_ => None,
+	}
+}
+
+/// Returns `val` rounded to at most `decimals` decimal places. `None` returns `val` unchanged.
+pub(crate) fn round_decimals( val: f64, decimals: Option<u8> ) -> f64 {
+	match decimals {
+		Some( x ) => {
+			let factor = 10_f64.powi( x as i32 );
+			match ( val * factor ).round() / factor {
+				// Very large numbers of decimal places might overflow while scaling.
+				y if y.is_finite() => y,
+				_ => val,
+			}
+		},
+		None => val,
+	}
+}
+
+/// Returns `val` rounded to `sig` significant figures. A `sig` of zero is treated like 1. Zero and non-finite values are returned unchanged.
+pub(crate) fn round_significant( val: f64, sig: u8 ) -> f64 {
+	if val == 0.0 || !val.is_finite() {
+		return val;
+	}
+
+	// Scaling with a positive power of ten only to keep the floating point error to a minimum.
+	let exp = sig.max( 1 ) as i32 - 1 - val.abs().log10().floor() as i32;
+	match exp {
+		x if x >= 0 => ( val * 10_f64.powi( x ) ).round() / 10_f64.powi( x ),
+		x => ( val / 10_f64.powi( -x ) ).round() * 10_f64.powi( -x ),
+	}
+}
 
 
 
@@ -643,4 +1299,269 @@ mod tests {
 		assert_eq!( Num::new( 9999.9 ).with_prefix( Prefix::Milli ).to_string_eng(), "9999.9×10^-3".to_string() );
 		assert_eq!( Num::new( 9999.9 ).with_prefix( Prefix::Mega ).to_prefix( Prefix::Milli ).to_string_eng(), "9999900000000×10^-3".to_string() );
 	}
+
+	#[test]
+	fn sinum_ordered() {
+		use std::collections::{BTreeSet, HashSet};
+
+		let set: HashSet<OrderedNum> = [
+			Num::new( 1.0 ).with_prefix( Prefix::Kilo ),
+			Num::new( 1000.0 ),
+			Num::new( 0.001 ).with_prefix( Prefix::Mega ),
+		].into_iter().map( OrderedNum ).collect();
+		assert_eq!( set.len(), 1 );
+
+		let set: HashSet<OrderedNum> = [ 0.0, -0.0, f64::NAN, -f64::NAN, 1.0 ].into_iter()
+			.map( |x| OrderedNum( Num::new( x ) ) )
+			.collect();
+		assert_eq!( set.len(), 3 );
+
+		assert_eq!( OrderedNum( Num::new( 0.0 ) ), OrderedNum( Num::new( -0.0 ) ) );
+		assert_eq!( OrderedNum( Num::new( f64::NAN ) ), OrderedNum::from( Num::new( f64::NAN ) ) );
+		assert_ne!( OrderedNum( Num::new( 1.0 ) ), OrderedNum( Num::new( 1.0 + f64::EPSILON ) ) );
+
+		let sorted: Vec<f64> = [ f64::NAN, 2.0, f64::INFINITY, -1.0 ].into_iter()
+			.map( |x| OrderedNum( Num::new( x ) ) )
+			.collect::<BTreeSet<OrderedNum>>()
+			.into_iter()
+			.map( |x| x.0.as_f64() )
+			.collect();
+		assert_eq!( &sorted[..3], &[ -1.0, 2.0, f64::INFINITY ] );
+		assert!( sorted[3].is_nan() );
+	}
+
+	#[test]
+	fn sinum_display_format_spec() {
+		let prefixed = Num::new( 1.23456 ).with_prefix( Prefix::Kilo );
+		let plain = Num::new( 9.87654 );
+
+		assert_eq!( format!( "{:.3}", prefixed ), "1.235 k" );
+		assert_eq!( format!( "{:.3}", plain ), "9.877" );
+		assert_eq!( format!( "{:.0}", plain ), "10" );
+		assert_eq!( format!( "{:.2}", Num::new( 2.5 ) ), "2.50" );
+
+		assert_eq!( format!( "{:10}", Num::new( 2.5 ) ), "       2.5" );
+		assert_eq!( format!( "{:10}", Num::new( 2.5 ).with_prefix( Prefix::Milli ) ), "     2.5 m" );
+		assert_eq!( format!( "{:<10}|", Num::new( 2.5 ) ), "2.5       |" );
+		assert_eq!( format!( "{:^9}", Num::new( 2.5 ) ), "   2.5   " );
+		assert_eq!( format!( "{:*>6}", Num::new( 2.5 ) ), "***2.5" );
+		assert_eq!( format!( "{:2}", plain ), "9.87654" );
+
+		assert_eq!( format!( "{:10.2}", prefixed ), "    1.23 k" );
+		assert_eq!( format!( "{:<10.2}|", plain ), "9.88      |" );
+		assert_eq!( format!( "{:8.1}", Num::new( f64::INFINITY ) ), "       ∞" );
+		assert_eq!( format!( "{:#12}", Num::new( 2.0 ).with_prefix( Prefix::Milli ) ), "     2×10^-3" );
+
+		// Without a format spec, the output is unchanged.
+		assert_eq!( format!( "{}", prefixed ), prefixed.to_string_rounded( display_rounding() ) );
+	}
+
+	#[test]
+	fn sinum_display_alternate() {
+		let num = Num::new( 9.9 ).with_prefix( Prefix::Milli );
+		assert_eq!( format!( "{}", num ), "9.9 m" );
+		assert_eq!( format!( "{:#}", num ), "9.9×10^-3" );
+		assert_eq!( format!( "{:#}", Num::new( 9.9 ) ), "9.9" );
+		assert_eq!( format!( "{:#}", Num::new( f64::NEG_INFINITY ).with_prefix( Prefix::Kilo ) ), "-∞" );
+	}
+
+	#[test]
+	fn sinum_finite() {
+		let inf = Num::new( 1.0 ).with_prefix( Prefix::Kilo ) / 0.0;
+		assert!( inf.is_infinite() && !inf.is_finite() && !inf.is_nan() );
+
+		let nan = Num::new( 0.0 ) / Num::new( 0.0 );
+		assert!( nan.is_nan() && !nan.is_finite() && !nan.is_infinite() );
+
+		let num = Num::new( -2.0 ).with_prefix( Prefix::Milli );
+		assert!( num.is_finite() && !num.is_nan() && !num.is_infinite() );
+	}
+
+	#[test]
+	fn sinum_round_then_shorten() {
+		assert_eq!( Num::new( 999.96 ).round_then_shorten( 2 ).unwrap().prefix(), Prefix::Kilo );
+		assert_eq!( Num::new( 999.4 ).round_then_shorten( 3 ).unwrap().to_string(), "999".to_string() );
+		assert_eq!( Num::new( 999.5 ).round_then_shorten( 3 ).unwrap().to_string(), "1 k".to_string() );
+		assert_eq!( Num::new( 0.99996 ).with_prefix( Prefix::Milli ).round_then_shorten( 3 ).unwrap().to_string(), "1 m".to_string() );
+		assert_eq!( Num::new( 999_960.0 ).with_prefix( Prefix::Kilo ).round_then_shorten( 2 ).unwrap().to_string(), "1 G".to_string() );
+		assert_eq!( Num::new( 0.0 ).with_prefix( Prefix::Kilo ).round_then_shorten( 2 ).unwrap(), Num::new( 0.0 ) );
+		assert!( Num::new( 1e40 ).round_then_shorten( 2 ).is_err() );
+	}
+
+	#[test]
+	fn sinum_normalized() {
+		let num = Num::new( 1_000_000.0 ).normalized();
+		assert_eq!( num.prefix(), Prefix::Mega );
+		assert_eq!( num.mantissa(), 1.0 );
+
+		let num = Num::new( 0.0005 ).normalized();
+		assert_eq!( num.prefix(), Prefix::Micro );
+		assert!( ( num.mantissa() - 500.0 ).abs() < 1e-9 );
+
+		let num = Num::new( 0.0 ).with_prefix( Prefix::Kilo ).normalized();
+		assert_eq!( num.prefix(), Prefix::Nothing );
+		assert_eq!( num.mantissa(), 0.0 );
+
+		assert_eq!( Num::new( -2500.0 ).normalized().to_string(), "-2.5 k" );
+		assert_eq!( Num::new( 999.0 ).normalized().prefix(), Prefix::Nothing );
+		assert_eq!( Num::new( 1.5 ).with_prefix( Prefix::Centi ).normalized().to_string(), "15 m" );
+		assert_eq!( Num::new( 2500.0 ).with_prefix( Prefix::Milli ).normalized().to_string(), "2.5" );
+		assert_eq!( Num::new( 1e40 ).normalized().prefix(), Prefix::Quetta );
+		assert_eq!( Num::new( 1e-40 ).normalized().prefix(), Prefix::Quecto );
+		assert!( Num::new( f64::INFINITY ).with_prefix( Prefix::Kilo ).normalized().is_infinite() );
+		assert!( Num::new( f64::NAN ).normalized().is_nan() );
+	}
+
+	#[test]
+	fn sinum_clamp() {
+		let min = Num::new( 1.0 ).with_prefix( Prefix::Kilo );
+		let max = Num::new( 2.0 ).with_prefix( Prefix::Kilo );
+
+		let res = Num::new( 1500.0 ).clamp( min, max );
+		assert_eq!( res, Num::new( 1500.0 ) );
+		assert_eq!( res.prefix(), Prefix::Nothing );
+
+		let res = Num::new( 0.5 ).with_prefix( Prefix::Kilo ).clamp( min, max );
+		assert_eq!( res, min );
+		assert_eq!( res.prefix(), Prefix::Kilo );
+
+		let res = Num::new( 3e6 ).with_prefix( Prefix::Milli ).clamp( min, max );
+		assert_eq!( res, max );
+		assert_eq!( res.prefix(), Prefix::Milli );
+		assert_eq!( res.mantissa(), 2e6 );
+
+		assert!( Num::new( f64::NAN ).clamp( min, max ).is_nan() );
+		assert_eq!( Num::new( 1.0 ).clamp( min, min ), min );
+	}
+
+	#[test]
+	#[should_panic]
+	fn sinum_clamp_invalid() {
+		Num::new( 1.0 ).clamp( Num::new( 2.0 ), Num::new( 1.0 ) );
+	}
+
+	#[test]
+	fn sinum_display_non_finite() {
+		assert_eq!( Num::new( f64::INFINITY ).to_string(), "∞" );
+		assert_eq!( Num::new( f64::NEG_INFINITY ).with_prefix( Prefix::Milli ).to_string(), "-∞" );
+		assert_eq!( Num::new( f64::NAN ).with_prefix( Prefix::Kilo ).to_string(), "NaN" );
+		assert_eq!( Num::new( f64::INFINITY ).with_prefix( Prefix::Kilo ).to_string_eng(), "∞" );
+		assert_eq!( Num::new( f64::NAN ).to_string_precision( 2 ), "NaN" );
+		assert_eq!( Num::new( f64::NEG_INFINITY ).to_string_rounded( Some( 2 ) ), "-∞" );
+	}
+
+	// Taking references of `Copy` operands is the point of this test.
+	#[allow( clippy::op_ref )]
+	#[test]
+	fn sinum_ops_ref() {
+		let a = Num::new( 8.0 ).with_prefix( Prefix::Milli );
+		let b = Num::new( 4.0 ).with_prefix( Prefix::Kilo );
+
+		for ( by_ref, owned ) in [
+			( &a + &b, a + b ),
+			( &a - &b, a - b ),
+			( &a * &b, a * b ),
+			( &a / &b, a / b ),
+			( &a * 2.5, a * 2.5 ),
+			( &b / 4.0, b / 4.0 ),
+			( -&a, -a ),
+		] {
+			assert_eq!( by_ref, owned );
+			assert_eq!( by_ref.prefix(), owned.prefix() );
+		}
+	}
+
+	#[test]
+	fn sinum_sum() {
+		let nums = [ Num::new( 1.0 ).with_prefix( Prefix::Kilo ), Num::new( 2.0 ), Num::new( 3.0 ).with_prefix( Prefix::Milli ) ];
+		assert!( ( nums.iter().sum::<Num>().as_f64() - 1002.003 ).abs() < 1e-9 );
+		assert_eq!( nums.iter().sum::<Num>().prefix(), Prefix::Kilo );
+		assert_eq!( Vec::<Num>::new().into_iter().sum::<Num>(), Num::new( 0.0 ) );
+	}
+
+	#[test]
+	fn sinum_snap_to_int_mantissa() {
+		assert_eq!( Num::new( 2.9999999 ).with_prefix( Prefix::Kilo ).snap_to_int_mantissa( 1e-6 ).to_string(), "3 k".to_string() );
+		assert_eq!( Num::new( -4.0000001 ).snap_to_int_mantissa( 1e-6 ).mantissa(), -4.0 );
+		assert_eq!( Num::new( 2.5 ).with_prefix( Prefix::Milli ).snap_to_int_mantissa( 1e-6 ).mantissa(), 2.5 );
+		assert_eq!( Num::new( 2.5 ).snap_to_int_mantissa( 1e-6 ).prefix(), Prefix::Nothing );
+		assert_eq!( Num::new( 2.01 ).snap_to_int_mantissa( 1e-3 ).mantissa(), 2.01 );
+	}
+
+	#[test]
+	fn sinum_rounding() {
+		let x = Num::new( 1234.5 ).with_prefix( Prefix::Kilo );
+		assert_eq!( x.round().mantissa(), 1235.0 );
+		assert_eq!( x.floor().mantissa(), 1234.0 );
+		assert_eq!( x.ceil().mantissa(), 1235.0 );
+		assert_eq!( x.trunc().mantissa(), 1234.0 );
+		assert_eq!( x.round().prefix(), Prefix::Kilo );
+		assert_eq!( x.round().as_f64(), 1_235_000.0 );
+
+		let y = Num::new( -2.5 ).with_prefix( Prefix::Milli );
+		assert_eq!( y.round(), Num::new( -3.0 ).with_prefix( Prefix::Milli ) );
+		assert_eq!( y.floor(), Num::new( -3.0 ).with_prefix( Prefix::Milli ) );
+		assert_eq!( y.ceil(), Num::new( -2.0 ).with_prefix( Prefix::Milli ) );
+		assert_eq!( y.trunc(), Num::new( -2.0 ).with_prefix( Prefix::Milli ) );
+		assert_eq!( y.trunc().prefix(), Prefix::Milli );
+
+		assert_eq!( Num::new( 0.4 ).with_prefix( Prefix::Mega ).round().as_f64(), 0.0 );
+		assert!( Num::new( f64::NAN ).round().mantissa().is_nan() );
+	}
+
+	#[test]
+	fn sinum_from_str() {
+		assert_eq!( Num::from_str( "9.9" ).unwrap(), Num::new( 9.9 ) );
+		assert_eq!( Num::from_str( "+9.9" ).unwrap(), Num::new( 9.9 ) );
+		assert_eq!( Num::from_str( "1_000" ).unwrap(), Num::new( 1000.0 ) );
+		assert_eq!( Num::from_str( "+1_000_000.000_1" ).unwrap(), Num::new( 1_000_000.000_1 ) );
+		assert_eq!( Num::from_str( "-1__0e1_0" ).unwrap(), Num::new( -10e10 ) );
+		assert_eq!( Num::from_str( "+∞" ).unwrap(), Num::new( f64::INFINITY ) );
+		assert_eq!( Num::from_str( "1_000" ).unwrap().prefix(), Prefix::Nothing );
+		for s in [ "", "_1", "+_1", "1._5", "1e_3", "_", "1.2.3", "xyz" ] {
+			assert!( Num::from_str( s ).is_err(), "{}", s );
+		}
+	}
+
+	#[test]
+	fn sinum_display_rounding() {
+		let x = Num::new( 1.23456789 );
+		assert_eq!( x.to_string(), "1.23456789" );
+		assert_eq!( x.to_string_rounded( None ), "1.23456789" );
+		assert_eq!( x.to_string_rounded( Some( CLEAN_DISPLAY_DECIMALS ) ), "1.234568" );
+		assert_eq!( x.to_string_rounded( Some( 2 ) ), "1.23" );
+		assert_eq!( x.to_string_rounded( Some( 0 ) ), "1" );
+
+		let x = Num::new( 0.1 + 0.2 ).with_prefix( Prefix::Milli );
+		assert_eq!( x.to_string(), "0.30000000000000004 m" );
+		assert_eq!( x.to_string_rounded( Some( CLEAN_DISPLAY_DECIMALS ) ), "0.3 m" );
+
+		assert_eq!( Num::new( 1.5e300 ).to_string_rounded( Some( 200 ) ), Num::new( 1.5e300 ).to_string() );
+	}
+
+	#[test]
+	fn sinum_string_precision() {
+		assert_eq!( Num::new( 1.23456789 ).to_string_precision( 3 ), "1.235".to_string() );
+		assert_eq!( Num::new( 1.23456789 ).to_string_precision( 0 ), "1".to_string() );
+		assert_eq!( Num::new( 9.9991 ).with_prefix( Prefix::Kilo ).to_string_precision( 3 ), "9.999 k".to_string() );
+		assert_eq!( Num::new( 9999.1 ).with_prefix( Prefix::Milli ).to_string_precision( 1 ), "9999.1 m".to_string() );
+		assert_eq!( Num::new( 0.1 ).to_string(), "0.1".to_string() );
+	}
+
+	#[test]
+	fn sinum_with_precision() {
+		let x = Num::new( 1.23456789 ).with_precision( 3 );
+		assert_eq!( x.to_string(), "1.235".to_string() );
+		assert_eq!( x.precision(), 3 );
+		assert_eq!( x.num(), Num::new( 1.23456789 ) );
+
+		assert_eq!( Num::new( 9.9991 ).with_prefix( Prefix::Kilo ).with_precision( 3 ).to_string(), "9.999 k".to_string() );
+		assert_eq!( Num::new( 2.5 ).with_precision( 2 ).to_string(), "2.50".to_string() );
+		assert_eq!( format!( "{:<6}|", Num::new( 2.5 ).with_precision( 1 ) ), "2.5   |".to_string() );
+		assert_eq!( format!( "{:.5}", Num::new( 2.5 ).with_precision( 1 ) ), "2.5".to_string() );
+		assert_eq!( Num::new( f64::NAN ).with_precision( 2 ).to_string(), "NaN".to_string() );
+
+		// `Display` of `Num` itself is unchanged.
+		assert_eq!( Num::new( 1.23456789 ).to_string(), "1.23456789".to_string() );
+	}
 }
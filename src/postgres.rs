@@ -0,0 +1,126 @@
+//! Provides the mapping of quantities to PostgreSQL text columns.
+//!
+//! A `Qty` is stored as text of the form "9.9 km": the mantissa with full precision followed by a space and the symbols of the prefix and the unit. This representation is human readable and can be parsed by `Qty::from_str()`. Since the same value may be stored with different prefixes or units ("9.9 km" and "9900 m"), comparing or indexing the column as text does not respect the numeric order. Quantities whose text cannot be parsed back into the same prefix and unit (like custom or compound units) are refused when writing to the database.
+
+
+
+
+//=============================================================================
+// Crates
+
+
+use std::error::Error;
+
+use bytes::BytesMut;
+use postgres_types::{FromSql, IsNull, ToSql, Type, to_sql_checked};
+
+use crate::{Qty, QtyError};
+
+
+
+
+//=============================================================================
+// Functions
+
+
+/// Returns the text stored in the database to represent `qty`.
+fn to_string_sql( qty: &Qty ) -> String {
+	format!(
+		"{} {}{}",
+		qty.number().mantissa(),
+		qty.number().prefix().to_string_sym(),
+		qty.unit().to_string_sym()
+	)
+}
+
+
+
+
+//=============================================================================
+// Trait Implementations
+
+
+impl ToSql for Qty {
+	fn to_sql( &self, ty: &Type, out: &mut BytesMut ) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+		let text = to_string_sql( self );
+
+		// Refuse quantities that would be read back with another unit or prefix.
+		let is_parsable = text.parse::<Qty>()
+			.is_ok_and( |x| x.unit() == self.unit() && x.number().prefix() == self.number().prefix() );
+		if !is_parsable {
+			return Err( QtyError::Unrepresentable( text ).into() );
+		}
+
+		text.to_sql( ty, out )
+	}
+
+	fn accepts( ty: &Type ) -> bool {
+		<String as ToSql>::accepts( ty )
+	}
+
+	to_sql_checked!();
+}
+
+impl<'a> FromSql<'a> for Qty {
+	fn from_sql( ty: &Type, raw: &'a [u8] ) -> Result<Self, Box<dyn Error + Sync + Send>> {
+		let text = <&str as FromSql>::from_sql( ty, raw )?;
+		Ok( text.parse::<Qty>()? )
+	}
+
+	fn accepts( ty: &Type ) -> bool {
+		<&str as FromSql>::accepts( ty )
+	}
+}
+
+
+
+
+//=============================================================================
+// Testing
+
+
+#[cfg( test )]
+mod tests {
+	use super::*;
+
+	use crate::{Num, Prefix, Unit};
+
+	fn round_trip( qty: &Qty ) -> Qty {
+		let mut buf = BytesMut::new();
+		qty.to_sql_checked( &Type::TEXT, &mut buf ).unwrap();
+		Qty::from_sql( &Type::TEXT, &buf ).unwrap()
+	}
+
+	#[test]
+	fn postgres_round_trip() {
+		for qty in [
+			Qty::new( Num::new( 9.9 ).with_prefix( Prefix::Kilo ), &Unit::Meter ),
+			Qty::new( Num::new( 1.234_567_890_123 ).with_prefix( Prefix::Micro ), &Unit::Ampere ),
+			Qty::new( Num::new( 5.0 ).with_prefix( Prefix::Milli ), &Unit::Gram ),
+			Qty::new( 2.0.into(), &Unit::Kilogram ),
+			Qty::new( Num::new( -20.5 ), &Unit::Celsius ),
+			Qty::new( 20.0.into(), &Unit::Degree ),
+			Qty::new( 5.0.into(), &Unit::Year ),
+			Qty::new( Num::new( 5.0 ).with_prefix( Prefix::Pico ), &Unit::Ampere ),
+			Qty::new( Num::new( 5.0 ).with_prefix( Prefix::Pico ), &Unit::Coulomb ),
+		] {
+			let res = round_trip( &qty );
+			assert_eq!( res, qty );
+			assert_eq!( res.number().prefix(), qty.number().prefix() );
+			assert_eq!( res.unit(), qty.unit() );
+		}
+	}
+
+	#[test]
+	fn postgres_text() {
+		assert_eq!( to_string_sql( &Qty::new( Num::new( 9.9 ).with_prefix( Prefix::Kilo ), &Unit::Meter ) ), "9.9 km" );
+		assert!( <Qty as ToSql>::accepts( &Type::TEXT ) );
+		assert!( <Qty as ToSql>::accepts( &Type::VARCHAR ) );
+		assert!( !<Qty as ToSql>::accepts( &Type::INT4 ) );
+		assert!( Qty::from_sql( &Type::TEXT, b"9.9 xyz" ).is_err() );
+
+		let mut buf = BytesMut::new();
+		assert!( Qty::new( Num::new( 5.0 ).with_prefix( Prefix::Kilo ), &Unit::One ).to_sql_checked( &Type::TEXT, &mut buf ).is_err() );
+		assert!( Qty::new( 5.0.into(), &Unit::Custom( "apple".to_string() ) ).to_sql_checked( &Type::TEXT, &mut buf ).is_err() );
+	}
+}
@@ -40,6 +40,9 @@ pub enum PrefixError {
 
 	#[error( "There is no SI prefix for `{0}`" )]
 	ExpInvalid( i32 ),
+
+	#[error( "The set of allowed prefixes is empty" )]
+	EmptySet,
 }
 
 
@@ -49,6 +52,18 @@ pub enum PrefixError {
 // Enums
 
 
+/// Represents the direction in which a value is rounded when snapping it to a valid value.
+#[derive( Clone, Copy, PartialEq, Eq, Debug )]
+pub enum RoundMode {
+	/// Rounding to the nearest valid value. Ties are rounded towards the smaller value.
+	Nearest,
+	/// Rounding to the largest valid value not larger than the original value.
+	Floor,
+	/// Rounding to the smallest valid value not smaller than the original value.
+	Ceil,
+}
+
+
 /// Represents the different SI prefixes like kilo, milli, nano etc.
 #[cfg_attr( feature = "serde", derive( Serialize, Deserialize ) )]
 #[derive( Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Debug )]
@@ -87,15 +102,60 @@ impl Prefix {
 	/// Smalles exponent representable by `Self`.
 	pub const MIN_EXP: i8 = -30;
 
-	/// Return the factor represented by this prefix.
+	/// All prefixes ordered by their exponent from `Prefix::Quecto` to `Prefix::Quetta`, including `Prefix::Nothing`.
+	pub const ALL: [Prefix; 25] = [
+		Prefix::Quecto,
+		Prefix::Ronto,
+		Prefix::Yocto,
+		Prefix::Zepto,
+		Prefix::Atto,
+		Prefix::Femto,
+		Prefix::Pico,
+		Prefix::Nano,
+		Prefix::Micro,
+		Prefix::Milli,
+		Prefix::Centi,
+		Prefix::Deci,
+		Prefix::Nothing,
+		Prefix::Deca,
+		Prefix::Hecto,
+		Prefix::Kilo,
+		Prefix::Mega,
+		Prefix::Giga,
+		Prefix::Tera,
+		Prefix::Peta,
+		Prefix::Exa,
+		Prefix::Zetta,
+		Prefix::Yotta,
+		Prefix::Ronna,
+		Prefix::Quetta,
+	];
+
+	/// Returns an iterator over all prefixes ordered by their exponent from `Prefix::Quecto` to `Prefix::Quetta`, including `Prefix::Nothing`.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::Prefix;
+	/// assert_eq!( Prefix::all().next(), Some( Prefix::Quecto ) );
+	/// assert_eq!( Prefix::all().last(), Some( Prefix::Quetta ) );
+	/// assert_eq!( Prefix::all().filter( |x| x.exp() > 0 ).count(), 12 );
+	/// ```
+	pub fn all() -> impl Iterator<Item = Prefix> {
+		Self::ALL.into_iter()
+	}
+
+	/// Return the factor represented by this prefix. This can be used in const contexts.
 	///
 	/// # Example
 	/// ```
 	/// # use sinum::Prefix;
 	/// assert_eq!( Prefix::Peta.as_f64(), 1e15f64 );
 	/// assert_eq!( Prefix::Femto.as_f64(), 1e-15f64 );
+	///
+	/// const FACTOR: f64 = Prefix::Kilo.as_f64();
+	/// assert_eq!( FACTOR, 1e3 );
 	/// ```
-	pub fn as_f64( &self ) -> f64 {
+	pub const fn as_f64( &self ) -> f64 {
 		match self {
 			Self::Quecto => 1e-30,
 			Self::Ronto => 1e-27,
@@ -125,7 +185,7 @@ impl Prefix {
 		}
 	}
 
-	/// Returns the exponent representing this prefix.
+	/// Returns the exponent representing this prefix. This can be used in const contexts.
 	///
 	/// # Example
 	/// ```
@@ -133,7 +193,7 @@ impl Prefix {
 	/// assert_eq!( Prefix::Peta.exp(), 15i8 );
 	/// assert_eq!( Prefix::Femto.exp(), -15i8 );
 	/// ```
-	pub fn exp( &self ) -> i8 {
+	pub const fn exp( &self ) -> i8 {
 		match self {
 			Self::Quecto => -30,
 			Self::Ronto =>  -27,
@@ -195,6 +255,97 @@ impl Prefix {
 
 		res.to_string()
 	}
+
+	/// Returns the `Prefix` represented by the symbol `s` as returned by `to_string_sym()`.
+	///
+	/// While `from_str()` expects the name of the prefix, this expects the prefix letter as it is written in front of the unit symbol. The comparison is case-sensitive, since e.g. "m" represents milli while "M" represents mega. If `s` is not a valid prefix symbol, a `PrefixError` will be returned.
+	///
+	/// Since the micro sign "µ" is hard to type, the Greek letter "μ" and the ASCII letter "u" are accepted for micro as well. `to_string_sym()` always returns the micro sign.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::Prefix;
+	/// assert_eq!( Prefix::from_symbol( "k" ).unwrap(), Prefix::Kilo );
+	/// assert_eq!( Prefix::from_symbol( "m" ).unwrap(), Prefix::Milli );
+	/// assert_eq!( Prefix::from_symbol( "M" ).unwrap(), Prefix::Mega );
+	/// assert_eq!( Prefix::from_symbol( "µ" ).unwrap(), Prefix::Micro );
+	/// assert_eq!( Prefix::from_symbol( "u" ).unwrap(), Prefix::Micro );
+	/// assert_eq!( Prefix::from_symbol( "da" ).unwrap(), Prefix::Deca );
+	/// assert!( Prefix::from_symbol( "K" ).is_err() );
+	/// ```
+	pub fn from_symbol( s: &str ) -> Result<Self, PrefixError> {
+		let res = match s {
+			"q"  => Self::Quecto,
+			"r"  => Self::Ronto,
+			"y"  => Self::Yocto,
+			"z"  => Self::Zepto,
+			"a"  => Self::Atto,
+			"f"  => Self::Femto,
+			"p"  => Self::Pico,
+			"n"  => Self::Nano,
+			"µ" | "μ" | "u" => Self::Micro,
+			"m"  => Self::Milli,
+			"c"  => Self::Centi,
+			"d"  => Self::Deci,
+			"da" => Self::Deca,
+			"h"  => Self::Hecto,
+			"k"  => Self::Kilo,
+			"M"  => Self::Mega,
+			"G"  => Self::Giga,
+			"T"  => Self::Tera,
+			"P"  => Self::Peta,
+			"E"  => Self::Exa,
+			"Z"  => Self::Zetta,
+			"Y"  => Self::Yotta,
+			"R"  => Self::Ronna,
+			"Q"  => Self::Quetta,
+			_ => return Err( PrefixError::TryFromStr( s.to_string() ) ),
+		};
+
+		Ok( res )
+	}
+
+	/// Returns the `Prefix` whose exponent is closest to `exp` in the direction given by `round`.
+	///
+	/// In contrast to `Prefix::try_from()` this never fails for exponents without a prefix (like 4 or 7), but snaps to a valid exponent instead. Exponents beyond the range of the prefixes are snapped to `Prefix::MIN_EXP` or `Prefix::MAX_EXP` respectively.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Prefix, RoundMode};
+	/// assert_eq!( Prefix::try_from_exp_snap( 4, RoundMode::Floor ), Prefix::Kilo );
+	/// assert_eq!( Prefix::try_from_exp_snap( 4, RoundMode::Ceil ), Prefix::Mega );
+	/// assert_eq!( Prefix::try_from_exp_snap( 5, RoundMode::Nearest ), Prefix::Mega );
+	/// assert_eq!( Prefix::try_from_exp_snap( 3, RoundMode::Ceil ), Prefix::Kilo );
+	/// ```
+	pub fn try_from_exp_snap( exp: i8, round: RoundMode ) -> Self {
+		let exp = exp.clamp( Self::MIN_EXP, Self::MAX_EXP );
+
+		// Both searches succeed, since `Prefix::MIN_EXP` and `Prefix::MAX_EXP` are valid exponents.
+		let below = ( Self::MIN_EXP..=exp ).rev().find_map( |x| Self::try_from( x ).ok() ).unwrap();
+		let above = ( exp..=Self::MAX_EXP ).find_map( |x| Self::try_from( x ).ok() ).unwrap();
+
+		match round {
+			RoundMode::Floor => below,
+			RoundMode::Ceil => above,
+			RoundMode::Nearest if exp - below.exp() <= above.exp() - exp => below,
+			RoundMode::Nearest => above,
+		}
+	}
+
+	/// Returns the `Prefix` representing the product of the factors of `self` and `other`, which means the exponents of both prefixes are added.
+	///
+	/// If there is no prefix representing the resulting exponent, a `PrefixError::TryFromExp` will be returned.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::Prefix;
+	/// assert_eq!( Prefix::Kilo.checked_add_exp( Prefix::Kilo ).unwrap(), Prefix::Mega );
+	/// assert_eq!( Prefix::Kilo.checked_add_exp( Prefix::Milli ).unwrap(), Prefix::Nothing );
+	/// assert!( Prefix::Centi.checked_add_exp( Prefix::Centi ).is_err() );
+	/// ```
+	pub fn checked_add_exp( self, other: Prefix ) -> Result<Self, PrefixError> {
+		Self::try_from( self.exp() + other.exp() )
+	}
 }
 
 impl TryFrom<i8> for Prefix {
@@ -382,7 +533,7 @@ impl LatexSym for Prefix {
 			Self::Micro =>   r"\micro".to_string(),
 			Self::Milli =>   r"\milli".to_string(),
 			Self::Centi =>   r"\centi".to_string(),
-			Self::Deci =>    r"\deca".to_string(),
+			Self::Deci =>    r"\deci".to_string(),
 			Self::Nothing => "".to_string(),
 			Self::Deca =>    r"\deca".to_string(),
 			Self::Hecto =>   r"\hecto".to_string(),
@@ -401,6 +552,186 @@ impl LatexSym for Prefix {
 }
 
 
+/// Represents the binary prefixes like kibi, mebi, gibi etc. defined by the IEC. These are not SI prefixes and represent powers of 1024 instead of powers of 1000. They are mostly used for amounts of information like file sizes (see `Qty::shortened_binary()`).
+#[derive( Clone, Copy, PartialOrd, Ord, PartialEq, Eq, Debug )]
+pub enum BinaryPrefix {
+	Nothing,
+	Kibi,
+	Mebi,
+	Gibi,
+	Tebi,
+	Pebi,
+	Exbi,
+	Zebi,
+	Yobi,
+}
+
+impl BinaryPrefix {
+	/// All binary prefixes ordered by their exponent from `BinaryPrefix::Nothing` to `BinaryPrefix::Yobi`.
+	pub const ALL: [BinaryPrefix; 9] = [
+		BinaryPrefix::Nothing,
+		BinaryPrefix::Kibi,
+		BinaryPrefix::Mebi,
+		BinaryPrefix::Gibi,
+		BinaryPrefix::Tebi,
+		BinaryPrefix::Pebi,
+		BinaryPrefix::Exbi,
+		BinaryPrefix::Zebi,
+		BinaryPrefix::Yobi,
+	];
+
+	/// Returns an iterator over all binary prefixes ordered by their exponent.
+	pub fn all() -> impl Iterator<Item = BinaryPrefix> {
+		Self::ALL.into_iter()
+	}
+
+	/// Returns the exponent of 1024 represented by this prefix.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::BinaryPrefix;
+	/// assert_eq!( BinaryPrefix::Nothing.exp(), 0 );
+	/// assert_eq!( BinaryPrefix::Mebi.exp(), 2 );
+	/// ```
+	pub const fn exp( &self ) -> u8 {
+		*self as u8
+	}
+
+	/// Return the factor represented by this prefix.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::BinaryPrefix;
+	/// assert_eq!( BinaryPrefix::Kibi.as_f64(), 1024.0 );
+	/// assert_eq!( BinaryPrefix::Mebi.as_f64(), 1_048_576.0 );
+	/// ```
+	pub fn as_f64( &self ) -> f64 {
+		1024f64.powi( self.exp() as i32 )
+	}
+
+	/// Returns the symbol of the prefix like "Ki" or "Mi". `BinaryPrefix::Nothing` results in an empty string.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::BinaryPrefix;
+	/// assert_eq!( BinaryPrefix::Kibi.to_string_sym(), "Ki" );
+	/// assert_eq!( BinaryPrefix::Nothing.to_string_sym(), "" );
+	/// ```
+	pub fn to_string_sym( &self ) -> String {
+		let res = match self {
+			Self::Nothing => "",
+			Self::Kibi =>    "Ki",
+			Self::Mebi =>    "Mi",
+			Self::Gibi =>    "Gi",
+			Self::Tebi =>    "Ti",
+			Self::Pebi =>    "Pi",
+			Self::Exbi =>    "Ei",
+			Self::Zebi =>    "Zi",
+			Self::Yobi =>    "Yi",
+		};
+
+		res.to_string()
+	}
+}
+
+impl fmt::Display for BinaryPrefix {
+	fn fmt( &self, f: &mut fmt::Formatter ) -> fmt::Result {
+		match self {
+			Self::Nothing => write!( f, "" ),
+			Self::Kibi =>    write!( f, "kibi" ),
+			Self::Mebi =>    write!( f, "mebi" ),
+			Self::Gibi =>    write!( f, "gibi" ),
+			Self::Tebi =>    write!( f, "tebi" ),
+			Self::Pebi =>    write!( f, "pebi" ),
+			Self::Exbi =>    write!( f, "exbi" ),
+			Self::Zebi =>    write!( f, "zebi" ),
+			Self::Yobi =>    write!( f, "yobi" ),
+		}
+	}
+}
+
+
+
+
+//=============================================================================
+// Structs
+
+
+/// Represents a set of prefixes, e.g. to restrict the prefixes chosen by `Qty::shortened_with()`.
+///
+/// # Example
+/// ```
+/// # use sinum::{Prefix, PrefixSet};
+/// let set = PrefixSet::thousands();
+///
+/// assert!( set.contains( Prefix::Milli ) );
+/// assert!( !set.contains( Prefix::Centi ) );
+/// assert!( set.with( Prefix::Centi ).contains( Prefix::Centi ) );
+///
+/// let set: PrefixSet = [ Prefix::Nothing, Prefix::Kilo ].into_iter().collect();
+/// assert_eq!( set.iter().collect::<Vec<Prefix>>(), vec![ Prefix::Nothing, Prefix::Kilo ] );
+/// ```
+#[derive( Clone, Copy, PartialEq, Eq, Hash, Default, Debug )]
+pub struct PrefixSet( u32 );
+
+impl PrefixSet {
+	/// Returns a set not containing any prefix.
+	pub const fn empty() -> Self {
+		Self( 0 )
+	}
+
+	/// Returns a set containing all prefixes, including `Prefix::Nothing`.
+	pub const fn all() -> Self {
+		Self( ( 1 << Prefix::ALL.len() ) - 1 )
+	}
+
+	/// Returns a set containing all prefixes whose exponent is a multiple of 3, which excludes `Prefix::Centi`, `Prefix::Deci`, `Prefix::Deca` and `Prefix::Hecto`. `Prefix::Nothing` is part of the set.
+	pub const fn thousands() -> Self {
+		Self::all()
+			.without( Prefix::Centi )
+			.without( Prefix::Deci )
+			.without( Prefix::Deca )
+			.without( Prefix::Hecto )
+	}
+
+	/// Returns the set with `prefix` added.
+	pub const fn with( self, prefix: Prefix ) -> Self {
+		Self( self.0 | Self::bit( prefix ) )
+	}
+
+	/// Returns the set with `prefix` removed.
+	pub const fn without( self, prefix: Prefix ) -> Self {
+		Self( self.0 & !Self::bit( prefix ) )
+	}
+
+	/// Returns `true` if `prefix` is part of the set.
+	pub const fn contains( &self, prefix: Prefix ) -> bool {
+		self.0 & Self::bit( prefix ) != 0
+	}
+
+	/// Returns `true` if the set does not contain any prefix.
+	pub const fn is_empty( &self ) -> bool {
+		self.0 == 0
+	}
+
+	/// Returns an iterator over the prefixes of the set ordered by their exponent.
+	pub fn iter( &self ) -> impl Iterator<Item = Prefix> + '_ {
+		Prefix::all().filter( |x| self.contains( *x ) )
+	}
+
+	/// Returns the bit representing `prefix`. The bits are ordered like the variants of `Prefix`.
+	const fn bit( prefix: Prefix ) -> u32 {
+		1 << prefix as u32
+	}
+}
+
+impl FromIterator<Prefix> for PrefixSet {
+	fn from_iter<I: IntoIterator<Item = Prefix>>( iter: I ) -> Self {
+		iter.into_iter().fold( Self::empty(), |set, x| set.with( x ) )
+	}
+}
+
+
 
 
 //=============================================================================
@@ -411,6 +742,60 @@ impl LatexSym for Prefix {
 mod tests {
 	use super::*;
 
+	#[test]
+	fn prefix_const() {
+		const FACTOR: f64 = Prefix::Kilo.as_f64();
+		const EXP: i8 = Prefix::Micro.exp();
+		const FACTORS: [f64; 3] = [ Prefix::Milli.as_f64(), Prefix::Nothing.as_f64(), Prefix::Mega.as_f64() ];
+
+		assert_eq!( FACTOR, 1e3 );
+		assert_eq!( EXP, -6 );
+		assert_eq!( FACTORS, [ 1e-3, 1.0, 1e6 ] );
+	}
+
+	#[test]
+	fn binary_prefix() {
+		assert_eq!( BinaryPrefix::all().count(), 9 );
+		for ( i, prefix ) in BinaryPrefix::all().enumerate() {
+			assert_eq!( prefix.exp() as usize, i );
+			assert_eq!( prefix.as_f64(), 2f64.powi( 10 * i as i32 ) );
+		}
+		assert_eq!( BinaryPrefix::Gibi.to_string_sym(), "Gi" );
+		assert_eq!( BinaryPrefix::Gibi.to_string(), "gibi" );
+		assert_eq!( BinaryPrefix::Nothing.to_string(), "" );
+		assert!( BinaryPrefix::Kibi < BinaryPrefix::Mebi );
+	}
+
+	#[test]
+	fn prefix_set() {
+		assert!( PrefixSet::empty().is_empty() );
+		assert_eq!( PrefixSet::default(), PrefixSet::empty() );
+		assert_eq!( PrefixSet::all().iter().collect::<Vec<Prefix>>(), Prefix::ALL.to_vec() );
+		assert_eq!( PrefixSet::thousands().iter().count(), 21 );
+		assert!( PrefixSet::thousands().iter().all( |x| x.exp() % 3 == 0 ) );
+		assert!( PrefixSet::thousands().contains( Prefix::Nothing ) );
+
+		let set = PrefixSet::empty().with( Prefix::Kilo ).with( Prefix::Milli ).with( Prefix::Kilo );
+		assert_eq!( set.iter().collect::<Vec<Prefix>>(), vec![ Prefix::Milli, Prefix::Kilo ] );
+		assert!( set.without( Prefix::Kilo ).contains( Prefix::Milli ) );
+		assert!( !set.without( Prefix::Kilo ).contains( Prefix::Kilo ) );
+		assert_eq!( set, [ Prefix::Kilo, Prefix::Milli ].into_iter().collect() );
+		assert!( PrefixSet::all().contains( Prefix::Quecto ) );
+		assert!( PrefixSet::all().contains( Prefix::Quetta ) );
+	}
+
+	#[cfg( feature = "tex" )]
+	#[test]
+	fn prefix_latex() {
+		assert_eq!( Prefix::Deci.to_latex_sym( &TexOptions::none() ), r"\deci" );
+		assert_eq!( Prefix::Deca.to_latex_sym( &TexOptions::none() ), r"\deca" );
+
+		// The `{siunitx}` commands are named like the prefixes.
+		for prefix in Prefix::all().filter( |x| *x != Prefix::Nothing ) {
+			assert_eq!( prefix.to_latex_sym( &TexOptions::none() ), format!( r"\{}", prefix ) );
+		}
+	}
+
 	#[test]
 	fn print_prefix() {
 		assert_eq!( Prefix::Peta.to_string(), "peta".to_string() );
@@ -418,4 +803,73 @@ mod tests {
 		assert_eq!( Prefix::Femto.to_string(), "femto".to_string() );
 		assert_eq!( Prefix::Femto.to_string_sym(), "f".to_string() );
 	}
+
+	#[test]
+	fn prefix_all() {
+		assert_eq!( Prefix::all().count(), 25 );
+		assert_eq!( Prefix::all().next().unwrap().exp(), Prefix::MIN_EXP );
+		assert_eq!( Prefix::all().last().unwrap().exp(), Prefix::MAX_EXP );
+		assert!( Prefix::all().collect::<Vec<Prefix>>().windows( 2 ).all( |x| x[0].exp() < x[1].exp() ) );
+		assert!( Prefix::all().collect::<Vec<Prefix>>().windows( 2 ).all( |x| x[0] < x[1] ) );
+
+		for exp in Prefix::MIN_EXP..=Prefix::MAX_EXP {
+			assert_eq!( Prefix::try_from( exp ).is_ok(), Prefix::all().any( |x| x.exp() == exp ) );
+		}
+	}
+
+	#[test]
+	fn prefix_from_symbol() {
+		let prefixes = [
+			Prefix::Quecto, Prefix::Ronto, Prefix::Yocto, Prefix::Zepto, Prefix::Atto, Prefix::Femto, Prefix::Pico, Prefix::Nano, Prefix::Micro, Prefix::Milli, Prefix::Centi, Prefix::Deci,
+			Prefix::Deca, Prefix::Hecto, Prefix::Kilo, Prefix::Mega, Prefix::Giga, Prefix::Tera, Prefix::Peta, Prefix::Exa, Prefix::Zetta, Prefix::Yotta, Prefix::Ronna, Prefix::Quetta,
+		];
+		for prefix in prefixes {
+			assert_eq!( Prefix::from_symbol( &prefix.to_string_sym() ).unwrap(), prefix );
+		}
+
+		assert_eq!( Prefix::from_symbol( "\u{00B5}" ).unwrap(), Prefix::Micro );
+		assert_eq!( Prefix::from_symbol( "\u{03BC}" ).unwrap(), Prefix::Micro );
+		assert_eq!( Prefix::from_symbol( "u" ).unwrap(), Prefix::Micro );
+		assert_eq!( Prefix::Micro.to_string_sym(), "\u{00B5}" );
+
+		assert!( Prefix::from_symbol( "" ).is_err() );
+		assert!( Prefix::from_symbol( "U" ).is_err() );
+		assert!( Prefix::from_symbol( "x" ).is_err() );
+		assert!( Prefix::from_symbol( "DA" ).is_err() );
+		assert!( Prefix::from_symbol( "kilo" ).is_err() );
+	}
+
+	#[test]
+	fn prefix_checked_add_exp() {
+		assert_eq!( Prefix::Kilo.checked_add_exp( Prefix::Kilo ).unwrap(), Prefix::Mega );
+		assert_eq!( Prefix::Milli.checked_add_exp( Prefix::Micro ).unwrap(), Prefix::Nano );
+		assert_eq!( Prefix::Deca.checked_add_exp( Prefix::Deci ).unwrap(), Prefix::Nothing );
+		assert_eq!( Prefix::Centi.checked_add_exp( Prefix::Deci ).unwrap(), Prefix::Milli );
+
+		for prefix in Prefix::all() {
+			assert_eq!( prefix.checked_add_exp( Prefix::Nothing ).unwrap(), prefix );
+		}
+
+		assert!( matches!( Prefix::Centi.checked_add_exp( Prefix::Centi ), Err( PrefixError::TryFromExp( -4 ) ) ) );
+		assert!( matches!( Prefix::Kilo.checked_add_exp( Prefix::Hecto ), Err( PrefixError::TryFromExp( 5 ) ) ) );
+		assert!( matches!( Prefix::Quetta.checked_add_exp( Prefix::Quetta ), Err( PrefixError::TryFromExp( 60 ) ) ) );
+	}
+
+	#[test]
+	fn prefix_try_from_exp_snap() {
+		assert_eq!( Prefix::try_from_exp_snap( 4, RoundMode::Floor ), Prefix::Kilo );
+		assert_eq!( Prefix::try_from_exp_snap( 5, RoundMode::Floor ), Prefix::Kilo );
+		assert_eq!( Prefix::try_from_exp_snap( 7, RoundMode::Floor ), Prefix::Mega );
+		assert_eq!( Prefix::try_from_exp_snap( 4, RoundMode::Ceil ), Prefix::Mega );
+		assert_eq!( Prefix::try_from_exp_snap( 5, RoundMode::Ceil ), Prefix::Mega );
+		assert_eq!( Prefix::try_from_exp_snap( 7, RoundMode::Ceil ), Prefix::Giga );
+		assert_eq!( Prefix::try_from_exp_snap( 4, RoundMode::Nearest ), Prefix::Kilo );
+		assert_eq!( Prefix::try_from_exp_snap( 5, RoundMode::Nearest ), Prefix::Mega );
+		assert_eq!( Prefix::try_from_exp_snap( 7, RoundMode::Nearest ), Prefix::Mega );
+		assert_eq!( Prefix::try_from_exp_snap( -4, RoundMode::Floor ), Prefix::Micro );
+		assert_eq!( Prefix::try_from_exp_snap( -4, RoundMode::Ceil ), Prefix::Milli );
+		assert_eq!( Prefix::try_from_exp_snap( 2, RoundMode::Floor ), Prefix::Hecto );
+		assert_eq!( Prefix::try_from_exp_snap( 33, RoundMode::Ceil ), Prefix::Quetta );
+		assert_eq!( Prefix::try_from_exp_snap( -33, RoundMode::Floor ), Prefix::Quecto );
+	}
 }
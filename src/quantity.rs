@@ -8,20 +8,64 @@
 
 
 use std::cmp::Ordering;
+use std::collections::HashMap;
 use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::iter::Sum;
 use std::ops::{Add, Sub, Mul, MulAssign, Div, Neg};
+use std::str::FromStr;
 
 #[cfg( feature = "serde" )]
-use serde::{Serialize, Deserialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+#[cfg( feature = "serde" )]
+use serde::de::Error as DeError;
+
+#[cfg( feature = "i18n" )] use fluent_templates::Loader;
+#[cfg( feature = "i18n" )] use unic_langid::LanguageIdentifier;
 
+#[cfg( feature = "i18n" )] use crate::DisplayLocale;
+#[cfg( feature = "i18n" )] use crate::LOCALES;
 #[cfg( feature = "tex" )]
 use crate::{Latex, LatexSym};
 #[cfg( feature = "tex" )]
-use crate::TexOptions;
+use crate::{Notation, TexOptions};
 
+use thiserror::Error;
+
+use crate::number::{canonical, round_decimals, round_significant};
 use crate::prefix::PrefixError;
+use crate::RoundMode;
 use crate::unit::UnitError;
-use crate::{Num, Prefix, Unit, PhysicalQuantity};
+use crate::{BinaryPrefix, Num, Prefix, PrefixSet, Unit, PhysicalQuantity};
+use crate::{CLEAN_DISPLAY_DECIMALS, display_rounding};
+use crate::SinumError;
+
+
+
+
+//=============================================================================
+// Errors
+
+
+/// Errors specific to quantities. Fallible functions of `Qty` that may also fail due to a prefix or a unit return a `SinumError` wrapping this error instead.
+#[derive( Error, Debug )]
+pub enum QtyError {
+	#[error( "Not a finite value: `{0}`" )]
+	NotFinite( f64 ),
+
+	#[error( "The value `{0}` is below the physical minimum of `{1}`" )]
+	BelowMinimum( f64, f64 ),
+
+	#[error( "The weights add up to zero" )]
+	ZeroWeight,
+
+	#[error( "A prefix cannot be applied to the custom unit `{0}`" )]
+	PrefixedCustomUnit( Unit ),
+
+	#[error( "The quantity `{0}` cannot be parsed back from its text representation" )]
+	Unrepresentable( String ),
+
+}
 
 
 
@@ -31,7 +75,8 @@ use crate::{Num, Prefix, Unit, PhysicalQuantity};
 
 
 /// Represents a number in combination with a SI prefix.
-#[cfg_attr( feature = "serde", derive( Serialize, Deserialize ) )]
+///
+/// With the **`serde`** feature enabled, human-readable formats like JSON represent a `Qty` as string like "9.9 km". Other formats as well as quantities whose string cannot be parsed back into the same prefix and unit (like custom or compound units) are represented by the fields of the struct.
 #[derive( Clone, Debug )]
 pub struct Qty {
 	number: Num,
@@ -48,15 +93,16 @@ impl Qty {
 	/// assert_eq!( Qty::new( 99.9.into(), &Unit::Kelvin ).as_f64(), 99.9 );
 	/// ```
 	pub fn new( number: Num, unit: &Unit ) -> Self {
-		let ( num, uni ) = match unit {
+		// For compound units, the prefix applies to the leading unit.
+		let ( num, uni ) = match unit.leading().0 {
 			// The Kilogram as base unit must only be used if the number prefix is `Prefix::Nothing`. If the Prefix is anything else, the unit `Unit::Gram` must be used to correctly display the prefixes like "mg" or "ng".
 			Unit::Kilogram if number.prefix() != Prefix::Nothing => {
 				let exp_new = number.prefix().exp() + 3;
 				let prefix_new = Prefix::try_from( exp_new ).unwrap();
-				( number.with_prefix( prefix_new ), Unit::Gram )
+				( number.with_prefix( prefix_new ), unit.with_leading( Unit::Gram ) )
 			},
 			Unit::Gram if number.prefix() == Prefix::Kilo => {
-				( number.with_prefix( Prefix::Nothing ), Unit::Kilogram )
+				( number.with_prefix( Prefix::Nothing ), unit.with_leading( Unit::Kilogram ) )
 			},
 			_ => ( number, unit.clone() ),
 		};
@@ -67,16 +113,87 @@ impl Qty {
 		}
 	}
 
+	/// Returns a `QtyBuilder` starting with `value`, to construct a `Qty` step by step.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Qty, Num, Unit, Prefix};
+	/// let qty = Qty::builder( 9.9 ).prefix( Prefix::Milli ).unit( Unit::Ampere ).build();
+	///
+	/// assert_eq!( qty, Qty::new( Num::new( 9.9 ).with_prefix( Prefix::Milli ), &Unit::Ampere ) );
+	/// assert_eq!( qty.to_string(), "9.9 mA" );
+	/// ```
+	pub fn builder( value: f64 ) -> QtyBuilder {
+		QtyBuilder::new().value( value )
+	}
+
+	/// Create a new `Qty` like `new()`, but refusing combinations of `number` and `unit` that are not meaningful. Such combinations are:
+	///
+	/// * A mantissa that is not finite (NaN or infinite), resulting in `QtyError::NotFinite`.
+	/// * A prefix other than `Prefix::Nothing` applied to a custom unit (or a compound unit leading with a custom unit), resulting in `QtyError::PrefixedCustomUnit`. The name of a custom unit is arbitrary and might already contain a prefix.
+	///
+	/// In contrast to `try_new_checked()`, values below the physical minimum are accepted.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Qty, Num, Unit, Prefix};
+	/// let num = Num::new( 9.9 ).with_prefix( Prefix::Milli );
+	///
+	/// assert_eq!( Qty::try_new( num, &Unit::Ampere ).unwrap(), Qty::new( num, &Unit::Ampere ) );
+	/// assert!( Qty::try_new( Num::new( f64::NAN ), &Unit::Ampere ).is_err() );
+	/// assert!( Qty::try_new( num, &Unit::Custom( "widget".to_string() ) ).is_err() );
+	/// ```
+	pub fn try_new( number: Num, unit: &Unit ) -> Result<Self, SinumError> {
+		if !number.is_finite() {
+			return Err( QtyError::NotFinite( number.mantissa() ).into() );
+		}
+
+		if matches!( unit.leading().0, Unit::Custom( _ ) ) && number.prefix() != Prefix::Nothing {
+			return Err( QtyError::PrefixedCustomUnit( unit.clone() ).into() );
+		}
+
+		Ok( Self::new( number, unit ) )
+	}
+
+	/// Create a new `Qty` representing `value` in `unit` after validating `value`.
+	///
+	/// This constructor is meant to be used at API boundaries to sanitize input. It returns a `QtyError` if `value` is not finite (NaN or infinite) or if it lies below the physical minimum of the quantity measured by `unit` (e.g. a negative mass or a temperature below absolute zero).
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Qty, Unit};
+	/// assert_eq!( Qty::try_new_checked( 9.9, &Unit::Kilogram ).unwrap(), Qty::new( 9.9.into(), &Unit::Kilogram ) );
+	/// assert!( Qty::try_new_checked( f64::NAN, &Unit::Meter ).is_err() );
+	/// assert!( Qty::try_new_checked( -1.0, &Unit::Gram ).is_err() );
+	/// ```
+	pub fn try_new_checked( value: f64, unit: &Unit ) -> Result<Self, QtyError> {
+		if !value.is_finite() {
+			return Err( QtyError::NotFinite( value ) );
+		}
+
+		let qty = Self::new( value.into(), unit );
+
+		if let Some( min ) = qty.phys().minimum() {
+			if qty.as_f64() < min {
+				return Err( QtyError::BelowMinimum( qty.as_f64(), min ) );
+			}
+		}
+
+		Ok( qty )
+	}
+
 	/// Creates a new `Qty` from `self` with a reduced numbers of digits of the mantissa (see `mantissa()`) required to represent the number:
 	///
 	/// * No more than 3 digits in front of the decimal point.
-	///     (1234 s → 1.234 ks)
+	///   (1234 s → 1.234 ks)
 	///
 	/// * No zero in front of the decimal point.
-	///     (0.001 A → 1.0 mA)
+	///   (0.001 A → 1.0 mA)
 	///
 	/// This function will only modify the prefix, never the unit itself. (see `sorten_unit()`).
 	///
+	/// For compound units the prefix is raised to the exponent of the leading unit (1 km² = 10⁶ m²), so the mantissa is only guaranteed to lie between 1 and 1000ⁿ with `n` being the absolute value of this exponent.
+	///
 	/// # Example
 	/// ```
 	/// # use sinum::{Qty, Num, Unit, Prefix};
@@ -90,9 +207,282 @@ impl Qty {
 	/// );
 	/// ```
 	pub fn shortened( self ) -> Result<Self, PrefixError> {
-		let num = self.number.shortened()?;
+		let power = self.prefix_power();
+		if power == 1 {
+			let num = self.number.shortened()?;
+			return Ok( Self::new( num, self.unit() ) );
+		}
+
+		self.to_engineering()
+	}
+
+	/// Creates a new `Qty` from `self` like `shortened()`, but the prefix is chosen from `prefixes` only. The largest allowed prefix keeping the absolute value of the mantissa at 1 or above is used. If there is no such prefix, the smallest allowed prefix is used instead. A zero results in `Prefix::Nothing` if allowed.
+	///
+	/// If `prefixes` is empty, a `PrefixError::EmptySet` is returned.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Qty, Unit, Prefix, PrefixSet};
+	/// let qty = Qty::new( 0.05.into(), &Unit::Meter );
+	///
+	/// assert_eq!( qty.clone().shortened_with( PrefixSet::thousands() ).unwrap().to_string(), "50 mm" );
+	/// assert_eq!( qty.clone().shortened_with( PrefixSet::all() ).unwrap().to_string(), "5 cm" );
+	/// assert_eq!( qty.shortened_with( PrefixSet::empty().with( Prefix::Nothing ) ).unwrap().to_string(), "0.05 m" );
+	/// ```
+	pub fn shortened_with( self, prefixes: PrefixSet ) -> Result<Self, PrefixError> {
+		if prefixes.is_empty() {
+			return Err( PrefixError::EmptySet );
+		}
+
+		if self.number.mantissa() == 0.0 && prefixes.contains( Prefix::Nothing ) {
+			return Ok( self.to_prefix( Prefix::Nothing ) );
+		}
+
+		let candidates: Vec<Self> = prefixes.iter()
+			.map( |x| self.clone().to_prefix( x ) )
+			.collect();
+
+		// The smallest mantissa at or above 1 is the one of the largest prefix. For compound units with a negative exponent, the order of the prefixes is reversed, which is covered by comparing the mantissas.
+		let best = candidates.iter()
+			.filter( |x| x.number.mantissa().abs() >= 1.0 )
+			.min_by( |a, b| a.number.mantissa().abs().total_cmp( &b.number.mantissa().abs() ) )
+			.or_else( || candidates.iter().reduce( |a, b| if b.number.mantissa().abs() > a.number.mantissa().abs() { b } else { a } ) );
+
+		Ok( best.unwrap().clone() )
+	}
+
+	/// Returns the mantissa and the binary prefix representing `self` with the largest binary prefix that keeps the absolute value of the mantissa at 1 or above, like `shortened()` but using steps of 1024 instead of 1000. Values below 1 are returned with `BinaryPrefix::Nothing`, since there are no binary prefixes for factors below 1. The same applies to zero, infinite and NaN values.
+	///
+	/// Binary prefixes are not SI prefixes and cannot be stored inside a `Qty`. To display a quantity with a binary prefix, use `to_string_binary()`.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Qty, Num, Unit, Prefix, BinaryPrefix};
+	/// assert_eq!( Qty::new( 2048.0.into(), &Unit::Byte ).shortened_binary(), ( 2.0, BinaryPrefix::Kibi ) );
+	/// assert_eq!( Qty::new( Num::new( 3.0 ).with_prefix( Prefix::Mega ), &Unit::Byte ).shortened_binary().1, BinaryPrefix::Mebi );
+	/// assert_eq!( Qty::new( 512.0.into(), &Unit::Byte ).shortened_binary(), ( 512.0, BinaryPrefix::Nothing ) );
+	/// ```
+	pub fn shortened_binary( &self ) -> ( f64, BinaryPrefix ) {
+		let power = self.prefix_power();
+		let value = self.clone().to_prefix( Prefix::Nothing ).number.mantissa();
+
+		// The smallest mantissa at or above 1 is the one of the largest prefix. For compound units with a negative exponent, the order of the prefixes is reversed, which is covered by comparing the mantissas.
+		BinaryPrefix::all()
+			.map( |x| ( value / x.as_f64().powi( power ), x ) )
+			.filter( |( x, _ )| x.abs() >= 1.0 )
+			.min_by( |( a, _ ), ( b, _ )| a.abs().total_cmp( &b.abs() ) )
+			.unwrap_or( ( value, BinaryPrefix::Nothing ) )
+	}
+
+	/// Returns a string representation of the quantity like `to_string()`, but using the binary prefix chosen by `shortened_binary()`, like "2 KiB". The mantissa is rounded as set by `set_display_rounding()`.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Qty, Num, Unit, Prefix};
+	/// assert_eq!( Qty::new( 2048.0.into(), &Unit::Byte ).to_string_binary(), "2 KiB" );
+	/// assert_eq!( Qty::new( 1536.0.into(), &Unit::Bit ).to_string_binary(), "1.5 Kibit" );
+	/// assert_eq!( Qty::new( Num::new( 3.0 ).with_prefix( Prefix::Kilo ), &Unit::Byte ).to_string_binary(), "2.9296875 KiB" );
+	/// ```
+	pub fn to_string_binary( &self ) -> String {
+		let ( mantissa, prefix ) = self.shortened_binary();
+		let number = Num::new( mantissa ).to_string_rounded( display_rounding() );
+		self.join_prefixed_symbol( number, &prefix.to_string_sym(), ' ' )
+	}
+
+	/// Creates a new `Qty` from `self` with a prefix whose exponent is a multiple of 3, choosing the largest of these prefixes that keeps the absolute value of the mantissa at 1 or above. This is the engineering notation, so `Prefix::Centi`, `Prefix::Deci`, `Prefix::Deca` and `Prefix::Hecto` are never used, regardless of the prefix of `self`.
+	///
+	/// For compound units the prefix is raised to the exponent of the leading unit like in `shortened()`. A zero results in `Prefix::Nothing`. If the value is beyond the range of the SI prefixes, a `PrefixError` is returned.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Qty, Num, Unit, Prefix};
+	/// assert_eq!( Qty::new( 0.5.into(), &Unit::Meter ).to_engineering().unwrap().to_string(), "500 mm" );
+	/// assert_eq!( Qty::new( Num::new( 5.0 ).with_prefix( Prefix::Deci ), &Unit::Meter ).to_engineering().unwrap().to_string(), "500 mm" );
+	/// assert_eq!( Qty::new( Num::new( 25.0 ).with_prefix( Prefix::Hecto ), &Unit::Meter ).to_engineering().unwrap().to_string(), "2.5 km" );
+	/// ```
+	pub fn to_engineering( self ) -> Result<Self, PrefixError> {
+		let power = self.prefix_power();
+		let value = self.clone().to_prefix( Prefix::Nothing ).number.mantissa();
+		if value == 0.0 {
+			return Ok( self.to_prefix( Prefix::Nothing ) );
+		}
+
+		// Choosing the largest prefix that keeps the mantissa at 1 or above.
+		let steps = value.abs().log10() / ( 3 * power ) as f64;
+		let steps = if power > 0 { steps.floor() } else { steps.ceil() };
+		let exp = steps * 3.0;
+
+		if exp.abs() > Prefix::MAX_EXP as f64 {
+			return Err( PrefixError::ExpInvalid( exp as i32 ) );
+		}
+
+		let prefix_new = Prefix::try_from( exp as i8 )?;
+
+		Ok( self.to_prefix( prefix_new ) )
+	}
+
+	/// Creates a new `Qty` from `self` like `shortened()`, but instead of returning an error for values beyond the range of the SI prefixes, `Prefix::Quetta` or `Prefix::Quecto` respectively is used. This never fails and normalizes every value to the best prefix available.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Qty, Num, Unit, Prefix};
+	/// let huge = Qty::new( 1e40.into(), &Unit::Meter );
+	/// assert!( huge.clone().shortened().is_err() );
+	/// assert_eq!( huge.shortened_clamped().number().prefix(), Prefix::Quetta );
+	///
+	/// assert_eq!( Qty::new( 1e-40.into(), &Unit::Meter ).shortened_clamped().number().prefix(), Prefix::Quecto );
+	/// assert_eq!( Qty::new( 1500.0.into(), &Unit::Meter ).shortened_clamped().to_string(), "1.5 km" );
+	/// ```
+	pub fn shortened_clamped( self ) -> Self {
+		match self.clone().shortened() {
+			Ok( x ) => x,
+			Err( _ ) if self.number.as_f64().abs() >= 1.0 => self.to_prefix( Prefix::Quetta ),
+			Err( _ ) => self.to_prefix( Prefix::Quecto ),
+		}
+	}
+
+	/// Creates a new `Qty` from `self` like `to_prefix()`, but the mantissa is kept a normal floating point number instead of overflowing to infinity or underflowing to a subnormal number or zero. If `prefix` is too far away from the current prefix, the prefix closest to `prefix` that keeps the mantissa normal is used instead.
+	///
+	/// Zero, infinite and NaN values are converted like in `to_prefix()`.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Qty, Num, Unit, Prefix};
+	/// let huge = Qty::new( 1e300.into(), &Unit::Meter );
+	/// assert!( huge.clone().to_prefix( Prefix::Quecto ).number().mantissa().is_infinite() );
+	/// assert_eq!( huge.saturating_to_prefix( Prefix::Quecto ).number().prefix(), Prefix::Micro );
+	///
+	/// assert_eq!( Qty::new( 1500.0.into(), &Unit::Meter ).saturating_to_prefix( Prefix::Kilo ).to_string(), "1.5 km" );
+	/// ```
+	pub fn saturating_to_prefix( self, prefix: Prefix ) -> Self {
+		if !self.number.mantissa().is_normal() {
+			return self.to_prefix( prefix );
+		}
+
+		// Moving from `prefix` towards the current prefix, which is known to result in a normal mantissa.
+		let current = self.number.prefix();
+		let ( low, high ) = ( prefix.exp().min( current.exp() ), prefix.exp().max( current.exp() ) );
+		let mut candidates: Vec<Prefix> = Prefix::all()
+			.filter( |x| ( low..=high ).contains( &x.exp() ) )
+			.collect();
+		if prefix.exp() > current.exp() {
+			candidates.reverse();
+		}
+
+		candidates.into_iter()
+			.map( |x| self.clone().to_prefix( x ) )
+			.find( |x| x.number.mantissa().is_normal() )
+			.unwrap_or( self )
+	}
+
+	/// Returns the largest prefix whose factor is not larger than the absolute value of `self`. This can be used to group quantities into bins by their order of magnitude. All prefixes are considered, including `Prefix::Centi`, `Prefix::Deci`, `Prefix::Deca` and `Prefix::Hecto`.
+	///
+	/// For compound units the factor of the prefix is raised to the exponent of the leading unit. Values beyond the range of the SI prefixes result in `Prefix::Quetta` or `Prefix::Quecto` respectively. A zero, infinite or NaN value results in `Prefix::Nothing`.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Qty, Unit, Prefix};
+	/// assert_eq!( Qty::new( 1500.0.into(), &Unit::Meter ).floor_to_prefix(), Prefix::Kilo );
+	/// assert_eq!( Qty::new( 0.002.into(), &Unit::Meter ).floor_to_prefix(), Prefix::Milli );
+	/// assert_eq!( Qty::new( 50.0.into(), &Unit::Meter ).floor_to_prefix(), Prefix::Deca );
+	/// ```
+	pub fn floor_to_prefix( &self ) -> Prefix {
+		self.prefix_bound( RoundMode::Floor )
+	}
+
+	/// Returns the smallest prefix whose factor is not smaller than the absolute value of `self`. Behaves like `floor_to_prefix()` otherwise.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Qty, Unit, Prefix};
+	/// assert_eq!( Qty::new( 1500.0.into(), &Unit::Meter ).ceil_to_prefix(), Prefix::Mega );
+	/// assert_eq!( Qty::new( 0.002.into(), &Unit::Meter ).ceil_to_prefix(), Prefix::Centi );
+	/// assert_eq!( Qty::new( 1000.0.into(), &Unit::Meter ).ceil_to_prefix(), Prefix::Kilo );
+	/// ```
+	pub fn ceil_to_prefix( &self ) -> Prefix {
+		self.prefix_bound( RoundMode::Ceil )
+	}
+
+	/// Returns the prefix bounding the absolute value of `self` in the direction of `round`. Is used by `floor_to_prefix()` and `ceil_to_prefix()`.
+	fn prefix_bound( &self, round: RoundMode ) -> Prefix {
+		let value = self.clone().to_prefix( Prefix::Nothing ).number.mantissa().abs();
+		if value == 0.0 || !value.is_finite() {
+			return Prefix::Nothing;
+		}
+
+		// A negative power reverses the order of the prefix factors.
+		let power = self.prefix_power();
+		let round = match ( power < 0, round ) {
+			( true, RoundMode::Floor ) => RoundMode::Ceil,
+			( true, RoundMode::Ceil ) => RoundMode::Floor,
+			( _, x ) => x,
+		};
+
+		let exp = value.log10() / power as f64;
+		let exp = match round {
+			RoundMode::Floor => exp.floor(),
+			RoundMode::Ceil => exp.ceil(),
+			RoundMode::Nearest => exp.round(),
+		};
+
+		Prefix::try_from_exp_snap( exp.clamp( Prefix::MIN_EXP as f64, Prefix::MAX_EXP as f64 ) as i8, round )
+	}
+
+	/// Returns `true` if `self` expressed with `prefix` results in a mantissa as returned by `shortened()`, which means its absolute value lies between 1 (inclusive) and 1000 (exclusive). For compound units the upper bound is raised to the exponent of the leading unit. A zero fits only `Prefix::Nothing`.
+	///
+	/// This can be used to check if a value can be represented in the range of the SI prefixes at all.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Qty, Num, Unit, Prefix};
+	/// let qty = Qty::new( 1500.0.into(), &Unit::Meter );
+	/// assert!( qty.fits_prefix( Prefix::Kilo ) );
+	/// assert!( !qty.fits_prefix( Prefix::Mega ) );
+	/// assert!( !qty.fits_prefix( Prefix::Nothing ) );
+	///
+	/// assert!( !Qty::new( 1e40.into(), &Unit::Meter ).fits_prefix( Prefix::Quetta ) );
+	/// ```
+	pub fn fits_prefix( &self, prefix: Prefix ) -> bool {
+		let mantissa = self.clone().to_prefix( prefix ).number.mantissa().abs();
+
+		if mantissa == 0.0 {
+			return prefix == Prefix::Nothing;
+		}
+
+		// Allowing for floating point errors introduced by the conversion.
+		let upper = 1000_f64.powi( self.prefix_power().abs() );
+		mantissa >= 1.0 - 1e-12 && mantissa < upper * ( 1.0 - 1e-12 )
+	}
+
+	/// Creates a new `Qty` from `self` that is best suited for display. The following steps are applied in order:
+	///
+	/// 1. Unit selection: Masses in gram, kilogram or tonne are expressed in tonne if they amount to at least 1000 kg and in gram (or kilogram) otherwise. Other units are kept.
+	/// 2. Shortening: The prefix is chosen like in `shortened()` but with respect to the absolute value, so negative values are shortened like positive ones.
+	/// 3. Sign normalization: A negative zero becomes a positive zero.
+	///
+	/// Applying `normalized()` to an already normalized `Qty` does not change it.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Qty, Num, Unit, Prefix};
+	/// assert_eq!( Qty::new( 1500.0.into(), &Unit::Gram ).normalized().unwrap().to_string(), "1.5 kg" );
+	/// assert_eq!( Qty::new( 2500.0.into(), &Unit::Kilogram ).normalized().unwrap().to_string(), "2.5 t" );
+	/// assert_eq!( Qty::new( Num::new( -1500.0 ), &Unit::Meter ).normalized().unwrap().to_string(), "-1.5 km" );
+	/// ```
+	pub fn normalized( self ) -> Result<Self, SinumError> {
+		let qty = match self.unit {
+			Unit::Gram | Unit::Kilogram | Unit::Tonne => {
+				let unit = if self.as_f64().abs() >= 1e3 { Unit::Tonne } else { Unit::Gram };
+				self.to_unit( &unit )?.to_prefix( Prefix::Nothing )
+			},
+			_ => self,
+		};
+
+		let sign = if qty.number.as_f64() < 0.0 { -1.0 } else { 1.0 };
+		let qty_abs = Self::new( qty.number.abs(), &qty.unit ).shortened()?;
 
-		Ok( Self::new( num, self.unit() ) )
+		// `Num::shortened()` always returns a positive zero.
+		Ok( Self::new( qty_abs.number * sign, &qty_abs.unit ) )
 	}
 
 	/// Returns the numeric value of the `Qty` without any prefix or unit.
@@ -104,7 +494,62 @@ impl Qty {
 	/// assert_eq!( Qty::new( 99.9.into(), &Unit::Kelvin ).as_f64(), 99.9 );
 	/// ```
 	pub fn as_f64( &self ) -> f64 {
-		self.number.as_f64() * self.unit.factor()
+		let number = self.number.mantissa() * self.number.prefix().as_f64().powi( self.prefix_power() );
+		number * self.unit.factor() + self.unit.offset()
+	}
+
+	/// Returns the base quantities the physical quantity of `self` is composed of, raised to the respective (non-zero) exponent. A dimensionless quantity results in an empty list.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Qty, Unit, PhysicalQuantity};
+	/// let energy = Qty::new( 1.0.into(), &Unit::Joule );
+	/// assert_eq!( energy.base_components(), vec![
+	///     ( PhysicalQuantity::Mass, 1 ),
+	///     ( PhysicalQuantity::Length, 2 ),
+	///     ( PhysicalQuantity::Time, -2 ),
+	/// ] );
+	///
+	/// assert_eq!( Qty::new( 1.0.into(), &Unit::Meter ).base_components(), vec![ ( PhysicalQuantity::Length, 1 ) ] );
+	/// ```
+	pub fn base_components( &self ) -> Vec<( PhysicalQuantity, i8 )> {
+		self.phys().dimensions()
+	}
+
+	/// Returns `true` if the numeric value of `self` is NaN.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Qty, Unit};
+	/// assert!( ( Qty::new( 0.0.into(), &Unit::Ampere ) / 0.0 ).is_nan() );
+	/// assert!( !Qty::new( 1.0.into(), &Unit::Ampere ).is_nan() );
+	/// ```
+	pub fn is_nan( &self ) -> bool {
+		self.number.is_nan()
+	}
+
+	/// Returns `true` if the numeric value of `self` is positive or negative infinity.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Qty, Unit};
+	/// assert!( ( Qty::new( 1.0.into(), &Unit::Ampere ) / 0.0 ).is_infinite() );
+	/// assert!( !Qty::new( 1.0.into(), &Unit::Ampere ).is_infinite() );
+	/// ```
+	pub fn is_infinite( &self ) -> bool {
+		self.number.is_infinite()
+	}
+
+	/// Returns `true` if the numeric value of `self` is neither infinite nor NaN.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Qty, Unit};
+	/// assert!( Qty::new( 1.0.into(), &Unit::Ampere ).is_finite() );
+	/// assert!( !( Qty::new( 1.0.into(), &Unit::Ampere ) / 0.0 ).is_finite() );
+	/// ```
+	pub fn is_finite( &self ) -> bool {
+		self.number.is_finite()
 	}
 
 	/// Returns the numeric `Num` of the `Qty`.
@@ -129,11 +574,41 @@ impl Qty {
 		&self.unit
 	}
 
+	/// Returns the mantissa, the prefix and the unit of the `Qty`.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Num, Qty, Unit, Prefix};
+	/// let qty = Qty::new( Num::new( 9.9 ).with_prefix( Prefix::Milli ), &Unit::Ampere );
+	///
+	/// assert_eq!( qty.parts(), ( 9.9, Prefix::Milli, &Unit::Ampere ) );
+	/// ```
+	pub fn parts( &self ) -> ( f64, Prefix, &Unit ) {
+		( self.number.mantissa(), self.number.prefix(), &self.unit )
+	}
+
+	/// Returns the physical quantity that is represented by the `Qty`. Quantities representing the same physical quantity can be added, subtracted and compared to each other.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Qty, Unit, PhysicalQuantity};
+	/// assert_eq!( Qty::new( 9.9.into(), &Unit::Tonne ).quantity(), PhysicalQuantity::Mass );
+	/// assert_eq!( ( Qty::new( 1.0.into(), &Unit::Meter ) / Qty::new( 1.0.into(), &Unit::Second ) ).quantity(), PhysicalQuantity::Compound( vec![ ( PhysicalQuantity::Length, 1 ), ( PhysicalQuantity::Time, -1 ) ] ) );
+	/// ```
+	pub fn quantity( &self ) -> PhysicalQuantity {
+		self.phys()
+	}
+
 	/// Returns the physical quantity that is represented by the `Qty`.
 	fn phys( &self ) -> PhysicalQuantity {
 		self.unit.phys()
 	}
 
+	/// Returns the exponent the prefix of the number is raised to. This is 1 except for compound units whose leading unit is raised to a power.
+	fn prefix_power( &self ) -> i32 {
+		self.unit.leading().1 as i32
+	}
+
 	/// Creates a new `Qty` from `self` at the specified `prefix`.
 	///
 	/// The numeric value of the new `Qty` will be identical to `self` (apart from possible floating point rounding errors) since the mantissa is being modified alongside the prefix to reflect the same numeric value as before.
@@ -151,14 +626,64 @@ impl Qty {
 	/// assert_eq!( qty.to_prefix( Prefix::Kilo ).number().mantissa(), 0.002 );
 	/// ```
 	pub fn to_prefix( self, prefix: Prefix ) -> Self {
-		let number = self.number.to_prefix( prefix );
+		let number = match self.prefix_power() {
+			1 => self.number.to_prefix( prefix ),
+			x => {
+				let factor = ( self.number.prefix().as_f64() / prefix.as_f64() ).powi( x );
+				Num::new( self.number.mantissa() * factor ).with_prefix( prefix )
+			},
+		};
 		Self::new( number, &self.unit )
 	}
 
+	/// Creates a new `Qty` from `self` with the prefix replaced by `prefix`, keeping the mantissa.
+	///
+	/// **Note:** In contrast to `to_prefix()`, this **changes the numeric value** of the quantity, just like `Num::with_prefix()`. Use `to_prefix()` to express the same value with a different prefix.
+	///
+	/// For masses, `prefix` replaces the prefix as displayed, so a kilogram becomes a gram with the new prefix: "2 kg" with `Prefix::Milli` results in "2 mg".
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Qty, Num, Prefix, Unit};
+	/// let qty = Qty::new( 2.0.into(), &Unit::Meter );
+	///
+	/// assert_eq!( qty.clone().with_prefix( Prefix::Milli ).to_string(), "2 mm" );
+	/// assert_eq!( qty.clone().with_prefix( Prefix::Milli ).as_f64(), 0.002 );
+	/// assert_eq!( qty.to_prefix( Prefix::Milli ).to_string(), "2000 mm" );
+	///
+	/// assert_eq!( Qty::new( 2.0.into(), &Unit::Kilogram ).with_prefix( Prefix::Milli ).to_string(), "2 mg" );
+	/// ```
+	pub fn with_prefix( self, prefix: Prefix ) -> Self {
+		let unit = match self.unit.leading().0 {
+			Unit::Kilogram => self.unit.with_leading( Unit::Gram ),
+			_ => self.unit,
+		};
+		Self::new( Num::new( self.number.mantissa() ).with_prefix( prefix ), &unit )
+	}
+
+	/// Returns `self` expressed in the base unit of its unit (see [`Unit::base`]) without a prefix. This is useful to normalize quantities before storing them.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Qty, Num, Prefix, Unit};
+	/// let qty = Qty::new( 9.9.into(), &Unit::Tonne ).to_base();
+	/// assert_eq!( qty.unit(), &Unit::Kilogram );
+	/// assert_eq!( qty.number().mantissa(), 9900.0 );
+	///
+	/// let qty = Qty::new( Num::new( 2.0 ).with_prefix( Prefix::Milli ), &Unit::Gram ).to_base();
+	/// assert_eq!( qty.unit(), &Unit::Kilogram );
+	/// assert_eq!( qty.number().prefix(), Prefix::Nothing );
+	/// ```
+	pub fn to_base( &self ) -> Self {
+		Self::new( self.as_f64().into(), &self.unit.base() )
+	}
+
 	/// Returns a new `Qty` from `self` with the new `unit`.
 	///
 	/// If `unit` does not represent the same physical quantity as the original unit, this function returns an `UnitError`.
 	///
+	/// Affine units like degree Celsius are converted by scaling first and applying the offset between the zero points of both units afterwards.
+	///
 	/// # Example
 	/// ```
 	/// # use sinum::{Qty, Unit};
@@ -171,530 +696,3066 @@ impl Qty {
 			return Err( UnitError::UnitMismatch( vec![ self.unit().clone(), unit.clone() ] ) );
 		};
 
-		let factor_old = self.unit().factor();
-		let factor_new = unit.factor();
-		let factor = factor_old / factor_new;
-		let num_new = self.number() * factor;
+		// The meaning of the prefix changes, if it is raised to a different power in the new unit.
+		if self.unit().leading().1 != unit.leading().1 && self.number().prefix() != Prefix::Nothing {
+			let prefix = self.number().prefix();
+			return Ok( self.clone().to_prefix( Prefix::Nothing ).to_unit( unit )?.to_prefix( prefix ) );
+		}
+
+		let ( factor, offset ) = self.conversion( unit );
+		let num_new = self.number() * factor + offset;
 
 		Ok( Self::new( num_new, unit ) )
 	}
 
-	/// Computes the absolute value of `self` with respect to the base unit. This means 10.0 t are returned as 10e3.
+	/// Returns the factor and the offset converting a value in the unit of `self` into `unit`. The offset is only relevant for affine units.
+	fn conversion( &self, unit: &Unit ) -> ( f64, f64 ) {
+		let factor_new = unit.factor();
+		let factor = self.unit().factor() / factor_new;
+		let offset = ( self.unit().offset() - unit.offset() ) / factor_new;
+
+		( factor, offset )
+	}
+
+	/// Returns the numeric value of `self` expressed in `unit` without any prefix. In contrast to `as_f64()`, which always refers to the base unit, this allows for retrieving the value in any unit of the same physical quantity without creating a new `Qty`.
+	///
+	/// The value refers to `unit` itself, so for a value in kilometer, divide the value in meter by 1000 or use `convert()`.
+	///
+	/// If `unit` does not represent the same physical quantity as the unit of `self`, this function returns an `UnitError`.
 	///
 	/// # Example
 	/// ```
-	/// # use sinum::{Qty, Num, Unit, Prefix};
-	/// let x = Qty::new( 3.5.into(), &Unit::Ampere );
-	/// let y = Qty::new( Num::from( -3.5 ), &Unit::Ampere );
-	///
-	/// let abs_difference_x = ( x.clone().abs() - x ).abs();
-	/// let abs_difference_y = ( y.clone().abs() - ( -y ) ).abs();
+	/// # use sinum::{Qty, Num, Prefix, Unit};
+	/// let qty = Qty::new( Num::new( 1.5 ).with_prefix( Prefix::Kilo ), &Unit::Meter );
 	///
-	/// assert!( abs_difference_x < 1e-10 );
-	/// assert!( abs_difference_y < 1e-10 );
+	/// assert_eq!( qty.as_unit_f64( &Unit::Meter ).unwrap(), 1500.0 );
+	/// assert!( ( qty.as_unit_f64( &Unit::Mile ).unwrap() - 0.932 ).abs() < 1e-3 );
+	/// assert_eq!( Qty::new( 1500.0.into(), &Unit::Gram ).as_unit_f64( &Unit::Kilogram ).unwrap(), 1.5 );
+	/// assert!( qty.as_unit_f64( &Unit::Second ).is_err() );
 	/// ```
-	pub fn abs( self ) -> Self {
-		let val = self.as_f64().abs();
-		Self::new( Num::new( val ).to_prefix( self.number.prefix() ), self.unit() )
+	pub fn as_unit_f64( &self, unit: &Unit ) -> Result<f64, UnitError> {
+		if self.phys() != unit.phys() {
+			return Err( UnitError::UnitMismatch( vec![ self.unit().clone(), unit.clone() ] ) );
+		};
+
+		let ( factor, offset ) = self.conversion( unit );
+		let value = self.clone().to_prefix( Prefix::Nothing ).number.mantissa();
+
+		Ok( value * factor + offset )
 	}
 
-	/// Returns a string representation of the quantity with engineering notation.
-	/// Engineering notation is similar to scientific notation (using exponents of ten) but the exponents are always a multiple of 3.
+	/// Returns the absolute error (with respect to the base unit, see `as_f64()`) introduced by converting `self` to `via` and back to the original unit with `to_unit()`. This quantifies the floating point error accumulated by the conversion.
+	///
+	/// Since `to_unit()` keeps the prefix, quantities like "9.9 km" converted via the meter do not accumulate any error. Converting into other units might result in an error in the order of the floating point precision, even for integer factors like between bar and pascal.
+	///
+	/// If `via` does not represent the same physical quantity as the unit of `self`, this function returns an `UnitError`.
 	///
 	/// # Example
 	/// ```
-	/// # use sinum::{Qty, Num, Unit, Prefix};
-	/// let x = Qty::new( Num::new( 2.0 ).with_prefix( Prefix::Milli ), &Unit::Ampere );
-	///
-	/// assert_eq!( x.to_string_eng(), "2×10^-3 A" );
+	/// # use sinum::{Qty, Num, Prefix, Unit};
+	/// let qty = Qty::new( Num::new( 9.9 ).with_prefix( Prefix::Kilo ), &Unit::Meter );
+	/// assert_eq!( qty.round_trip_error( &Unit::Meter ).unwrap(), 0.0 );
+	/// assert!( qty.round_trip_error( &Unit::Inch ).unwrap() < 1e-9 );
+	/// assert!( qty.round_trip_error( &Unit::Second ).is_err() );
 	/// ```
-	pub fn to_string_eng( &self ) -> String {
-		format!( "{} {}", self.number.to_string_eng(), self.unit.to_string_sym() )
+	pub fn round_trip_error( &self, via: &Unit ) -> Result<f64, UnitError> {
+		let res = self.to_unit( via )?.to_unit( &self.unit )?;
+
+		Ok( ( res.as_f64() - self.as_f64() ).abs() )
 	}
 
-	/// Returns a LaTeX string representation of the quantity with engineering notation.
-	/// Engineering notation is similar to scientific notation (using exponents of ten) but the exponents are always a multiple of 3.
+	/// Returns the quantity in the coarsest unit of the family of its unit (like gram, kilogram and tonne) that still represents the value as a whole number. This allows storing values without losing significant figures. The returned quantity does not use a prefix.
+	///
+	/// Only units coarser than the unit of `self` are considered. If the value cannot be represented by a whole number in any of those, `self` is returned unchanged.
 	///
 	/// # Example
 	/// ```
-	/// # use sinum::{Qty, Num, Unit, Prefix, TexOptions};
-	/// let x = Qty::new( Num::new( 2.0 ).with_prefix( Prefix::Milli ), &Unit::Ampere );
-	///
-	/// assert_eq!( x.to_latex_eng( &TexOptions::new() ), r"\qty{2e-3}{\ampere}" );
+	/// # use sinum::{Qty, Unit};
+	/// assert_eq!( Qty::new( 5000.0.into(), &Unit::Kilogram ).coarsest_exact_unit().to_string(), "5 t" );
+	/// assert_eq!( Qty::new( 5001.0.into(), &Unit::Kilogram ).coarsest_exact_unit().to_string(), "5001 kg" );
+	/// assert_eq!( Qty::new( 7200.0.into(), &Unit::Second ).coarsest_exact_unit().to_string(), "2 h" );
 	/// ```
-	#[cfg( feature = "tex" )]
-	pub fn to_latex_eng( &self, options: &TexOptions ) -> String {
-		if let Prefix::Nothing = self.number.prefix() {
-			return self.to_latex_sym( options );
-		}
+	pub fn coarsest_exact_unit( &self ) -> Self {
+		let is_whole = |x: f64| ( x - x.round() ).abs() <= x.abs() * 1e-12;
+		let factor = self.unit.factor();
 
-		let mantissa = match options.minimum_decimal_digits {
-			Some( x ) => format!( "{:.1$}", self.number.mantissa(), x as usize ),
-			None => self.number.mantissa().to_string(),
-		};
-		format!(
-			r"\qty{}{{{}e{}}}{{{}}}",
-			options,
-			mantissa,
-			self.number.prefix().exp(),
-			self.unit.to_latex_sym( options )
-		)
+		self.unit.family().into_iter()
+			.rev()
+			.filter( |x| x.factor() >= factor && x != &self.unit )
+			.filter_map( |x| self.to_unit( &x ).ok() )
+			.map( |x| x.to_prefix( Prefix::Nothing ) )
+			.find( |x| is_whole( x.number.mantissa() ) )
+			.unwrap_or_else( || self.clone() )
 	}
-}
 
-impl PartialEq for Qty {
-	/// Compares two `Qty`s for equality. It compares that the numeric value is identical, not the representation.
-	/// 1 Mg == 1000 kg == 1 t
+	/// Returns a new `Qty` from `self` converted to `unit` and using `prefix`. This is a shortcut for `self.to_unit( unit )?.to_prefix( prefix )`.
+	///
+	/// Returns a `SinumError` if `unit` does not represent the same physical quantity as the original unit.
 	///
 	/// # Example
 	/// ```
-	/// # use sinum::{Qty, Num, Prefix, Unit};
-	/// assert_eq!( Qty::new( 1.1.into(), &Unit::Ampere ), Qty::new( 1.1.into(), &Unit::Ampere ) );
+	/// # use sinum::{Qty, Num, Unit, Prefix};
+	/// let x = Qty::new( 2.0.into(), &Unit::Tonne );
 	///
-	/// let val_a = Qty::new( Num::new( 1.0 ).with_prefix( Prefix::Mega ), &Unit::Gram );
-	/// let val_b = Qty::new( Num::new( 1000.0 ), &Unit::Kilogram );
-	/// let val_c = Qty::new( Num::new( 1.0 ), &Unit::Tonne );
-	/// assert!( val_a == val_b );
-	/// assert!( val_a == val_c );
-	/// assert!( val_b == val_c );
+	/// assert_eq!( x.convert( &Unit::Gram, Prefix::Mega ).unwrap().to_string(), "2 Mg" );
+	/// assert!( x.convert( &Unit::Second, Prefix::Kilo ).is_err() );
 	/// ```
-	fn eq( &self, other: &Qty ) -> bool {
-		if self.phys() != other.phys() {
-			return false;
-		}
-
-		self.as_f64().eq( &other.as_f64() )
+	pub fn convert( &self, unit: &Unit, prefix: Prefix ) -> Result<Self, SinumError> {
+		Ok( self.to_unit( unit )?.to_prefix( prefix ) )
 	}
-}
 
-impl PartialEq<f64> for Qty {
-	/// Compares a `Qty` and a `f64` for equality.
+	/// Returns a new `Qty` from `self` converted to `unit` with the prefix chosen by `shortened()`. This is a shortcut for `self.to_unit( unit )?.shortened()?`.
+	///
+	/// Returns a `SinumError` if `unit` does not represent the same physical quantity as the original unit or if no fitting prefix exists.
 	///
 	/// # Example
 	/// ```
-	/// # use sinum::{Qty, Num, Prefix, Unit};
-	/// assert!( Qty::new( 1.1.into(), &Unit::Ampere ) == 1.1 );
-	/// assert!( Qty::new( Num::new( 2.0 ).with_prefix( Prefix::Kilo ), &Unit::Second ) == 2e3 );
+	/// # use sinum::{Qty, Num, Unit, Prefix};
+	/// let x = Qty::new( Num::new( 1.5e6 ).with_prefix( Prefix::Milli ), &Unit::Meter );
+	///
+	/// assert_eq!( x.to_unit_shortened( &Unit::Meter ).unwrap().to_string(), "1.5 km" );
+	/// assert!( x.to_unit_shortened( &Unit::Second ).is_err() );
 	/// ```
-	fn eq( &self, other: &f64 ) -> bool {
-		self.as_f64().eq( other )
+	pub fn to_unit_shortened( &self, unit: &Unit ) -> Result<Self, SinumError> {
+		Ok( self.to_unit( unit )?.shortened()? )
 	}
-}
 
-impl PartialOrd for Qty {
-	fn partial_cmp( &self, other: &Self ) -> Option<Ordering> {
-		self.as_f64().partial_cmp( &other.as_f64() )
+	/// Returns `self` converted to every unit measuring its physical quantity in the order of their declaration (see `Unit::for_quantity()`). The prefix is kept like in `to_unit()`.
+	///
+	/// Custom and compound units are never part of the result, so quantities using these units result in an empty `Vec`, unless other units measure the same physical quantity.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Qty, Unit};
+	/// let all = Qty::new( 2.0.into(), &Unit::Hour ).convert_all();
+	///
+	/// assert_eq!( all.len(), 5 );
+	/// assert_eq!( all[0].to_string(), "7200 s" );
+	/// assert_eq!( all[1].to_string(), "120 min" );
+	/// assert_eq!( all[2].to_string(), "2 h" );
+	/// ```
+	pub fn convert_all( &self ) -> Vec<Self> {
+		Unit::for_quantity( &self.phys() ).iter()
+			.filter_map( |x| self.to_unit( x ).ok() )
+			.collect()
 	}
 
-	fn lt( &self, other: &Self ) -> bool {
-		self.as_f64() < other.as_f64()
+	/// Computes the absolute value of `self`, keeping its prefix and unit.
+	///
+	/// For affine units like degree Celsius, the absolute value refers to the scale of the unit itself, so the absolute value of -10 °C is 10 °C.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Qty, Num, Unit, Prefix};
+	/// let x = Qty::new( 3.5.into(), &Unit::Ampere );
+	/// let y = Qty::new( Num::from( -3.5 ), &Unit::Ampere );
+	///
+	/// let abs_difference_x = ( x.clone().abs() - x ).abs();
+	/// let abs_difference_y = ( y.clone().abs() - ( -y ) ).abs();
+	///
+	/// assert!( abs_difference_x < 1e-10 );
+	/// assert!( abs_difference_y < 1e-10 );
+	/// ```
+	pub fn abs( self ) -> Self {
+		Self::new( self.number.abs(), &self.unit )
 	}
 
-	fn le( &self, other: &Self ) -> bool {
-		self.as_f64() <= other.as_f64()
-	}
+	/// Adds `other` to `self`. The resulting `Qty` will keep the prefix and unit of `self`.
+	///
+	/// `other` is converted into the unit of `self` before adding the numbers. For affine units like degree Celsius, this means 20 °C + 10 °C results in 30 °C.
+	///
+	/// In contrast to the addition operator `+`, this does not panic if both `Qty`s represent different physical quantities but returns an `UnitError` instead.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Qty, Num, Unit, Prefix};
+	/// let calc = Qty::new( Num::new( 8.0 ).with_prefix( Prefix::Milli ), &Unit::Gram ).checked_add( Qty::new( 4.0.into(), &Unit::Tonne ) ).unwrap();
+	///
+	/// assert_eq!( calc, Qty::new( Num::new( 4_000_000_008.0 ).with_prefix( Prefix::Milli ), &Unit::Gram ) );
+	/// assert!( Qty::new( 1.0.into(), &Unit::Ampere ).checked_add( Qty::new( 1.0.into(), &Unit::Second ) ).is_err() );
+	/// ```
+	pub fn checked_add( self, other: Self ) -> Result<Self, UnitError> {
+		if self.phys() != other.phys() {
+			return Err( UnitError::UnitMismatch( vec![ self.unit, other.unit ] ) );
+		}
 
-	fn ge( &self, other: &Self ) -> bool {
-		self.as_f64() >= other.as_f64()
-	}
+		let val = self.number.mantissa() + self.mantissa_of( &other )?;
 
-	fn gt( &self, other: &Self ) -> bool {
-		self.as_f64() > other.as_f64()
+		Ok( Self::new( Num::new( val ).with_prefix( self.number.prefix() ), &self.unit ) )
 	}
-}
 
-impl PartialOrd<f64> for Qty {
-	fn partial_cmp( &self, other: &f64 ) -> Option<Ordering> {
-		self.as_f64().partial_cmp( other )
-	}
+	/// Subtracts `other` from `self`. The resulting `Qty` will keep the prefix and unit of `self`.
+	///
+	/// `other` is converted into the unit of `self` before subtracting the numbers. For affine units like degree Celsius, this means 20 °C - 10 °C results in 10 °C.
+	///
+	/// In contrast to the subtraction operator `-`, this does not panic if both `Qty`s represent different physical quantities but returns an `UnitError` instead.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Qty, Num, Unit, Prefix};
+	/// let calc = Qty::new( 1.0.into(), &Unit::Ampere ).checked_sub( Qty::new( Num::new( 100.0 ).with_prefix( Prefix::Milli ), &Unit::Ampere ) ).unwrap();
+	///
+	/// assert_eq!( calc, Qty::new( 0.9.into(), &Unit::Ampere ) );
+	/// assert!( Qty::new( 1.0.into(), &Unit::Ampere ).checked_sub( Qty::new( 1.0.into(), &Unit::Second ) ).is_err() );
+	/// ```
+	pub fn checked_sub( self, other: Self ) -> Result<Self, UnitError> {
+		if self.phys() != other.phys() {
+			return Err( UnitError::UnitMismatch( vec![ self.unit, other.unit ] ) );
+		}
 
-	fn lt( &self, other: &f64 ) -> bool {
-		self.as_f64() < *other
-	}
+		let val = self.number.mantissa() - self.mantissa_of( &other )?;
 
-	fn le( &self, other: &f64 ) -> bool {
-		self.as_f64() <= *other
+		Ok( Self::new( Num::new( val ).with_prefix( self.number.prefix() ), &self.unit ) )
 	}
 
-	fn ge( &self, other: &f64 ) -> bool {
-		self.as_f64() >= *other
-	}
+	/// Returns the mantissa of `other` expressed in the unit and with the prefix of `self`.
+	fn mantissa_of( &self, other: &Self ) -> Result<f64, UnitError> {
+		let val = other.as_unit_f64( &self.unit )?;
 
-	fn gt( &self, other: &f64 ) -> bool {
-		self.as_f64() > *other
+		Ok( Self::new( val.into(), &self.unit ).to_prefix( self.number.prefix() ).number.mantissa() )
 	}
-}
 
-impl Add for Qty {
-	type Output = Self;
-
-	/// The addition operator `+`. The resulting `Qty` will keep the prefix and unit of `self`.
+	/// Returns the absolute difference between `self` and `other`. The resulting `Qty` will keep the prefix and unit of `self`.
 	///
-	/// **Note:** Adding two `Qty`s representing different physical quantities results in a **panic**.
+	/// Like `checked_sub()`, this returns an `UnitError` if both `Qty`s represent different physical quantities.
 	///
 	/// # Example
 	/// ```
 	/// # use sinum::{Qty, Num, Unit, Prefix};
-	/// let calc_a = Qty::new( 1.0.into(), &Unit::Ampere ) + Qty::new( 0.1.into(), &Unit::Ampere );
-	///
-	/// assert_eq!( calc_a, Qty::new( 1.1.into(), &Unit::Ampere ) );
-	/// assert_eq!( calc_a.number().prefix(), Prefix::Nothing );
-	/// assert_eq!( calc_a.unit(), &Unit::Ampere );
-	///
-	/// let calc_b = Qty::new( Num::new( 8.0 ).with_prefix( Prefix::Milli ), &Unit::Gram ) + Qty::new( 4.0.into(), &Unit::Tonne );
+	/// let diff = Qty::new( 1.0.into(), &Unit::Ampere ).abs_diff( Qty::new( Num::new( 1100.0 ).with_prefix( Prefix::Milli ), &Unit::Ampere ) ).unwrap();
 	///
-	/// assert_eq!( calc_b, Qty::new( Num::new( 4_000_000_008.0 ).with_prefix( Prefix::Milli ), &Unit::Gram ) );
-	/// assert_eq!( calc_b.number().prefix(), Prefix::Milli );
+	/// assert!( ( diff.as_f64() - 0.1 ).abs() < 1e-12 );
+	/// assert_eq!( diff.unit(), &Unit::Ampere );
+	/// assert!( Qty::new( 1.0.into(), &Unit::Ampere ).abs_diff( Qty::new( 1.0.into(), &Unit::Second ) ).is_err() );
 	/// ```
-	fn add( self, other: Self ) -> Self::Output {
-		let val = self.as_f64() + other.as_f64();
-
-		Self::new( val.into(), &self.unit.base() )
-			.to_unit( &self.unit ).unwrap()
-			.to_prefix( self.number.prefix() )
+	pub fn abs_diff( self, other: Self ) -> Result<Self, UnitError> {
+		let diff = self.checked_sub( other )?;
+		Ok( Self::new( diff.number.abs(), &diff.unit ) )
 	}
-}
 
-impl Add<f64> for Qty {
-	type Output = Self;
-
-	/// The addition operator `+`. The resulting `Num` will keep the prefix.
+	/// Multiplies `self` with `other`. See the multiplication operator `*` for the unit and prefix of the result.
+	///
+	/// Affine units like degree Celsius do not have a well-defined product, since their zero point is arbitrary. In contrast to the multiplication operator `*`, this does not panic if one of the `Qty`s uses an affine unit but returns an `UnitError` instead. Use kelvin to represent temperature differences in products.
 	///
 	/// # Example
 	/// ```
-	/// # use sinum::{Qty, Num, Unit, Prefix};
-	/// let calc_a = Qty::new( 1.0.into(), &Unit::Ampere ) + 0.1;
-	///
-	/// assert_eq!( calc_a, Qty::new( 1.1.into(), &Unit::Ampere ) );
-	/// assert_eq!( calc_a.number().prefix(), Prefix::Nothing );
-	/// assert_eq!( calc_a.unit(), &Unit::Ampere );
-	///
-	/// let calc_b = Qty::new( Num::new( 32.0 ).with_prefix( Prefix::Milli ), &Unit::Gram ) + 4.0;
+	/// # use sinum::{Qty, Unit};
+	/// let calc = Qty::new( 2.0.into(), &Unit::Kelvin ).checked_mul( Qty::new( 3.0.into(), &Unit::Second ) ).unwrap();
 	///
-	/// assert_eq!( calc_b, Qty::new( Num::new( 4_000_032.0 ).with_prefix( Prefix::Milli ), &Unit::Gram ) );
-	/// assert_eq!( calc_b.number().prefix(), Prefix::Milli );
+	/// assert_eq!( calc.to_string(), "6 K·s" );
+	/// assert!( Qty::new( 2.0.into(), &Unit::Celsius ).checked_mul( Qty::new( 3.0.into(), &Unit::Second ) ).is_err() );
 	/// ```
-	fn add( self, other: f64 ) -> Self::Output {
-		let val = self.as_f64() + other;
-
-		Self::new( val.into(), &self.unit.base() )
-			.to_unit( &self.unit ).unwrap()
-			.to_prefix( self.number.prefix() )
+	pub fn checked_mul( self, other: Self ) -> Result<Self, UnitError> {
+		self.checked_product( other, 1 )
 	}
-}
-
-impl Sub for Qty {
-	type Output = Self;
 
-	/// The subtraction operator `-`. The resulting `Qty` will keep the prefix and unit of `self`.
+	/// Divides `self` by `other`. See the division operator `/` for the unit and prefix of the result.
 	///
-	/// **Note:** Subtracting two `Qty`s representing different physical quantities results in a **panic**.
+	/// Affine units like degree Celsius do not have a well-defined quotient, since their zero point is arbitrary. In contrast to the division operator `/`, this does not panic if one of the `Qty`s uses an affine unit but returns an `UnitError` instead. Use kelvin to represent temperature differences, e.g. for temperature rates in K/s.
 	///
 	/// # Example
 	/// ```
-	/// # use sinum::{Qty, Num, Unit, Prefix};
-	/// let calc_a = Qty::new( 1.0.into(), &Unit::Ampere ) - Qty::new( 0.1.into(), &Unit::Ampere );
-	///
-	/// assert_eq!( calc_a, Qty::new( 0.9.into(), &Unit::Ampere ) );
-	/// assert_eq!( calc_a.number().prefix(), Prefix::Nothing );
-	/// assert_eq!( calc_a.unit(), &Unit::Ampere );
-	///
-	/// let calc_b = Qty::new( Num::new( 8.0 ).with_prefix( Prefix::Milli ), &Unit::Gram ) - Qty::new( 4.0.into(), &Unit::Tonne );
+	/// # use sinum::{Qty, Unit};
+	/// let rate = Qty::new( 5.0.into(), &Unit::Kelvin ).checked_div( Qty::new( 2.0.into(), &Unit::Second ) ).unwrap();
 	///
-	/// assert_eq!( calc_b, Qty::new( Num::new( -3_999_999_992.0 ).with_prefix( Prefix::Milli ), &Unit::Gram ) );
-	/// assert_eq!( calc_b.number().prefix(), Prefix::Milli );
+	/// assert_eq!( rate.to_string(), "2.5 K·s⁻¹" );
+	/// assert!( Qty::new( 5.0.into(), &Unit::Celsius ).checked_div( Qty::new( 2.0.into(), &Unit::Second ) ).is_err() );
 	/// ```
-	fn sub( self, other: Self ) -> Self::Output {
-		let val = self.as_f64() - other.as_f64();
-
-		Self::new( val.into(), &self.unit.base() )
-			.to_unit( &self.unit ).unwrap()
-			.to_prefix( self.number.prefix() )
+	pub fn checked_div( self, other: Self ) -> Result<Self, UnitError> {
+		self.checked_product( other, -1 )
 	}
-}
 
-impl Sub<f64> for Qty {
-	type Output = Self;
+	/// Returns the product of `self` and `other` raised to `exp` (1 for multiplication, -1 for division).
+	fn checked_product( self, other: Self, exp: i8 ) -> Result<Self, UnitError> {
+		if let Some( x ) = [ &self.unit, &other.unit ].into_iter().find( |x| x.is_affine() ) {
+			return Err( UnitError::AffineUnit( x.clone() ) );
+		}
 
-	/// The subtraction operator `-`. The resulting `Num` will keep the prefix.
+		let val = match exp {
+			1 => self.as_f64() * other.as_f64(),
+			_ => self.as_f64() / other.as_f64(),
+		};
+		let unit = Unit::product( vec![ ( self.unit.clone(), 1 ), ( other.unit.clone(), exp ) ] );
+
+		let res = Self::new( val.into(), &unit.base() )
+			.to_unit( &unit )?;
+
+		// A prefix on a dimensionless ratio would be mistaken for the unit it cancelled out ("500 m" for 0.5).
+		if unit == Unit::One {
+			return Ok( res.to_prefix( Prefix::Nothing ) );
+		}
+
+		match res.clone().shortened() {
+			Ok( x ) => Ok( x ),
+			Err( _ ) => Ok( res.to_prefix( self.number.prefix() ) ),
+		}
+	}
+
+	/// Returns the reciprocal of `self`, e.g. to turn a duration into a rate. The value of the result with respect to the base unit (see `as_f64()`) is the reciprocal of the value of `self`, the unit is the reciprocal unit of `self` (e.g. s⁻¹ for seconds).
 	///
-	/// # Example
-	/// ```
-	/// # use sinum::{Qty, Num, Unit, Prefix};
-	/// let calc_a = Qty::new( 1.0.into(), &Unit::Ampere ) - 0.1;
+	/// The prefix is kept and applies to the reciprocal unit, so the reciprocal of "4 ks" is "0.25 ks⁻¹" (meaning 0.25 per kilosecond), not "0.25 s⁻¹". Use `to_prefix()` on the result to choose another prefix.
 	///
-	/// assert_eq!( calc_a, Qty::new( 0.9.into(), &Unit::Ampere ) );
-	/// assert_eq!( calc_a.number().prefix(), Prefix::Nothing );
-	/// assert_eq!( calc_a.unit(), &Unit::Ampere );
+	/// # Panics
+	/// Panics if the unit of `self` is an affine unit like degree Celsius, which does not have a well-defined reciprocal (see `checked_div()`).
 	///
-	/// let calc_b = Qty::new( Num::new( 8.0 ).with_prefix( Prefix::Milli ), &Unit::Gram ) - 4.0;
+	/// # Example
+	/// ```
+	/// # use sinum::{Qty, Unit};
+	/// let rate = Qty::new( 4.0.into(), &Unit::Second ).recip();
 	///
-	/// assert_eq!( calc_b, Qty::new( Num::new( -3_999_992.0 ).with_prefix( Prefix::Milli ), &Unit::Gram ) );
-	/// assert_eq!( calc_b.number().prefix(), Prefix::Milli );
+	/// assert_eq!( rate.as_f64(), 0.25 );
+	/// assert_eq!( rate.to_string(), "0.25 s⁻¹" );
 	/// ```
-	fn sub( self, other: f64 ) -> Self::Output {
-		let val = self.as_f64() - other;
+	pub fn recip( self ) -> Self {
+		assert!( !self.unit.is_affine(), "The affine unit `{}` does not have a reciprocal", self.unit );
 
-		Self::new( val.into(), &self.unit.base() )
-			.to_unit( &self.unit ).unwrap()
-			.to_prefix( self.number.prefix() )
-	}
-}
+		let unit = Unit::compound( vec![ ( self.unit, -1 ) ] );
 
-impl Mul for Qty {
-	type Output = Self;
+		Self::new( Num::new( self.number.mantissa().recip() ).with_prefix( self.number.prefix() ), &unit )
+	}
 
-	/// The multiplication operator `*`. The resulting `Num` will keep the prefix and unit of `self`.
+	/// Raises `self` to the integer power `n`. The unit is raised to the same power, so squaring a length results in an area (m²) and cubing it in a volume (m³). The prefix is kept and applies to the unit raised to `n`, so squaring "2 km" results in "4 km²".
 	///
-	/// # Example
-	/// ```
-	/// # use sinum::{Num, Prefix};
-	/// let calc_a = Num::new( 1.0 ) * Num::new( 0.1 );
+	/// Only integer powers are supported, since the exponents of compound units are integers.
 	///
-	/// assert_eq!( calc_a, Num::new( 0.1 ) );
-	/// assert_eq!( calc_a.prefix(), Prefix::Nothing );
+	/// # Panics
+	/// Panics if the unit of `self` is an affine unit like degree Celsius (see `checked_mul()`) or if the exponent of any unit in the result does not fit into an `i8`.
 	///
-	/// let calc_b = Num::new( 2.0 ).with_prefix( Prefix::Kilo ) * Num::new( 4.0 );
+	/// # Example
+	/// ```
+	/// # use sinum::{Qty, Num, Unit, Prefix, PhysicalQuantity};
+	/// let area = Qty::new( 3.0.into(), &Unit::Meter ).powi( 2 );
 	///
-	/// assert_eq!( calc_b, Num::new( 8.0 ).with_prefix( Prefix::Kilo ) );
-	/// assert_eq!( calc_b.prefix(), Prefix::Kilo );
+	/// assert_eq!( area.as_f64(), 9.0 );
+	/// assert_eq!( area.to_string(), "9 m²" );
+	/// assert_eq!( Qty::new( Num::new( 2.0 ).with_prefix( Prefix::Kilo ), &Unit::Meter ).powi( 2 ).to_string(), "4 km²" );
 	/// ```
-	fn mul( self, other: Self ) -> Self::Output {
-		let val = self.as_f64() * other.as_f64();
+	pub fn powi( self, n: i32 ) -> Self {
+		assert!( n == 1 || !self.unit.is_affine(), "The affine unit `{}` cannot be raised to a power", self.unit );
 
-		Self::new( val.into(), &self.unit.base() )
-			.to_unit( &self.unit ).unwrap()
-			.to_prefix( self.number.prefix() )
-	}
-}
+		let parts = match &self.unit {
+			Unit::Compound( x ) => x.clone(),
+			x => vec![ ( x.clone(), 1 ) ],
+		};
+		let parts = parts.into_iter()
+			.map( |( unit, exp )| {
+				let exp_new = i8::try_from( exp as i32 * n ).expect( "The exponent of the unit exceeds the range of `i8`" );
+				( unit, exp_new )
+			} )
+			.collect();
+		let unit = Unit::compound( parts );
 
-impl Mul<f64> for Qty {
-	type Output = Self;
+		// The prefix cannot apply to a dimensionless result, so it is resolved into the value.
+		if unit == Unit::One {
+			return Self::new( Num::new( self.as_f64().powi( n ) ), &unit );
+		}
 
-	/// The multiplication operator `*`. The resulting `Qty` will keep the prefix.
+		Self::new( Num::new( self.number.mantissa().powi( n ) ).with_prefix( self.number.prefix() ), &unit )
+	}
+
+	/// Returns the one of `self` and `other` representing the smaller value, keeping its unit and prefix. If both are equal, `self` is returned.
+	///
+	/// Returns an `UnitError` if both `Qty`s represent different physical quantities.
 	///
 	/// # Example
 	/// ```
-	/// # use sinum::{Qty, Num, Unit, Prefix};
-	/// let calc_a = Qty::new( 1.0.into(), &Unit::Ampere ) * 0.1;
+	/// # use sinum::{Qty, Unit};
+	/// let tonne = Qty::new( 1.0.into(), &Unit::Tonne );
+	/// let kilogram = Qty::new( 900.0.into(), &Unit::Kilogram );
 	///
-	/// assert_eq!( calc_a, Qty::new( 0.1.into(), &Unit::Ampere ) );
-	/// assert_eq!( calc_a.number().prefix(), Prefix::Nothing );
-	/// assert_eq!( calc_a.unit(), &Unit::Ampere );
+	/// assert_eq!( tonne.clone().min( kilogram.clone() ).unwrap().unit(), &Unit::Kilogram );
+	/// assert!( tonne.min( Qty::new( 1.0.into(), &Unit::Meter ) ).is_err() );
+	/// ```
+	pub fn min( self, other: Self ) -> Result<Self, UnitError> {
+		if self.phys() != other.phys() {
+			return Err( UnitError::UnitMismatch( vec![ self.unit, other.unit ] ) );
+		}
+
+		match other.as_f64() < self.as_f64() {
+			true => Ok( other ),
+			false => Ok( self ),
+		}
+	}
+
+	/// Returns the one of `self` and `other` representing the larger value, keeping its unit and prefix. If both are equal, `self` is returned.
 	///
-	/// let calc_b = Qty::new( Num::new( 8.0 ).with_prefix( Prefix::Milli ), &Unit::Gram ) * 4.0;
+	/// Returns an `UnitError` if both `Qty`s represent different physical quantities.
 	///
-	/// assert_eq!( calc_b, Qty::new( Num::new( 32.0 ).with_prefix( Prefix::Milli ), &Unit::Gram ) );
-	/// assert_eq!( calc_b.number().prefix(), Prefix::Milli );
+	/// # Example
 	/// ```
-	fn mul( self, other: f64 ) -> Self::Output {
-		let val = self.as_f64() * other;
+	/// # use sinum::{Qty, Unit};
+	/// let tonne = Qty::new( 1.0.into(), &Unit::Tonne );
+	/// let kilogram = Qty::new( 900.0.into(), &Unit::Kilogram );
+	///
+	/// assert_eq!( tonne.clone().max( kilogram.clone() ).unwrap().unit(), &Unit::Tonne );
+	/// assert!( tonne.max( Qty::new( 1.0.into(), &Unit::Meter ) ).is_err() );
+	/// ```
+	pub fn max( self, other: Self ) -> Result<Self, UnitError> {
+		if self.phys() != other.phys() {
+			return Err( UnitError::UnitMismatch( vec![ self.unit, other.unit ] ) );
+		}
 
-		Self::new( val.into(), &self.unit.base() )
-			.to_unit( &self.unit ).unwrap()
-			.to_prefix( self.number.prefix() )
+		match other.as_f64() > self.as_f64() {
+			true => Ok( other ),
+			false => Ok( self ),
+		}
 	}
-}
 
-impl MulAssign<f64> for Qty {
-	/// The multiplication operator `*=`. `self` will keep the prefix.
+	/// Restricts the quantity to the interval [`min`, `max`]. If the quantity lies outside of the interval, the respective bound is returned converted to the unit and prefix of `self`.
+	///
+	/// Returns an `UnitError` if the three `Qty`s do not represent the same physical quantity.
+	///
+	/// # Panics
+	/// Panics if `min` > `max`, `min` is NaN, or `max` is NaN. This mirrors `f64::clamp()`.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Qty, Unit};
+	/// let min = Qty::new( 1.0.into(), &Unit::Tonne );
+	/// let max = Qty::new( 2.0.into(), &Unit::Tonne );
+	///
+	/// assert_eq!( Qty::new( 1500.0.into(), &Unit::Kilogram ).clamp( min.clone(), max.clone() ).unwrap().to_string(), "1500 kg" );
+	/// assert_eq!( Qty::new( 2500.0.into(), &Unit::Kilogram ).clamp( min.clone(), max.clone() ).unwrap().to_string(), "2000 kg" );
+	/// assert!( Qty::new( 1.0.into(), &Unit::Meter ).clamp( min, max ).is_err() );
+	/// ```
+	pub fn clamp( self, min: Self, max: Self ) -> Result<Self, UnitError> {
+		if self.phys() != min.phys() || self.phys() != max.phys() {
+			return Err( UnitError::UnitMismatch( vec![ self.unit, min.unit, max.unit ] ) );
+		}
+
+		let ( val_min, val_max ) = ( min.as_f64(), max.as_f64() );
+		assert!( val_min <= val_max, "min > max, or either was NaN. min = {:?}, max = {:?}", min, max );
+
+		let bound = match self.as_f64() {
+			x if x < val_min => min,
+			x if x > val_max => max,
+			_ => return Ok( self ),
+		};
+
+		Ok( bound.to_unit( &self.unit )?.to_prefix( self.number.prefix() ) )
+	}
+
+	/// Sums up all `Qty`s of `iter`. The resulting `Qty` will keep the prefix and unit of the first element. An empty `iter` results in a dimensionless zero.
+	///
+	/// In contrast to `sum()`, this does not panic if the `Qty`s represent different physical quantities but returns an `UnitError` instead.
 	///
 	/// # Example
 	/// ```
 	/// # use sinum::{Qty, Num, Unit, Prefix};
-	/// let mut calc_a = Qty::new( 1.0.into(), &Unit::Ampere );
-	/// calc_a *= 0.1;
+	/// let sum = Qty::try_sum( [
+	///     Qty::new( Num::new( 2.0 ).with_prefix( Prefix::Kilo ), &Unit::Meter ),
+	///     Qty::new( 500.0.into(), &Unit::Meter ),
+	/// ] ).unwrap();
 	///
-	/// assert_eq!( calc_a, Qty::new( 0.1.into(), &Unit::Ampere ) );
-	/// assert_eq!( calc_a.number().prefix(), Prefix::Nothing );
-	/// assert_eq!( calc_a.unit(), &Unit::Ampere );
+	/// assert_eq!( sum, Qty::new( Num::new( 2.5 ).with_prefix( Prefix::Kilo ), &Unit::Meter ) );
+	/// assert!( Qty::try_sum( [ Qty::new( 1.0.into(), &Unit::Meter ), Qty::new( 1.0.into(), &Unit::Second ) ] ).is_err() );
+	/// ```
+	pub fn try_sum<I: IntoIterator<Item = Qty>>( iter: I ) -> Result<Self, UnitError> {
+		let mut iter = iter.into_iter();
+
+		let Some( first ) = iter.next() else {
+			return Ok( Self::new( 0.0.into(), &Unit::compound( vec![] ) ) );
+		};
+
+		iter.try_fold( first, |acc, x| acc.checked_add( x ) )
+	}
+
+	/// Returns `true` if `self` deviates from `other` by no more than `tolerance`, i.e. |`self` - `other`| ≤ `tolerance`.
 	///
-	/// let mut calc_b = Qty::new( Num::new( 8.0 ).with_prefix( Prefix::Milli ), &Unit::Gram );
-	/// calc_b *= 4.0;
+	/// For affine units like degree Celsius, `tolerance` is interpreted as temperature difference, so 0.5 °C equals 0.5 K.
 	///
-	/// assert_eq!( calc_b, Qty::new( Num::new( 32.0 ).with_prefix( Prefix::Milli ), &Unit::Gram ) );
-	/// assert_eq!( calc_b.number().prefix(), Prefix::Milli );
+	/// Returns an `UnitError` if the three `Qty`s do not represent the same physical quantity.
+	///
+	/// # Example
 	/// ```
-	fn mul_assign( &mut self, rhs: f64 ) {
-		self.number *= rhs;
+	/// # use sinum::{Qty, Unit};
+	/// let target = Qty::new( 10.0.into(), &Unit::Kilogram );
+	/// let tolerance = Qty::new( 0.5.into(), &Unit::Kilogram );
+	///
+	/// assert!( Qty::new( 10.3.into(), &Unit::Kilogram ).within( &target, &tolerance ).unwrap() );
+	/// assert!( !Qty::new( 11.0.into(), &Unit::Kilogram ).within( &target, &tolerance ).unwrap() );
+	/// ```
+	pub fn within( &self, other: &Qty, tolerance: &Qty ) -> Result<bool, UnitError> {
+		if self.phys() != other.phys() || self.phys() != tolerance.phys() {
+			return Err( UnitError::UnitMismatch( vec![ self.unit.clone(), other.unit.clone(), tolerance.unit.clone() ] ) );
+		}
+
+		let tolerance = ( tolerance.as_f64() - tolerance.unit.offset() ).abs();
+
+		Ok( ( self.as_f64() - other.as_f64() ).abs() <= tolerance )
 	}
-}
 
-impl Div for Qty {
-	type Output = Self;
+	/// Returns the dimensionless ratio of `self` to `reference`, e.g. to express values as fraction of a maximum. Both quantities are compared in their base unit, so the ratio does not depend on units or prefixes. Temperatures are compared as absolute temperatures in kelvin.
+	///
+	/// If `reference` is zero, the result is infinite or NaN, like dividing floating point numbers.
+	///
+	/// Returns an `UnitError` if both `Qty`s do not represent the same physical quantity.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Qty, Num, Unit, Prefix};
+	/// let reference = Qty::new( 6.0.into(), &Unit::Ampere );
+	///
+	/// assert_eq!( Qty::new( 3.0.into(), &Unit::Ampere ).relative_to( &reference ).unwrap(), 0.5 );
+	/// assert_eq!( Qty::new( Num::new( 1.5e3 ).with_prefix( Prefix::Milli ), &Unit::Ampere ).relative_to( &reference ).unwrap(), 0.25 );
+	/// assert!( Qty::new( 3.0.into(), &Unit::Volt ).relative_to( &reference ).is_err() );
+	/// ```
+	pub fn relative_to( &self, reference: &Qty ) -> Result<f64, UnitError> {
+		if self.phys() != reference.phys() {
+			return Err( UnitError::UnitMismatch( vec![ self.unit.clone(), reference.unit.clone() ] ) );
+		}
+
+		Ok( self.as_f64() / reference.as_f64() )
+	}
 
-	/// The multiplication operator `/`. The resulting `Qty` will keep the higher prefix of the two parts.
+	/// Scales `self` by the dimensionless `factor`, keeping the unit and prefix of `self`.
+	///
+	/// This is equivalent to multiplying with `factor.as_f64()` but states the intent of scaling by a dimensionless ratio explicitly.
 	///
 	/// # Example
 	/// ```
 	/// # use sinum::{Qty, Num, Unit, Prefix};
-	/// let calc_a = Qty::new( 1.0.into(), &Unit::Ampere ) / Qty::new( 0.1.into(), &Unit::Ampere );
+	/// let length = Qty::new( Num::new( 2.0 ).with_prefix( Prefix::Kilo ), &Unit::Meter );
+	/// let scaled = length.scale( Num::new( 500.0 ).with_prefix( Prefix::Milli ) );
 	///
-	/// assert_eq!( calc_a, Qty::new( 10.0.into(), &Unit::Ampere ) );
-	/// assert_eq!( calc_a.number().prefix(), Prefix::Nothing );
-	/// assert_eq!( calc_a.unit(), &Unit::Ampere );
+	/// assert_eq!( scaled, Qty::new( Num::new( 1.0 ).with_prefix( Prefix::Kilo ), &Unit::Meter ) );
+	/// assert_eq!( scaled.number().prefix(), Prefix::Kilo );
+	/// ```
+	pub fn scale( self, factor: Num ) -> Self {
+		self * factor.as_f64()
+	}
+
+	/// Rounds the numeric value of `self` (in the unit of `self`) to `sig` significant figures, keeping the unit and prefix. Use `shortened()` afterwards if the prefix should be adapted to the rounded value.
 	///
-	/// let calc_b = Qty::new( Num::new( 8.0 ).with_prefix( Prefix::Milli ), &Unit::Gram ) / Qty::new( 4.0.into(), &Unit::Tonne );
+	/// A `sig` of zero is treated like 1. Zero and non-finite values are returned unchanged.
 	///
-	/// assert_eq!( calc_b, Qty::new( Num::new( 2e-3 ).with_prefix( Prefix::Milli ), &Unit::Gram ) );
-	/// assert_eq!( calc_b.number().prefix(), Prefix::Milli );
+	/// # Example
 	/// ```
-	fn div( self, other: Self ) -> Self::Output {
-		let val = self.as_f64() / other.as_f64();
+	/// # use sinum::{Qty, Num, Unit, Prefix};
+	/// assert_eq!( Qty::new( 1234.5.into(), &Unit::Meter ).round_to_significant_figures( 2 ), Qty::new( 1200.0.into(), &Unit::Meter ) );
+	///
+	/// let rounded = Qty::new( Num::new( 9.87654 ).with_prefix( Prefix::Kilo ), &Unit::Meter ).round_to_significant_figures( 3 );
+	/// assert_eq!( rounded.number().prefix(), Prefix::Kilo );
+	/// assert_eq!( rounded.to_string(), "9.88 km" );
+	/// ```
+	pub fn round_to_significant_figures( self, sig: u8 ) -> Self {
+		let val = round_significant( self.number.as_f64(), sig );
 
-		Self::new( val.into(), &self.unit.base() )
-			.to_unit( &self.unit ).unwrap()
-			.to_prefix( self.number.prefix() )
+		Self::new( Num::new( val ).to_prefix( self.number.prefix() ), &self.unit )
 	}
-}
 
-impl Div<f64> for Qty {
-	type Output = Self;
+	/// Returns the weighted arithmetic mean Σ(wᵢ·vᵢ)/Σwᵢ of the quantities vᵢ with their respective weights wᵢ in `values`. The result keeps the unit and prefix of the first quantity.
+	///
+	/// Returns a `SinumError` if the quantities do not represent the same physical quantity or if the weights add up to zero (including `values` being empty).
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Qty, Num, Unit, Prefix};
+	/// let mean = Qty::weighted_mean( &[
+	///     ( Qty::new( 1.0.into(), &Unit::Kilogram ), 1.0 ),
+	///     ( Qty::new( 4.0.into(), &Unit::Kilogram ), 2.0 ),
+	/// ] ).unwrap();
+	///
+	/// assert_eq!( mean, Qty::new( 3.0.into(), &Unit::Kilogram ) );
+	/// assert!( Qty::weighted_mean( &[] ).is_err() );
+	/// ```
+	pub fn weighted_mean( values: &[( Qty, f64 )] ) -> Result<Self, SinumError> {
+		let weight_total: f64 = values.iter().map( |( _, w )| w ).sum();
+		if weight_total == 0.0 {
+			return Err( QtyError::ZeroWeight.into() );
+		}
 
-	/// The multiplication operator `/`. The resulting `Qty` will keep the prefix.
+		let first = &values[0].0;
+		if values.iter().any( |( qty, _ )| qty.phys() != first.phys() ) {
+			return Err( UnitError::UnitMismatch( values.iter().map( |( qty, _ )| qty.unit.clone() ).collect() ).into() );
+		}
+
+		let val = values.iter().map( |( qty, w )| qty.as_f64() * w ).sum::<f64>() / weight_total;
+
+		Ok(
+			Self::new( val.into(), &first.unit.base() )
+				.to_unit( &first.unit )?
+				.to_prefix( first.number.prefix() )
+		)
+	}
+
+	/// Returns a string representation of the quantity using a narrow no-break space (U+202F) between the number and the unit as recommended by the SI brochure.
+	///
+	/// `to_string()` uses a normal ASCII space instead.
 	///
 	/// # Example
 	/// ```
 	/// # use sinum::{Qty, Num, Unit, Prefix};
-	/// let calc_a = Qty::new( 1.0.into(), &Unit::Ampere ) / 0.1;
+	/// let x = Qty::new( Num::new( 9.9 ).with_prefix( Prefix::Kilo ), &Unit::Meter );
 	///
-	/// assert_eq!( calc_a, Qty::new( 10.0.into(), &Unit::Ampere ) );
-	/// assert_eq!( calc_a.number().prefix(), Prefix::Nothing );
-	/// assert_eq!( calc_a.unit(), &Unit::Ampere );
+	/// assert_eq!( x.to_string_si_space(), "9.9\u{202F}km" );
+	/// assert_eq!( x.to_string(), "9.9 km" );
+	/// ```
+	pub fn to_string_si_space( &self ) -> String {
+		self.to_string_with( '\u{202F}', display_rounding() )
+	}
+
+	/// Returns a string representation of the quantity like `to_string()` but with the mantissa rounded to at most `decimals` decimal places, overriding the crate-wide setting of `set_display_rounding()`. If `decimals` is `None`, the mantissa is not rounded at all.
 	///
-	/// let calc_b = Qty::new( Num::new( 8.0 ).with_prefix( Prefix::Milli ), &Unit::Gram ) / 4.0;
+	/// # Example
+	/// ```
+	/// # use sinum::{Qty, Num, Unit, Prefix, CLEAN_DISPLAY_DECIMALS};
+	/// let x = Qty::new( Num::new( 1.23456789 ).with_prefix( Prefix::Kilo ), &Unit::Meter );
 	///
-	/// assert_eq!( calc_b, Qty::new( Num::new( 2.0 ).with_prefix( Prefix::Milli ), &Unit::Gram ) );
-	/// assert_eq!( calc_b.number().prefix(), Prefix::Milli );
+	/// assert_eq!( x.to_string_rounded( None ), "1.23456789 km" );
+	/// assert_eq!( x.to_string_rounded( Some( CLEAN_DISPLAY_DECIMALS ) ), "1.234568 km" );
 	/// ```
-	fn div( self, other: f64 ) -> Self::Output {
-		let val = self.as_f64() / other;
+	pub fn to_string_rounded( &self, decimals: Option<u8> ) -> String {
+		self.to_string_with( ' ', decimals )
+	}
+
+	/// Returns a string representation of the quantity like `to_string()` but using the decimal separator and the spacing between number and unit of the language specified by `locale`. In contrast to `to_string_locale()`, the prefix and unit are written as symbols.
+	///
+	/// This method is only available, if the **`i18n`** feature has been enabled.
+	///
+	/// # Example
+	/// ```
+	/// # use unic_langid::langid;
+	/// # use sinum::{Qty, Num, Unit, Prefix};
+	/// let x = Qty::new( Num::new( 9.9 ).with_prefix( Prefix::Kilo ), &Unit::Meter );
+	///
+	/// assert_eq!( x.to_string_locale_sym( &langid!( "en-US" ) ), "9.9 km" );
+	/// assert_eq!( x.to_string_locale_sym( &langid!( "de-DE" ) ), "9,9\u{A0}km" );
+	/// assert_eq!( x.to_string_locale_sym( &langid!( "fr-FR" ) ), "9,9\u{202F}km" );
+	/// ```
+	#[cfg( feature = "i18n" )]
+	pub fn to_string_locale_sym( &self, locale: &LanguageIdentifier ) -> String {
+		let space = LOCALES.lookup( locale, "unit_separator" ).chars().next().unwrap_or( ' ' );
+		let number = self.number.with_prefix( Prefix::Nothing ).to_string_locale( locale );
+
+		self.join_symbol( number, space )
+	}
+
+	/// Returns a string representation of the quantity using `space` to separate the number from the unit and the mantissa rounded to at most `decimals` decimal places.
+	fn to_string_with( &self, space: char, decimals: Option<u8> ) -> String {
+		let number = self.number.with_prefix( Prefix::Nothing ).to_string_rounded( decimals );
+		self.join_symbol( number, space )
+	}
+
+	/// Returns `number` followed by the symbols of the prefix and unit of `self`, separated by `space` if the unit requires it.
+	fn join_symbol( &self, number: String, space: char ) -> String {
+		// The prefix is meaningless for non-finite values.
+		let prefix = match self.number.is_finite() {
+			true => self.number.prefix().to_string_sym(),
+			false => "".to_string(),
+		};
+		self.join_prefixed_symbol( number, &prefix, space )
+	}
+
+	/// Returns `number` followed by the symbols `prefix` and of the unit of `self`, separated by `space` if the unit requires it.
+	fn join_prefixed_symbol( &self, number: String, prefix: &str, space: char ) -> String {
+		let symbol = format!( "{}{}", prefix, self.unit.to_string_sym() );
+
+		// Dimensionless quantities might lack any symbol.
+		if symbol.is_empty() {
+			return number;
+		}
+
+		// Some units like the plane angle in degree are written directly after the number.
+		if !self.unit.space_before_symbol() {
+			return format!( "{}{}", number, symbol );
+		}
+
+		format!( "{}{}{}", number, space, symbol )
+	}
+
+	/// Returns a string representation of the quantity with engineering notation.
+	/// Engineering notation is similar to scientific notation (using exponents of ten) but the exponents are always a multiple of 3.
+	///
+	/// Like `to_string()`, units written without a space (see [`Unit::space_before_symbol`]) directly follow the number.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Qty, Num, Unit, Prefix};
+	/// let x = Qty::new( Num::new( 2.0 ).with_prefix( Prefix::Milli ), &Unit::Ampere );
+	///
+	/// assert_eq!( x.to_string_eng(), "2×10^-3 A" );
+	/// ```
+	pub fn to_string_eng( &self ) -> String {
+		let number = match self.number.prefix() {
+			_ if !self.number.is_finite() => self.number.to_string_eng(),
+			Prefix::Nothing => self.number.mantissa().to_string(),
+			x => format!( "{}×10^{}", self.number.mantissa(), x.exp() as i32 * self.prefix_power() ),
+		};
+		let symbol = self.unit.to_string_sym();
+		match symbol.is_empty() || !self.unit.space_before_symbol() {
+			true => format!( "{}{}", number, symbol ),
+			false => format!( "{} {}", number, symbol ),
+		}
+	}
+
+	/// Returns the shortest string representation of the quantity among the prefixed form (`"99 km"`), the plain form without prefix (`"99000 m"`), the engineering notation (`"99×10^3 m"`) and the scientific notation (`"9.9×10^4 m"`).
+	///
+	/// Independent of `set_display_rounding()`, all forms round the mantissa to `CLEAN_DISPLAY_DECIMALS` decimal places. Forms losing precision by this rounding are not considered. If the lengths are equal, the forms are preferred in the order given above.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Qty, Num, Unit, Prefix};
+	/// assert_eq!( Qty::new( 9900.0.into(), &Unit::Meter ).to_string_compact(), "9.9 km" );
+	/// assert_eq!( Qty::new( 250.0.into(), &Unit::Meter ).to_string_compact(), "250 m" );
+	/// assert_eq!( Qty::new( 1e33.into(), &Unit::Meter ).to_string_compact(), "1×10^33 m" );
+	/// assert_eq!( Qty::new( 99_000.0.into(), &Unit::Meter ).to_string_compact(), "99 km" );
+	/// ```
+	pub fn to_string_compact( &self ) -> String {
+		let value = self.number.as_f64();
+		if value == 0.0 || !value.is_finite() {
+			return self.to_string();
+		}
+
+		let round = |x: f64| round_decimals( x, Some( CLEAN_DISPLAY_DECIMALS ) );
+		let is_precise = |x: f64| ( x - value ).abs() <= value.abs() * 1e-12;
+		let with_symbol = |number: String| {
+			let symbol = self.unit.to_string_sym();
+			match symbol.is_empty() || !self.unit.space_before_symbol() {
+				true => format!( "{}{}", number, symbol ),
+				false => format!( "{} {}", number, symbol ),
+			}
+		};
+
+		let mut candidates = Vec::new();
+
+		// Shortening the absolute value, since `shortened()` does not handle negative values.
+		if let Ok( qty ) = Self::new( self.number.abs(), &self.unit ).shortened() {
+			let qty = Self::new( qty.number * value.signum(), &qty.unit );
+			let exp = qty.number.prefix().exp() as i32 * self.prefix_power();
+			let mantissa = round( qty.number.mantissa() );
+			if is_precise( mantissa * 10_f64.powi( exp ) ) {
+				candidates.push( qty.to_string_rounded( Some( CLEAN_DISPLAY_DECIMALS ) ) );
+				if exp != 0 {
+					candidates.push( with_symbol( format!( "{}×10^{}", mantissa, exp ) ) );
+				}
+			}
+		}
+
+		if is_precise( round( value ) ) {
+			candidates.push( with_symbol( round( value ).to_string() ) );
+		}
+
+		let exp = value.abs().log10().floor() as i32;
+		let mantissa = round( value / 10_f64.powi( exp ) );
+		if exp != 0 && is_precise( mantissa * 10_f64.powi( exp ) ) {
+			candidates.push( with_symbol( format!( "{}×10^{}", mantissa, exp ) ) );
+		}
+
+		candidates.into_iter()
+			.min_by_key( |x| x.chars().count() )
+			.unwrap_or_else( || with_symbol( value.to_string() ) )
+	}
+
+	/// Returns a LaTeX string representation of the quantity with scientific notation, using a mantissa between 1 and 10 instead of the prefix.
+	#[cfg( feature = "tex" )]
+	fn to_latex_sci( &self, options: &TexOptions ) -> String {
+		let exp_prefix = self.number.prefix().exp() as i32 * self.prefix_power();
+		let value = self.number.mantissa() * 10_f64.powi( exp_prefix );
+		let exp = match value {
+			x if x == 0.0 || !x.is_finite() => 0,
+			x => x.abs().log10().floor() as i32,
+		};
+		// Scaling the original mantissa to reduce floating point errors.
+		let mantissa = self.number.mantissa() * 10_f64.powi( exp_prefix - exp );
+
+		let mantissa = options.format_mantissa( mantissa );
+		let number = match exp {
+			0 => mantissa,
+			x => format!( "{}e{}", mantissa, x ),
+		};
+		format!( r"{}{}{{{}}}{{{}}}", options.qty_command(), options, number, self.unit.to_latex_sym( options ) )
+	}
+
+	/// Returns a LaTeX string representation of the quantity with engineering notation.
+	/// Engineering notation is similar to scientific notation (using exponents of ten) but the exponents are always a multiple of 3.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Qty, Num, Unit, Prefix, TexOptions};
+	/// let x = Qty::new( Num::new( 2.0 ).with_prefix( Prefix::Milli ), &Unit::Ampere );
+	///
+	/// assert_eq!( x.to_latex_eng( &TexOptions::new() ), r"\qty{2e-3}{\ampere}" );
+	/// ```
+	#[cfg( feature = "tex" )]
+	pub fn to_latex_eng( &self, options: &TexOptions ) -> String {
+		if let Prefix::Nothing = self.number.prefix() {
+			return self.to_latex_sym( options );
+		}
+
+		let mantissa = options.format_mantissa( self.number.mantissa() );
+		format!(
+			r"{}{}{{{}e{}}}{{{}}}",
+			options.qty_command(),
+			options,
+			mantissa,
+			self.number.prefix().exp() as i32 * self.prefix_power(),
+			self.unit.to_latex_sym( options )
+		)
+	}
+
+	/// Returns a LaTeX string representation of the quantity with the prefix and unit written out as words like "9.9 kilometer" instead of `{siunitx}` commands. Words are not pluralized.
+	///
+	/// Masses with a prefix are written in gram, so the kilogram is never combined with another prefix.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Qty, Num, Unit, Prefix, TexOptions};
+	/// let x = Qty::new( Num::new( 9.9 ).with_prefix( Prefix::Kilo ), &Unit::Meter );
+	/// assert_eq!( x.to_latex_text( &TexOptions::new() ), "9.9 kilometer" );
+	///
+	/// let x = Qty::new( Num::new( 9.9 ).with_prefix( Prefix::Milli ), &Unit::Kilogram );
+	/// assert_eq!( x.to_latex_text( &TexOptions::new() ), "9.9 gram" );
+	/// ```
+	#[cfg( feature = "tex" )]
+	pub fn to_latex_text( &self, options: &TexOptions ) -> String {
+		let mantissa = options.format_mantissa( self.number.mantissa() );
+		let unit = match self.unit {
+			// Dimensionless quantities are written without any unit.
+			Unit::One => "".to_string(),
+			_ => self.unit.to_latex( options ),
+		};
+
+		match format!( "{}{}", self.number.prefix().to_latex( options ), unit ) {
+			x if x.is_empty() => mantissa,
+			x => format!( "{} {}", mantissa, x ),
+		}
+	}
+}
+
+impl PartialEq for Qty {
+	/// Compares two `Qty`s for equality. It compares that the numeric value is identical, not the representation.
+	/// 1 Mg == 1000 kg == 1 t
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Qty, Num, Prefix, Unit};
+	/// assert_eq!( Qty::new( 1.1.into(), &Unit::Ampere ), Qty::new( 1.1.into(), &Unit::Ampere ) );
+	///
+	/// let val_a = Qty::new( Num::new( 1.0 ).with_prefix( Prefix::Mega ), &Unit::Gram );
+	/// let val_b = Qty::new( Num::new( 1000.0 ), &Unit::Kilogram );
+	/// let val_c = Qty::new( Num::new( 1.0 ), &Unit::Tonne );
+	/// assert!( val_a == val_b );
+	/// assert!( val_a == val_c );
+	/// assert!( val_b == val_c );
+	/// ```
+	fn eq( &self, other: &Qty ) -> bool {
+		if self.phys() != other.phys() {
+			return false;
+		}
+
+		self.as_f64().eq( &other.as_f64() )
+	}
+}
+
+impl PartialEq<f64> for Qty {
+	/// Compares a `Qty` and a `f64` for equality.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Qty, Num, Prefix, Unit};
+	/// assert!( Qty::new( 1.1.into(), &Unit::Ampere ) == 1.1 );
+	/// assert!( Qty::new( Num::new( 2.0 ).with_prefix( Prefix::Kilo ), &Unit::Second ) == 2e3 );
+	/// ```
+	fn eq( &self, other: &f64 ) -> bool {
+		self.as_f64().eq( other )
+	}
+}
+
+impl PartialOrd for Qty {
+	fn partial_cmp( &self, other: &Self ) -> Option<Ordering> {
+		self.as_f64().partial_cmp( &other.as_f64() )
+	}
+
+	fn lt( &self, other: &Self ) -> bool {
+		self.as_f64() < other.as_f64()
+	}
+
+	fn le( &self, other: &Self ) -> bool {
+		self.as_f64() <= other.as_f64()
+	}
+
+	fn ge( &self, other: &Self ) -> bool {
+		self.as_f64() >= other.as_f64()
+	}
+
+	fn gt( &self, other: &Self ) -> bool {
+		self.as_f64() > other.as_f64()
+	}
+}
+
+impl PartialOrd<f64> for Qty {
+	fn partial_cmp( &self, other: &f64 ) -> Option<Ordering> {
+		self.as_f64().partial_cmp( other )
+	}
+
+	fn lt( &self, other: &f64 ) -> bool {
+		self.as_f64() < *other
+	}
+
+	fn le( &self, other: &f64 ) -> bool {
+		self.as_f64() <= *other
+	}
+
+	fn ge( &self, other: &f64 ) -> bool {
+		self.as_f64() >= *other
+	}
+
+	fn gt( &self, other: &f64 ) -> bool {
+		self.as_f64() > *other
+	}
+}
+
+impl Add for Qty {
+	type Output = Self;
+
+	/// The addition operator `+`. The resulting `Qty` will keep the prefix and unit of `self`.
+	///
+	/// **Note:** Adding two `Qty`s representing different physical quantities results in a **panic**.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Qty, Num, Unit, Prefix};
+	/// let calc_a = Qty::new( 1.0.into(), &Unit::Ampere ) + Qty::new( 0.1.into(), &Unit::Ampere );
+	///
+	/// assert_eq!( calc_a, Qty::new( 1.1.into(), &Unit::Ampere ) );
+	/// assert_eq!( calc_a.number().prefix(), Prefix::Nothing );
+	/// assert_eq!( calc_a.unit(), &Unit::Ampere );
+	///
+	/// let calc_b = Qty::new( Num::new( 8.0 ).with_prefix( Prefix::Milli ), &Unit::Gram ) + Qty::new( 4.0.into(), &Unit::Tonne );
+	///
+	/// assert_eq!( calc_b, Qty::new( Num::new( 4_000_000_008.0 ).with_prefix( Prefix::Milli ), &Unit::Gram ) );
+	/// assert_eq!( calc_b.number().prefix(), Prefix::Milli );
+	/// ```
+	fn add( self, other: Self ) -> Self::Output {
+		self.checked_add( other ).unwrap()
+	}
+}
+
+impl Add<f64> for Qty {
+	type Output = Self;
+
+	/// The addition operator `+`. The resulting `Num` will keep the prefix.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Qty, Num, Unit, Prefix};
+	/// let calc_a = Qty::new( 1.0.into(), &Unit::Ampere ) + 0.1;
+	///
+	/// assert_eq!( calc_a, Qty::new( 1.1.into(), &Unit::Ampere ) );
+	/// assert_eq!( calc_a.number().prefix(), Prefix::Nothing );
+	/// assert_eq!( calc_a.unit(), &Unit::Ampere );
+	///
+	/// let calc_b = Qty::new( Num::new( 32.0 ).with_prefix( Prefix::Milli ), &Unit::Gram ) + 4.0;
+	///
+	/// assert_eq!( calc_b, Qty::new( Num::new( 4_000_032.0 ).with_prefix( Prefix::Milli ), &Unit::Gram ) );
+	/// assert_eq!( calc_b.number().prefix(), Prefix::Milli );
+	/// ```
+	fn add( self, other: f64 ) -> Self::Output {
+		let val = self.as_f64() + other;
+
+		Self::new( val.into(), &self.unit.base() )
+			.to_unit( &self.unit ).unwrap()
+			.to_prefix( self.number.prefix() )
+	}
+}
+
+impl Sub for Qty {
+	type Output = Self;
+
+	/// The subtraction operator `-`. The resulting `Qty` will keep the prefix and unit of `self`.
+	///
+	/// **Note:** Subtracting two `Qty`s representing different physical quantities results in a **panic**.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Qty, Num, Unit, Prefix};
+	/// let calc_a = Qty::new( 1.0.into(), &Unit::Ampere ) - Qty::new( 0.1.into(), &Unit::Ampere );
+	///
+	/// assert_eq!( calc_a, Qty::new( 0.9.into(), &Unit::Ampere ) );
+	/// assert_eq!( calc_a.number().prefix(), Prefix::Nothing );
+	/// assert_eq!( calc_a.unit(), &Unit::Ampere );
+	///
+	/// let calc_b = Qty::new( Num::new( 8.0 ).with_prefix( Prefix::Milli ), &Unit::Gram ) - Qty::new( 4.0.into(), &Unit::Tonne );
+	///
+	/// assert_eq!( calc_b, Qty::new( Num::new( -3_999_999_992.0 ).with_prefix( Prefix::Milli ), &Unit::Gram ) );
+	/// assert_eq!( calc_b.number().prefix(), Prefix::Milli );
+	/// ```
+	fn sub( self, other: Self ) -> Self::Output {
+		self.checked_sub( other ).unwrap()
+	}
+}
+
+impl Sub<f64> for Qty {
+	type Output = Self;
+
+	/// The subtraction operator `-`. The resulting `Num` will keep the prefix.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Qty, Num, Unit, Prefix};
+	/// let calc_a = Qty::new( 1.0.into(), &Unit::Ampere ) - 0.1;
+	///
+	/// assert_eq!( calc_a, Qty::new( 0.9.into(), &Unit::Ampere ) );
+	/// assert_eq!( calc_a.number().prefix(), Prefix::Nothing );
+	/// assert_eq!( calc_a.unit(), &Unit::Ampere );
+	///
+	/// let calc_b = Qty::new( Num::new( 8.0 ).with_prefix( Prefix::Milli ), &Unit::Gram ) - 4.0;
+	///
+	/// assert_eq!( calc_b, Qty::new( Num::new( -3_999_992.0 ).with_prefix( Prefix::Milli ), &Unit::Gram ) );
+	/// assert_eq!( calc_b.number().prefix(), Prefix::Milli );
+	/// ```
+	fn sub( self, other: f64 ) -> Self::Output {
+		let val = self.as_f64() - other;
+
+		Self::new( val.into(), &self.unit.base() )
+			.to_unit( &self.unit ).unwrap()
+			.to_prefix( self.number.prefix() )
+	}
+}
+
+impl Mul for Qty {
+	type Output = Self;
+
+	/// The multiplication operator `*`. The unit of the result is the product of both units, units of the same physical quantity being merged into the unit of `self` (t·kg becomes t²).
+	///
+	/// The prefix of the product is not inherited from either factor. Instead it is chosen by `shortened()` on the combined magnitude, since the product of two prefixed values generally lands on a completely different order of magnitude. If the product cannot be shortened (its magnitude lies outside of the range representable by a `Prefix`), the prefix of `self` is kept.
+	///
+	/// **Note:** Multiplying a `Qty` using an affine unit like degree Celsius results in a **panic** (see `checked_mul()`).
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Qty, Num, Unit, Prefix};
+	/// let calc_a = Qty::new( 2.0.into(), &Unit::Ampere ) * Qty::new( 3.0.into(), &Unit::Second );
+	///
+	/// assert_eq!( calc_a.as_f64(), 6.0 );
+	/// assert_eq!( calc_a.unit(), &Unit::compound( vec![ ( Unit::Ampere, 1 ), ( Unit::Second, 1 ) ] ) );
+	/// assert_eq!( calc_a.to_string(), "6 A·s" );
+	///
+	/// let calc_b = Qty::new( Num::new( 2.0 ).with_prefix( Prefix::Kilo ), &Unit::Meter ) * Qty::new( Num::new( 3.0 ).with_prefix( Prefix::Kilo ), &Unit::Meter );
+	///
+	/// assert_eq!( calc_b.as_f64(), 6e6 );
+	/// assert_eq!( calc_b.to_string(), "6 km²" );
+	/// ```
+	fn mul( self, other: Self ) -> Self::Output {
+		self.checked_mul( other ).unwrap()
+	}
+}
+
+impl Mul<f64> for Qty {
+	type Output = Self;
+
+	/// The multiplication operator `*`. The resulting `Qty` will keep the prefix.
+	///
+	/// The number is scaled in the unit of `self`, so for affine units like degree Celsius, 20 °C * 2 results in 40 °C.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Qty, Num, Unit, Prefix};
+	/// let calc_a = Qty::new( 1.0.into(), &Unit::Ampere ) * 0.1;
+	///
+	/// assert_eq!( calc_a, Qty::new( 0.1.into(), &Unit::Ampere ) );
+	/// assert_eq!( calc_a.number().prefix(), Prefix::Nothing );
+	/// assert_eq!( calc_a.unit(), &Unit::Ampere );
+	///
+	/// let calc_b = Qty::new( Num::new( 8.0 ).with_prefix( Prefix::Milli ), &Unit::Gram ) * 4.0;
+	///
+	/// assert_eq!( calc_b, Qty::new( Num::new( 32.0 ).with_prefix( Prefix::Milli ), &Unit::Gram ) );
+	/// assert_eq!( calc_b.number().prefix(), Prefix::Milli );
+	/// ```
+	fn mul( self, other: f64 ) -> Self::Output {
+		Self::new( Num::new( self.number.mantissa() * other ).with_prefix( self.number.prefix() ), &self.unit )
+	}
+}
+
+impl MulAssign<f64> for Qty {
+	/// The multiplication operator `*=`. `self` will keep the prefix.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Qty, Num, Unit, Prefix};
+	/// let mut calc_a = Qty::new( 1.0.into(), &Unit::Ampere );
+	/// calc_a *= 0.1;
+	///
+	/// assert_eq!( calc_a, Qty::new( 0.1.into(), &Unit::Ampere ) );
+	/// assert_eq!( calc_a.number().prefix(), Prefix::Nothing );
+	/// assert_eq!( calc_a.unit(), &Unit::Ampere );
+	///
+	/// let mut calc_b = Qty::new( Num::new( 8.0 ).with_prefix( Prefix::Milli ), &Unit::Gram );
+	/// calc_b *= 4.0;
+	///
+	/// assert_eq!( calc_b, Qty::new( Num::new( 32.0 ).with_prefix( Prefix::Milli ), &Unit::Gram ) );
+	/// assert_eq!( calc_b.number().prefix(), Prefix::Milli );
+	/// ```
+	fn mul_assign( &mut self, rhs: f64 ) {
+		self.number *= rhs;
+	}
+}
+
+impl Div for Qty {
+	type Output = Self;
+
+	/// The division operator `/`. The unit of the result is the quotient of both units, units of the same physical quantity being merged into the unit of `self` and cancelling each other out (m²/m becomes m).
+	///
+	/// The prefix of the result is chosen by `shortened()` on the combined magnitude (see `mul()`).
+	///
+	/// **Note:** Dividing a `Qty` using an affine unit like degree Celsius results in a **panic** (see `checked_div()`).
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Qty, Num, Unit, Prefix};
+	/// let area = Qty::new( 6.0.into(), &Unit::compound( vec![ ( Unit::Meter, 2 ) ] ) );
+	/// let calc_a = area / Qty::new( 2.0.into(), &Unit::Meter );
+	///
+	/// assert_eq!( calc_a, Qty::new( 3.0.into(), &Unit::Meter ) );
+	/// assert_eq!( calc_a.unit(), &Unit::Meter );
+	///
+	/// let calc_b = Qty::new( Num::new( 8.0 ).with_prefix( Prefix::Kilo ), &Unit::Meter ) / Qty::new( 4.0.into(), &Unit::Second );
+	///
+	/// assert_eq!( calc_b.as_f64(), 2e3 );
+	/// assert_eq!( calc_b.to_string(), "2 km·s⁻¹" );
+	///
+	/// let calc_c = Qty::new( 1.0.into(), &Unit::Ampere ) / Qty::new( 0.1.into(), &Unit::Ampere );
+	///
+	/// assert_eq!( calc_c.as_f64(), 10.0 );
+	/// assert_eq!( calc_c.unit(), &Unit::One );
+	/// ```
+	fn div( self, other: Self ) -> Self::Output {
+		self.checked_div( other ).unwrap()
+	}
+}
+
+impl Div<f64> for Qty {
+	type Output = Self;
+
+	/// The multiplication operator `/`. The resulting `Qty` will keep the prefix.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Qty, Num, Unit, Prefix};
+	/// let calc_a = Qty::new( 1.0.into(), &Unit::Ampere ) / 0.1;
+	///
+	/// assert_eq!( calc_a, Qty::new( 10.0.into(), &Unit::Ampere ) );
+	/// assert_eq!( calc_a.number().prefix(), Prefix::Nothing );
+	/// assert_eq!( calc_a.unit(), &Unit::Ampere );
+	///
+	/// let calc_b = Qty::new( Num::new( 8.0 ).with_prefix( Prefix::Milli ), &Unit::Gram ) / 4.0;
+	///
+	/// assert_eq!( calc_b, Qty::new( Num::new( 2.0 ).with_prefix( Prefix::Milli ), &Unit::Gram ) );
+	/// assert_eq!( calc_b.number().prefix(), Prefix::Milli );
+	/// ```
+	fn div( self, other: f64 ) -> Self::Output {
+		Self::new( Num::new( self.number.mantissa() / other ).with_prefix( self.number.prefix() ), &self.unit )
+	}
+}
+
+impl Add<&Qty> for &Qty {
+	type Output = Qty;
+
+	/// The addition operator `+` for references, without consuming the operands. Behaves identical to adding owned `Qty`s.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Qty, Unit};
+	/// let a = Qty::new( 1.0.into(), &Unit::Ampere );
+	/// let b = Qty::new( 0.5.into(), &Unit::Ampere );
+	///
+	/// assert_eq!( &a + &b, Qty::new( 1.5.into(), &Unit::Ampere ) );
+	/// assert_eq!( a, Qty::new( 1.0.into(), &Unit::Ampere ) );
+	/// ```
+	fn add( self, other: &Qty ) -> Self::Output {
+		self.clone() + other.clone()
+	}
+}
+
+impl Sub<&Qty> for &Qty {
+	type Output = Qty;
+
+	/// The subtraction operator `-` for references, without consuming the operands. Behaves identical to subtracting owned `Qty`s.
+	fn sub( self, other: &Qty ) -> Self::Output {
+		self.clone() - other.clone()
+	}
+}
+
+impl Mul<&Qty> for &Qty {
+	type Output = Qty;
+
+	/// The multiplication operator `*` for references, without consuming the operands. Behaves identical to multiplying owned `Qty`s.
+	fn mul( self, other: &Qty ) -> Self::Output {
+		self.clone() * other.clone()
+	}
+}
+
+impl Div<&Qty> for &Qty {
+	type Output = Qty;
+
+	/// The division operator `/` for references, without consuming the operands. Behaves identical to dividing owned `Qty`s.
+	fn div( self, other: &Qty ) -> Self::Output {
+		self.clone() / other.clone()
+	}
+}
+
+impl Mul<f64> for &Qty {
+	type Output = Qty;
+
+	/// The multiplication operator `*` for a reference, without consuming the `Qty`. Behaves identical to multiplying an owned `Qty`.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Qty, Unit};
+	/// let a = Qty::new( 1.5.into(), &Unit::Meter );
+	///
+	/// assert_eq!( &a * 2.0, Qty::new( 3.0.into(), &Unit::Meter ) );
+	/// assert_eq!( -&a, Qty::new( ( -1.5 ).into(), &Unit::Meter ) );
+	/// assert_eq!( a, Qty::new( 1.5.into(), &Unit::Meter ) );
+	/// ```
+	fn mul( self, other: f64 ) -> Self::Output {
+		self.clone() * other
+	}
+}
+
+impl Div<f64> for &Qty {
+	type Output = Qty;
+
+	/// The division operator `/` for a reference, without consuming the `Qty`. Behaves identical to dividing an owned `Qty`.
+	fn div( self, other: f64 ) -> Self::Output {
+		self.clone() / other
+	}
+}
+
+impl Neg for &Qty {
+	type Output = Qty;
+
+	/// The negation operator `-` for a reference, without consuming the `Qty`. Behaves identical to negating an owned `Qty`.
+	fn neg( self ) -> Self::Output {
+		-self.clone()
+	}
+}
+
+impl Neg for Qty {
+	type Output = Self;
+
+	fn neg( self ) -> Self::Output {
+		Self::new( Num::new( -self.number.mantissa() ).with_prefix( self.number.prefix() ), &self.unit )
+	}
+}
+
+impl Sum for Qty {
+	/// Sums up all `Qty`s of `iter`. The resulting `Qty` will keep the prefix and unit of the first element.
+	///
+	/// Like the addition operator `+`, this panics if the `Qty`s represent different physical quantities. Use `Qty::try_sum()` to handle this case gracefully.
+	fn sum<I: Iterator<Item = Self>>( iter: I ) -> Self {
+		Self::try_sum( iter ).unwrap()
+	}
+}
+
+impl<'a> Sum<&'a Qty> for Qty {
+	fn sum<I: Iterator<Item = &'a Self>>( iter: I ) -> Self {
+		iter.cloned().sum()
+	}
+}
+
+impl TryFrom<( f64, &str )> for Qty {
+	type Error = SinumError;
+
+	/// Creates a new `Qty` from a value and the symbol or name of the unit, which might carry a prefix (see `Unit::parse_with_prefix()`). The prefix is applied to the value.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Qty, Num, Unit, Prefix};
+	/// assert_eq!( Qty::try_from( ( 9.9, "km" ) ).unwrap(), Qty::new( Num::new( 9.9 ).with_prefix( Prefix::Kilo ), &Unit::Meter ) );
+	/// assert_eq!( Qty::try_from( ( 2.0, "seconds" ) ).unwrap(), Qty::new( 2.0.into(), &Unit::Second ) );
+	/// assert!( Qty::try_from( ( 1.0, "xyz" ) ).is_err() );
+	/// ```
+	fn try_from( ( value, unit ): ( f64, &str ) ) -> Result<Self, Self::Error> {
+		let ( prefix, unit ) = Unit::parse_with_prefix( unit.trim() )?;
+
+		Ok( Self::new( Num::new( value ).with_prefix( prefix ), &unit ) )
+	}
+}
+
+impl FromStr for Qty {
+	type Err = UnitError;
+
+	/// Parses a string like "9.9 km" or "-2e-3 A" into a `Qty`.
+	///
+	/// The number and the unit symbol have to be separated by whitespace, unless the unit is written without a space (see [`Unit::space_before_symbol`]) like "20°" or "5m°". The unit symbol might be preceded by a prefix symbol. Symbols are case-sensitive (see `Unit::parse_with_prefix()`), so "5 a" is parsed as 5 years and "5 pA" as 5 picoampere.
+	///
+	/// The output of `to_string()` is parsed back into the same prefix and unit, apart from custom and compound units as well as `Unit::One` with a prefix, which has no symbol.
+	///
+	/// The number is parsed like `Num::from_str()` and might start with a "+" sign or contain underscores like "+1_000 m".
+	///
+	/// Whitespace surrounding the string is ignored. Any amount and kind of whitespace (including tabs and the narrow no-break space used by `to_string_si_space()`) may separate the number from the unit, so "  9.9 km  " and "9.9\tkm" are both parsed as 9.9 km.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Qty, Num, Unit, Prefix};
+	/// assert_eq!( "9.9 km".parse::<Qty>().unwrap(), Qty::new( Num::new( 9.9 ).with_prefix( Prefix::Kilo ), &Unit::Meter ) );
+	/// assert_eq!( "-2e-3 A".parse::<Qty>().unwrap(), Qty::new( Num::new( -2e-3 ), &Unit::Ampere ) );
+	/// assert_eq!( "5 mg".parse::<Qty>().unwrap(), Qty::new( Num::new( 5.0 ).with_prefix( Prefix::Milli ), &Unit::Gram ) );
+	/// assert_eq!( "20°".parse::<Qty>().unwrap(), Qty::new( Num::new( 20.0 ), &Unit::Degree ) );
+	/// assert!( "5 xyz".parse::<Qty>().is_err() );
+	/// ```
+	fn from_str( s: &str ) -> Result<Self, Self::Err> {
+		let s_trim = s.trim();
+		let ( number, unit ) = match s_trim.split_once( char::is_whitespace ) {
+			Some( x ) => x,
+			// The prefix of units written without a space might be mistaken for the exponent of the number ("5E°" is 5 exa-degree), so the longest number followed by a valid unit is split off.
+			None => s_trim.char_indices()
+				.rev()
+				.map( |( idx, _ )| s_trim.split_at( idx ) )
+				.find( |( number, unit )| {
+					!number.is_empty() && Num::from_str( number ).is_ok() && Unit::parse_with_prefix( unit ).is_ok()
+				} )
+				.ok_or_else( || UnitError::ParseFailure( s.to_string() ) )?,
+		};
+
+		let number = Num::from_str( number ).map_err( |_| UnitError::ParseFailure( s.to_string() ) )?;
+		let ( prefix, unit ) = Unit::parse_with_prefix( unit.trim() )?;
+
+		// Only units written without a space may directly follow the number.
+		if !s_trim.contains( char::is_whitespace ) && unit.space_before_symbol() {
+			return Err( UnitError::ParseFailure( s.to_string() ) );
+		}
+
+		Ok( Self::new( number.with_prefix( prefix ), &unit ) )
+	}
+}
+
+/// The struct form of a `Qty` used by serde.
+#[cfg( feature = "serde" )]
+#[derive( Serialize, Deserialize )]
+#[serde( rename = "Qty" )]
+struct QtyFields {
+	number: Num,
+	unit: Unit,
+}
+
+/// The representations of a `Qty` accepted from human-readable formats.
+#[cfg( feature = "serde" )]
+#[derive( Deserialize )]
+#[serde( untagged )]
+enum QtyRepr {
+	Text( String ),
+	Fields( QtyFields ),
+}
+
+#[cfg( feature = "serde" )]
+impl Serialize for Qty {
+	/// Serializes `self` as string like "9.9 km" if the format is human-readable and the string is parsed back into the same unit and prefix. Otherwise the fields of `self` are serialized.
+	fn serialize<S>( &self, serializer: S ) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		if serializer.is_human_readable() {
+			let text = self.to_string_rounded( None );
+			let is_parsable = text.parse::<Self>()
+				.is_ok_and( |x| x.unit == self.unit && x.number.prefix() == self.number.prefix() );
+
+			if is_parsable {
+				return serializer.serialize_str( &text );
+			}
+		}
+
+		QtyFields {
+			number: self.number,
+			unit: self.unit.clone(),
+		}.serialize( serializer )
+	}
+}
+
+#[cfg( feature = "serde" )]
+impl<'de> Deserialize<'de> for Qty {
+	/// Deserializes a `Qty` from the representations created by `serialize()`. Human-readable formats accept both, strings and the fields.
+	fn deserialize<D>( deserializer: D ) -> Result<Self, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		if !deserializer.is_human_readable() {
+			let fields = QtyFields::deserialize( deserializer )?;
+			return Ok( Self::new( fields.number, &fields.unit ) );
+		}
+
+		match QtyRepr::deserialize( deserializer )? {
+			QtyRepr::Text( x ) => x.parse::<Self>().map_err( D::Error::custom ),
+			QtyRepr::Fields( x ) => Ok( Self::new( x.number, &x.unit ) ),
+		}
+	}
+}
+
+impl fmt::Display for Qty {
+	/// Writes the quantity like "9.9 km". The mantissa is rounded as set by `set_display_rounding()`.
+	///
+	/// Infinite quantities are written as "∞ A" or "-∞ A" and NaN as "NaN A", omitting the prefix. Use `is_finite()` to guard against those values before displaying them.
+	///
+	/// The alternate flag (`{:#}`) writes the quantity with engineering notation like `to_string_eng()` instead.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Qty, Num, Unit, Prefix};
+	/// assert_eq!( Qty::new( Num::new( 9.9 ).with_prefix( Prefix::Kilo ), &Unit::Meter ).to_string(), "9.9 km" );
+	/// assert_eq!( format!( "{:#}", Qty::new( Num::new( 9.9 ).with_prefix( Prefix::Kilo ), &Unit::Meter ) ), "9.9×10^3 m" );
+	/// assert_eq!( ( Qty::new( 1.0.into(), &Unit::Ampere ) / 0.0 ).to_string(), "∞ A" );
+	/// ```
+	fn fmt( &self, f: &mut fmt::Formatter ) -> fmt::Result {
+		match f.alternate() {
+			true => write!( f, "{}", self.to_string_eng() ),
+			false => write!( f, "{}", self.to_string_with( ' ', display_rounding() ) ),
+		}
+	}
+}
+
+#[cfg( feature = "i18n" )]
+impl DisplayLocale for Qty {
+	/// Writes the quantity like "9,9 Kilometer", using the decimal separator and the names of the prefix and unit of the language specified by `locale`. The mantissa is rounded as set by `set_display_rounding()`.
+	///
+	/// # Example
+	/// ```
+	/// # use unic_langid::langid;
+	/// # use sinum::{DisplayLocale, Qty, Num, Unit, Prefix};
+	/// let x = Qty::new( Num::new( 9.9 ).with_prefix( Prefix::Kilo ), &Unit::Meter );
+	///
+	/// assert_eq!( x.to_string_locale( &langid!( "en-US" ) ), "9.9 kilometer" );
+	/// assert_eq!( x.to_string_locale( &langid!( "de-DE" ) ), "9,9 Kilometer" );
+	/// ```
+	fn to_string_locale( &self, locale: &LanguageIdentifier ) -> String {
+		let number = self.number.with_prefix( Prefix::Nothing ).to_string_locale( locale );
+		let unit = match self.unit {
+			// Dimensionless quantities are written without any unit.
+			Unit::One => "".to_string(),
+			_ => self.unit.to_string_locale( locale ),
+		};
+
+		// The prefix is meaningless for non-finite values.
+		let name = match self.number.prefix() {
+			Prefix::Nothing => unit,
+			_ if !self.number.is_finite() => unit,
+			x => {
+				// Nouns are capitalized in some languages, but the prefix is the start of the combined word: "Kilo" + "Meter" → "Kilometer".
+				let mut chars = unit.chars();
+				let unit_lower: String = chars.next().into_iter().flat_map( char::to_lowercase ).chain( chars ).collect();
+				format!( "{}{}", x.to_string_locale( locale ), unit_lower )
+			},
+		};
+
+		match name.is_empty() {
+			true => number,
+			false => format!( "{} {}", number, name ),
+		}
+	}
+}
+
+#[cfg( feature = "tex" )]
+impl Latex for Qty {
+	/// Return a string that represents this `Qty` as LaTeX string. The notation of the number is chosen by `options.notation`, defaulting to `Notation::Prefixed`.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Qty, Num, Unit, Prefix, Latex, Notation, TexOptions};
+	/// let x = Qty::new( Num::new( 9.9 ).with_prefix( Prefix::Milli ), &Unit::Ampere );
+	///
+	/// assert_eq!( x.to_latex( &TexOptions::new() ), r"\qty{9.9}{\milli\ampere}" );
+	/// assert_eq!( x.to_latex( &TexOptions::new().notation( Notation::Scientific ) ), r"\qty{9.9e-3}{\ampere}" );
+	/// ```
+	fn to_latex( &self, options: &TexOptions ) -> String {
+		match options.notation.unwrap_or_default() {
+			Notation::Prefixed => self.to_latex_sym( options ),
+			Notation::Engineering => self.to_latex_eng( options ),
+			Notation::Scientific => self.to_latex_sci( options ),
+		}
+	}
+}
+
+#[cfg( feature = "tex" )]
+impl LatexSym for Qty {
+	/// Return a string that represents this `Qty` as LaTeX command (requiring the usage of the `{siunitx}` package in LaTeX).
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::LatexSym;
+	/// # use sinum::{Qty, Unit, Num, Prefix, TexOptions};
+	/// assert_eq!( Qty::new( 9.9.into(), &Unit::Ampere ).to_latex_sym( &TexOptions::none() ), r"\qty{9.9}{\ampere}".to_string() );
+	/// assert_eq!(
+	///     Qty::new( Num::new( 9.9 ).with_prefix( Prefix::Milli ), &Unit::Ampere ).to_latex_sym( &TexOptions::none() ),
+	///     r"\qty{9.9}{\milli\ampere}".to_string()
+	/// );
+	/// ```
+	///
+	/// # Kilogram
+	///
+	/// The base unit for mass, the kilogram is a special case, since it already has a prefix (kilo), that has to be taken into account.
+	/// ```
+	/// # use sinum::LatexSym;
+	/// # use sinum::{Qty, Unit, Num, Prefix, TexOptions};
+	/// assert_eq!( Qty::new( 9.9.into(), &Unit::Kilogram ).to_latex_sym( &TexOptions::new() ), r"\qty{9.9}{\kilogram}".to_string() );
+	/// assert_eq!(
+	///     Qty::new( Num::new( 9.9 ).with_prefix( Prefix::Kilo ), &Unit::Kilogram ).to_latex_sym( &TexOptions::new() ),
+	///     r"\qty{9.9}{\mega\gram}".to_string()
+	/// );
+	/// assert_eq!(
+	///     Qty::new( Num::new( 9.9 ).with_prefix( Prefix::Milli ), &Unit::Kilogram ).to_latex_sym(
+	///         &TexOptions::new()
+	///             .minimum_decimal_digits( 1 )
+	///     ),
+	///     r"\qty{9.9}{\gram}".to_string()
+	/// );
+	/// assert_eq!(
+	///     Qty::new( Num::new( 9.9 ).with_prefix( Prefix::Micro ), &Unit::Kilogram ).to_latex_sym( &TexOptions::new() ),
+	///     r"\qty{9.9}{\milli\gram}".to_string()
+	/// );
+	/// assert_eq!( Qty::new(
+	///     Num::new( 9.9 ).with_prefix( Prefix::Milli ), &Unit::Gram ).to_latex_sym( &TexOptions::new() ),
+	///     r"\qty{9.9}{\milli\gram}".to_string()
+	/// );
+	/// assert_eq!(
+	///     Qty::new( Num::new( 9.9 ).with_prefix( Prefix::Kilo ), &Unit::Gram ).to_latex_sym( &TexOptions::new() ),
+	///     r"\qty{9.9}{\kilogram}".to_string()
+	/// );
+	/// ```
+	fn to_latex_sym( &self, options: &TexOptions ) -> String {
+		let mantissa = options.format_mantissa( self.number.mantissa() );
+		format!(
+			r"{}{}{{{}}}{{{}{}}}",
+			options.qty_command(),
+			options,
+			mantissa,
+			self.number.prefix().to_latex_sym( options ),
+			self.unit.to_latex_sym( options )
+		)
+	}
+}
+
+
+
+/// A flat representation of a `Qty` meant for tabular formats like CSV.
+///
+/// `value` is the numeric value including the prefix, given in the unit represented by the symbol `unit`.
+///
+/// This struct is only available, if the **`serde`** feature has been enabled.
+///
+/// # Example
+/// ```
+/// # use sinum::{Qty, QtyRecord, Num, Unit, Prefix};
+/// let record = QtyRecord::from( &Qty::new( Num::new( 9.9 ).with_prefix( Prefix::Kilo ), &Unit::Meter ) );
+///
+/// assert_eq!( record.value, 9900.0 );
+/// assert_eq!( record.unit, "m" );
+/// assert_eq!( record.physical_quantity, "length" );
+/// ```
+#[cfg( feature = "serde" )]
+#[derive( Serialize, Deserialize, Clone, PartialEq, Debug )]
+pub struct QtyRecord {
+	pub value: f64,
+	pub unit: String,
+	pub physical_quantity: String,
+}
+
+#[cfg( feature = "serde" )]
+impl From<&Qty> for QtyRecord {
+	fn from( item: &Qty ) -> Self {
+		Self {
+			value: item.number.as_f64(),
+			unit: item.unit.to_string_sym(),
+			physical_quantity: item.phys().to_string(),
+		}
+	}
+}
+
+
+
+/// Constructs a `Qty` step by step. Is created by `Qty::builder()` or `QtyBuilder::new()`.
+///
+/// Without setting them explicitly, the builder uses `Prefix::Nothing` and `Unit::One`.
+///
+/// # Example
+/// ```
+/// # use sinum::{Qty, QtyBuilder, Num, Unit, Prefix};
+/// let qty = QtyBuilder::new()
+///     .value( 9.9 )
+///     .prefix( Prefix::Milli )
+///     .unit( Unit::Ampere )
+///     .build();
+///
+/// assert_eq!( qty, Qty::new( Num::new( 9.9 ).with_prefix( Prefix::Milli ), &Unit::Ampere ) );
+/// ```
+#[derive( Clone, PartialEq, Debug )]
+pub struct QtyBuilder {
+	value: f64,
+	prefix: Prefix,
+	unit: Unit,
+}
+
+impl QtyBuilder {
+	/// Create a new `QtyBuilder` with the value 0.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	pub fn value( mut self, value: f64 ) -> Self {
+		self.value = value;
+		self
+	}
+
+	pub fn prefix( mut self, prefix: Prefix ) -> Self {
+		self.prefix = prefix;
+		self
+	}
+
+	pub fn unit( mut self, unit: Unit ) -> Self {
+		self.unit = unit;
+		self
+	}
+
+	/// Returns the `Qty` represented by the builder. Is identical to calling `Qty::new()` with the number and unit set.
+	pub fn build( self ) -> Qty {
+		Qty::new( Num::new( self.value ).with_prefix( self.prefix ), &self.unit )
+	}
+}
+
+impl Default for QtyBuilder {
+	fn default() -> Self {
+		Self {
+			value: 0.0,
+			prefix: Prefix::Nothing,
+			unit: Unit::One,
+		}
+	}
+}
+
+/// Converts quantities given in one unit into another unit. The conversion factor is computed once on creation, which makes a `UnitLens` cheaper than calling `Qty::to_unit()` for every quantity when converting many quantities of the same unit.
+///
+/// # Example
+/// ```
+/// # use sinum::{Qty, Unit, UnitLens};
+/// let lens = UnitLens::new( &Unit::Mile, &Unit::Meter ).unwrap();
+/// let distances = [ 1.0, 2.5, 26.2 ].map( |x| Qty::new( x.into(), &Unit::Mile ) );
+///
+/// for qty in distances {
+///     assert_eq!( lens.apply( &qty ), qty.to_unit( &Unit::Meter ).unwrap() );
+/// }
+/// ```
+#[derive( Clone, PartialEq, Debug )]
+pub struct UnitLens {
+	source: Unit,
+	target: Unit,
+	factor: f64,
+	offset: f64,
+}
+
+impl UnitLens {
+	/// Create a new `UnitLens` converting quantities given in `source` into `target`.
+	///
+	/// If `source` and `target` do not represent the same physical quantity, this function returns an `UnitError`.
+	pub fn new( source: &Unit, target: &Unit ) -> Result<Self, UnitError> {
+		if source.phys() != target.phys() {
+			return Err( UnitError::UnitMismatch( vec![ source.clone(), target.clone() ] ) );
+		}
+
+		Ok( Self {
+			source: source.clone(),
+			target: target.clone(),
+			factor: source.factor() / target.factor(),
+			offset: ( source.offset() - target.offset() ) / target.factor(),
+		} )
+	}
+
+	/// Returns the unit of the quantities this `UnitLens` accepts.
+	pub fn source( &self ) -> &Unit {
+		&self.source
+	}
+
+	/// Returns the unit the quantities are converted into.
+	pub fn target( &self ) -> &Unit {
+		&self.target
+	}
+
+	/// Returns the factor the numeric value is multiplied with during conversion.
+	pub fn factor( &self ) -> f64 {
+		self.factor
+	}
+
+	/// Returns `qty` converted into the target unit. The result is identical to `qty.to_unit( lens.target() )`.
+	///
+	/// Quantities given in a unit other than the source unit (like gram instead of kilogram, which `Qty::new()` chooses for prefixed masses) are converted by `Qty::to_unit()` without the benefit of the precomputed factor.
+	///
+	/// # Panics
+	/// Panics if `qty` does not represent the physical quantity of the source unit.
+	pub fn apply( &self, qty: &Qty ) -> Qty {
+		if qty.unit() != &self.source {
+			return qty.to_unit( &self.target )
+				.unwrap_or_else( |_| panic!( "`UnitLens` expects quantities convertible to `{}`, got `{}`", self.target, qty.unit() ) );
+		}
+
+		// The meaning of the prefix changes, if it is raised to a different power in the new unit.
+		if self.source.leading().1 != self.target.leading().1 && qty.number().prefix() != Prefix::Nothing {
+			let prefix = qty.number().prefix();
+			return self.apply( &qty.clone().to_prefix( Prefix::Nothing ) ).to_prefix( prefix );
+		}
+
+		Qty::new( qty.number() * self.factor + self.offset, &self.target )
+	}
+}
+
+
+/// Wraps a `Qty` to provide equality, a total order and hashing based on the physical quantity and the value with respect to the base unit (see `Qty::as_f64()`), so "1 t" and "1000 kg" are considered identical. This allows for using quantities as keys in a `HashSet` or a `BTreeMap`.
+///
+/// Quantities are ordered by their physical quantity first, which has no physical meaning (see `sort_by_base_value()`). The caveats of comparing floating point values by their exact representation apply like for `OrderedNum`: quantities differing only by rounding errors of the unit conversion are considered different.
+///
+/// # Example
+/// ```
+/// # use std::collections::HashSet;
+/// # use sinum::{Qty, OrderedQty, Unit};
+/// let set: HashSet<OrderedQty> = [
+///     Qty::new( 1.0.into(), &Unit::Tonne ),
+///     Qty::new( 1000.0.into(), &Unit::Kilogram ),
+/// ].into_iter().map( OrderedQty ).collect();
+///
+/// assert_eq!( set.len(), 1 );
+/// ```
+#[derive( Clone, Debug )]
+pub struct OrderedQty( pub Qty );
+
+impl OrderedQty {
+	/// Returns the physical quantity and the numeric value `self` is compared and hashed by.
+	fn key( &self ) -> ( PhysicalQuantity, f64 ) {
+		( self.0.phys(), canonical( self.0.as_f64() ) )
+	}
+}
+
+impl From<Qty> for OrderedQty {
+	fn from( item: Qty ) -> Self {
+		Self( item )
+	}
+}
+
+impl PartialEq for OrderedQty {
+	fn eq( &self, other: &Self ) -> bool {
+		self.cmp( other ) == Ordering::Equal
+	}
+}
+
+impl Eq for OrderedQty {}
+
+impl PartialOrd for OrderedQty {
+	fn partial_cmp( &self, other: &Self ) -> Option<Ordering> {
+		Some( self.cmp( other ) )
+	}
+}
+
+impl Ord for OrderedQty {
+	fn cmp( &self, other: &Self ) -> Ordering {
+		let ( phys_a, val_a ) = self.key();
+		let ( phys_b, val_b ) = other.key();
+
+		phys_a.cmp( &phys_b ).then_with( || val_a.total_cmp( &val_b ) )
+	}
+}
+
+impl Hash for OrderedQty {
+	fn hash<H: Hasher>( &self, state: &mut H ) {
+		let ( phys, val ) = self.key();
+		phys.hash( state );
+		val.to_bits().hash( state );
+	}
+}
+
+
+
+
+//=============================================================================
+// Functions
+
+
+/// Groups the quantities of `values` by the physical quantity they represent, e.g. to render a section per physical quantity. The quantities keep their order within each group.
+///
+/// # Example
+/// ```
+/// # use sinum::{Qty, Unit, PhysicalQuantity, group_by_quantity};
+/// let values = [
+///     Qty::new( 1.0.into(), &Unit::Meter ),
+///     Qty::new( 2.0.into(), &Unit::Second ),
+///     Qty::new( 3.0.into(), &Unit::Foot ),
+/// ];
+/// let groups = group_by_quantity( &values );
+///
+/// assert_eq!( groups.len(), 2 );
+/// assert_eq!( groups[&PhysicalQuantity::Length], vec![ values[0].clone(), values[2].clone() ] );
+/// ```
+pub fn group_by_quantity( values: &[Qty] ) -> HashMap<PhysicalQuantity, Vec<Qty>> {
+	let mut res: HashMap<PhysicalQuantity, Vec<Qty>> = HashMap::new();
+	for qty in values {
+		res.entry( qty.phys() ).or_default().push( qty.clone() );
+	}
+
+	res
+}
+
+
+/// Sorts `values` by their physical quantity first and by their value with respect to the base unit second, e.g. "1 km" is placed after "900 m". The sort is stable, so equal quantities like "1 km" and "1000 m" keep their order.
+///
+/// This ordering is meant for display purposes only: different physical quantities cannot be compared, so their relative order has no physical meaning.
+///
+/// # Example
+/// ```
+/// # use sinum::{Qty, Num, Prefix, Unit, sort_by_base_value};
+/// let mut values = [
+///     Qty::new( Num::new( 1.0 ).with_prefix( Prefix::Kilo ), &Unit::Meter ),
+///     Qty::new( 2.0.into(), &Unit::Second ),
+///     Qty::new( 900.0.into(), &Unit::Meter ),
+///     Qty::new( 1.0.into(), &Unit::Minute ),
+/// ];
+/// sort_by_base_value( &mut values );
+///
+/// assert_eq!( values.map( |x| x.to_string() ), [ "900 m", "1 km", "2 s", "1 min" ].map( |x| x.to_string() ) );
+/// ```
+pub fn sort_by_base_value( values: &mut [Qty] ) {
+	values.sort_by( |a, b| {
+		a.phys().cmp( &b.phys() )
+			.then_with( || a.as_f64().total_cmp( &b.as_f64() ) )
+	} );
+}
+
+
+/// Sums up the quantities of `values` sharing exactly the same unit and returns one `Qty` per unit in the order of their first occurrence. Each sum keeps the prefix of the first occurrence of its unit.
+///
+/// Quantities of the same physical quantity but different units (like kilometer and mile) are kept separate. Note that `Qty::new()` represents prefixed masses in gram, so "2 kg" (kilogram) and "500 mg" (gram) are not merged either.
+///
+/// # Example
+/// ```
+/// # use sinum::{Qty, Num, Prefix, Unit, merge_equal_units};
+/// let values = [
+///     Qty::new( Num::new( 2.0 ).with_prefix( Prefix::Kilo ), &Unit::Meter ),
+///     Qty::new( 3.0.into(), &Unit::Mile ),
+///     Qty::new( 500.0.into(), &Unit::Meter ),
+/// ];
+/// let merged = merge_equal_units( &values );
+///
+/// assert_eq!( merged.len(), 2 );
+/// assert_eq!( merged[0].to_string(), "2.5 km" );
+/// assert_eq!( merged[1].to_string(), "3 mi" );
+/// ```
+pub fn merge_equal_units( values: &[Qty] ) -> Vec<Qty> {
+	let mut res: Vec<Qty> = Vec::new();
+	for qty in values {
+		match res.iter_mut().find( |x| x.unit == qty.unit ) {
+			Some( x ) => *x = x.clone() + qty.clone(),
+			None => res.push( qty.clone() ),
+		}
+	}
+
+	res
+}
+
+
+
+
+//=============================================================================
+// Testing
+
+
+#[cfg( test )]
+mod tests {
+	use super::*;
+
+	use crate::Prefix;
+
+	#[test]
+	fn qty_eq() {
+		assert!( Qty::new( 10e3.into(), &Unit::Kilogram ) == Qty::new( 10.0.into(), &Unit::Tonne ) );
+	}
+
+	#[test]
+	fn siqty_as_f64() {
+		// `as_f64()` returns the value with regard to the base unit.
+		assert_eq!( Qty::new( 9.9.into(), &Unit::Tonne ).as_f64(), 9.9e3 );
+		assert_eq!( Qty::new( Num::new( 8.0 ).with_prefix( Prefix::Milli ), &Unit::Gram ).as_f64(), 8.0e-6 );
+	}
+
+	#[test]
+	fn siqty_string() {
+		assert_eq!( Qty::new( 9.9.into(), &Unit::Ampere ).to_string(), "9.9 A".to_string() );
+		assert_eq!( Qty::new( Num::new( 9.9 ).with_prefix( Prefix::Kilo ), &Unit::Meter ).to_string(), "9.9 km".to_string() );
+		assert_eq!( Qty::new( 9.9.into(), &Unit::Kelvin ).to_string(), "9.9 K".to_string() );
+	}
+
+	#[test]
+	fn qty_electrical() {
+		assert_eq!( Qty::new( 5.0.into(), &Unit::Volt ).to_string(), "5 V".to_string() );
+		assert_eq!( Qty::new( Num::new( 2.5 ).with_prefix( Prefix::Kilo ), &Unit::Watt ).to_string(), "2.5 kW".to_string() );
+		assert!( Qty::new( 5.0.into(), &Unit::Volt ).to_unit( &Unit::Watt ).is_err() );
+	}
+
+	#[test]
+	fn qty_try_new_checked() {
+		assert!( matches!( Qty::try_new_checked( f64::NAN, &Unit::Ampere ), Err( QtyError::NotFinite( _ ) ) ) );
+		assert!( matches!( Qty::try_new_checked( f64::INFINITY, &Unit::Ampere ), Err( QtyError::NotFinite( _ ) ) ) );
+		assert!( matches!( Qty::try_new_checked( -2.0, &Unit::Tonne ), Err( QtyError::BelowMinimum( x, _ ) ) if x == -2e3 ) );
+		assert_eq!( Qty::try_new_checked( 2.0, &Unit::Tonne ).unwrap(), Qty::new( 2.0.into(), &Unit::Tonne ) );
+		assert_eq!( Qty::try_new_checked( -2.0, &Unit::Meter ).unwrap(), Qty::new( Num::new( -2.0 ), &Unit::Meter ) );
+	}
+
+	#[test]
+	fn qty_temperature() {
+		let kelvin = Qty::new( 300.0.into(), &Unit::Kelvin );
+		let celsius = kelvin.to_unit( &Unit::Celsius ).unwrap();
+		assert!( ( celsius.number().as_f64() - 26.85 ).abs() < 1e-9 );
+		assert!( ( celsius.to_unit( &Unit::Kelvin ).unwrap().number().as_f64() - 300.0 ).abs() < 1e-9 );
+
+		let fahrenheit = Qty::new( 100.0.into(), &Unit::Celsius ).to_unit( &Unit::Fahrenheit ).unwrap();
+		assert!( ( fahrenheit.number().as_f64() - 212.0 ).abs() < 1e-9 );
+		assert!( ( fahrenheit.to_unit( &Unit::Celsius ).unwrap().number().as_f64() - 100.0 ).abs() < 1e-9 );
+
+		assert!( ( Qty::new( Num::new( -40.0 ), &Unit::Fahrenheit ).to_unit( &Unit::Celsius ).unwrap().number().as_f64() + 40.0 ).abs() < 1e-9 );
+		assert_eq!( Qty::new( 0.0.into(), &Unit::Celsius ).as_f64(), 273.15 );
+	}
+
+	#[test]
+	fn qty_affine_arithmetic() {
+		let celsius = |x: f64| Qty::new( x.into(), &Unit::Celsius );
+		let fahrenheit = |x: f64| Qty::new( x.into(), &Unit::Fahrenheit );
+		let close = |a: &Qty, b: f64| ( a.number().mantissa() - b ).abs() < 1e-9;
+
+		assert!( close( &celsius( 20.0 ).checked_add( celsius( 10.0 ) ).unwrap(), 30.0 ) );
+		assert!( close( &celsius( 20.0 ).checked_sub( celsius( 10.0 ) ).unwrap(), 10.0 ) );
+		assert!( close( &( celsius( 20.0 ) + fahrenheit( 50.0 ) ), 30.0 ) );
+		assert!( close( &( fahrenheit( 50.0 ) + fahrenheit( 10.0 ) ), 60.0 ) );
+		assert!( close( &( fahrenheit( 50.0 ) - celsius( 10.0 ) ), 0.0 ) );
+
+		assert!( close( &-celsius( 20.0 ), -20.0 ) );
+		assert!( close( &-fahrenheit( 20.0 ), -20.0 ) );
+		assert!( close( &( celsius( 20.0 ) * 2.0 ), 40.0 ) );
+		assert!( close( &( fahrenheit( 20.0 ) * 2.0 ), 40.0 ) );
+		assert!( close( &( celsius( 20.0 ) / 2.0 ), 10.0 ) );
+		assert!( close( &celsius( -10.0 ).abs(), 10.0 ) );
+		assert!( close( &fahrenheit( -10.0 ).abs(), 10.0 ) );
+
+		let values = [ celsius( 20.0 ), celsius( 10.0 ) ];
+		assert!( close( &Qty::try_sum( values.clone() ).unwrap(), 30.0 ) );
+		assert!( close( &values.iter().sum::<Qty>(), 30.0 ) );
+		assert!( close( &merge_equal_units( &values )[0], 30.0 ) );
+		assert!( close( &Qty::try_sum( [ fahrenheit( 20.0 ), fahrenheit( 10.0 ) ] ).unwrap(), 30.0 ) );
+		assert!( close( &merge_equal_units( &[ fahrenheit( 20.0 ), fahrenheit( 10.0 ) ] )[0], 30.0 ) );
+
+		assert_eq!( celsius( 20.0 ).unit(), &Unit::Celsius );
+		assert_eq!( ( celsius( 20.0 ) + celsius( 10.0 ) ).unit(), &Unit::Celsius );
+	}
+
+	#[test]
+	fn siqty_string_si_space() {
+		assert_eq!( Qty::new( 9.9.into(), &Unit::Ampere ).to_string_si_space(), "9.9\u{202F}A".to_string() );
+		assert!( Qty::new( Num::new( 9.9 ).with_prefix( Prefix::Milli ), &Unit::Gram ).to_string_si_space().contains( '\u{202F}' ) );
+		assert!( !Qty::new( 9.9.into(), &Unit::Ampere ).to_string().contains( '\u{202F}' ) );
+	}
+
+	#[test]
+	fn qty_from_str() {
+		assert_eq!( Qty::from_str( "9.9 km" ).unwrap(), Qty::new( Num::new( 9.9 ).with_prefix( Prefix::Kilo ), &Unit::Meter ) );
+		assert_eq!( Qty::from_str( "2e-3 A" ).unwrap(), Qty::new( Num::new( 2e-3 ), &Unit::Ampere ) );
+		assert_eq!( Qty::from_str( "+7 µs" ).unwrap(), Qty::new( Num::new( 7.0 ).with_prefix( Prefix::Micro ), &Unit::Second ) );
+		assert_eq!( Qty::from_str( "7 us" ).unwrap(), Qty::new( Num::new( 7.0 ).with_prefix( Prefix::Micro ), &Unit::Second ) );
+		assert_eq!( Qty::from_str( "2.5 uA" ).unwrap().to_string(), "2.5 µA" );
+		assert_eq!( Qty::from_str( "-1.5 MPa" ).unwrap(), Qty::new( Num::new( -1.5 ).with_prefix( Prefix::Mega ), &Unit::Pascal ) );
+		assert_eq!( Qty::from_str( "3 dam" ).unwrap(), Qty::new( Num::new( 3.0 ).with_prefix( Prefix::Deca ), &Unit::Meter ) );
+		assert_eq!( Qty::from_str( "1 pc" ).unwrap().unit(), &Unit::Parsec );
+		assert_eq!( Qty::from_str( "2 mol" ).unwrap().unit(), &Unit::Mole );
+		assert_eq!( Qty::from_str( "5 pA" ).unwrap(), Qty::new( Num::new( 5.0 ).with_prefix( Prefix::Pico ), &Unit::Ampere ) );
+		assert_eq!( Qty::from_str( "5 pC" ).unwrap(), Qty::new( Num::new( 5.0 ).with_prefix( Prefix::Pico ), &Unit::Coulomb ) );
+		assert_eq!( Qty::try_from( ( 5.0, "pA" ) ).unwrap(), Qty::new( Num::new( 5.0 ).with_prefix( Prefix::Pico ), &Unit::Ampere ) );
+		assert!( Qty::from_str( "5 Mb" ).is_err() );
+
+		let mg = Qty::from_str( "9.9 mg" ).unwrap();
+		assert_eq!( mg.unit(), &Unit::Gram );
+		assert_eq!( mg.number().prefix(), Prefix::Milli );
+		assert_eq!( Qty::from_str( "9.9 kg" ).unwrap().unit(), &Unit::Kilogram );
+
+		assert!( matches!( Qty::from_str( "5 xyz" ), Err( UnitError::ParseFailure( _ ) ) ) );
+		assert!( Qty::from_str( "five m" ).is_err() );
+		assert!( Qty::from_str( "5" ).is_err() );
+	}
+
+	#[test]
+	fn qty_from_str_literal() {
+		assert_eq!( Qty::from_str( "+1_000 m" ).unwrap(), Qty::new( 1000.0.into(), &Unit::Meter ) );
+		assert_eq!( Qty::from_str( "+1_000 m" ).unwrap().number().prefix(), Prefix::Nothing );
+		assert_eq!( Qty::from_str( "+9.9 km" ).unwrap(), Qty::new( Num::new( 9.9 ).with_prefix( Prefix::Kilo ), &Unit::Meter ) );
+		assert_eq!( Qty::from_str( "1_080°" ).unwrap(), Qty::new( 1080.0.into(), &Unit::Degree ) );
+		assert!( Qty::from_str( "_1 m" ).is_err() );
+	}
+
+	#[test]
+	fn qty_from_str_whitespace() {
+		let expected = Qty::new( Num::new( 9.9 ).with_prefix( Prefix::Kilo ), &Unit::Meter );
+		for s in [
+			"  9.9 km  ",
+			"9.9\tkm",
+			"9.9   km",
+			"\t9.9 \t km\n",
+			"9.9\u{A0}km",
+			"9.9\u{202F}km",
+		] {
+			let res = Qty::from_str( s ).unwrap();
+			assert_eq!( res, expected, "{:?}", s );
+			assert_eq!( res.number().prefix(), Prefix::Kilo, "{:?}", s );
+		}
+
+		assert_eq!( Qty::from_str( " 20° " ).unwrap(), Qty::new( 20.0.into(), &Unit::Degree ) );
+		assert_eq!( Qty::from_str( "5  astronomical \t units" ).unwrap().unit(), &Unit::AstronomicalUnit );
+		assert!( Qty::from_str( "   " ).is_err() );
+		assert!( Qty::from_str( "9.9 k m" ).is_err() );
+	}
+
+	#[test]
+	fn qty_from_str_round_trip() {
+		for qty in [
+			Qty::new( Num::new( 9.9 ).with_prefix( Prefix::Kilo ), &Unit::Meter ),
+			Qty::new( Num::new( -2.5 ).with_prefix( Prefix::Micro ), &Unit::Ampere ),
+			Qty::new( Num::new( 9.9 ).with_prefix( Prefix::Kilo ), &Unit::Kilogram ),
+			Qty::new( 26.85.into(), &Unit::Celsius ),
+			Qty::new( 20.0.into(), &Unit::Degree ),
+			Qty::new( Num::new( 5.0 ).with_prefix( Prefix::Exa ), &Unit::Degree ),
+			Qty::new( Num::new( 5.0 ).with_prefix( Prefix::Milli ), &Unit::Degree ),
+			Qty::new( 5.0.into(), &Unit::Year ),
+			Qty::new( Num::new( 5.0 ).with_prefix( Prefix::Pico ), &Unit::Ampere ),
+		] {
+			assert_eq!( qty.to_string().parse::<Qty>().unwrap().unit(), qty.unit() );
+			assert_eq!( qty.to_string().parse::<Qty>().unwrap().number().prefix(), qty.number().prefix() );
+			assert_eq!( qty.to_string().parse::<Qty>().unwrap(), qty );
+			assert_eq!( qty.to_string_si_space().parse::<Qty>().unwrap(), qty );
+		}
+	}
+
+	#[test]
+	fn qty_space_before_symbol() {
+		assert_eq!( Qty::new( 20.0.into(), &Unit::Degree ).to_string(), "20°" );
+		assert_eq!( Qty::new( 20.0.into(), &Unit::Degree ).to_string_si_space(), "20°" );
+		assert_eq!( Qty::new( 20.0.into(), &Unit::Degree ).to_string_eng(), "20°" );
+		assert_eq!( format!( "{:#}", Qty::new( Num::new( 20.0 ).with_prefix( Prefix::Milli ), &Unit::Degree ) ), "20×10^-3°" );
+		assert_eq!( Qty::new( 20.0.into(), &Unit::Celsius ).to_string_eng(), "20 °C" );
+		assert_eq!( Qty::new( 20.0.into(), &Unit::Celsius ).to_string(), "20 °C" );
+		assert_eq!( Qty::new( 20.0.into(), &Unit::Celsius ).to_string_si_space(), "20\u{202F}°C" );
+		assert!( "20m".parse::<Qty>().is_err() );
+	}
+
+	#[test]
+	fn qty_dimensionless() {
+		let percent = Qty::new( 50.0.into(), &Unit::Percent );
+		assert_eq!( percent.to_string(), "50 %" );
+		assert_eq!( percent.to_unit( &Unit::One ).unwrap().number(), Num::new( 0.5 ) );
+		assert_eq!( percent.to_unit( &Unit::PerMille ).unwrap().number(), Num::new( 500.0 ) );
+		assert_eq!( percent.as_f64(), 0.5 );
+
+		let permille = Qty::new( 5.0.into(), &Unit::PerMille );
+		assert_eq!( permille.to_string(), "5 ‰" );
+		assert_eq!( permille.to_unit( &Unit::Percent ).unwrap().number(), Num::new( 0.5 ) );
+		assert_eq!( permille.to_unit( &Unit::One ).unwrap().number(), Num::new( 0.005 ) );
+
+		let one = Qty::new( 0.25.into(), &Unit::One );
+		assert_eq!( one.to_string(), "0.25" );
+		assert_eq!( one.to_unit( &Unit::Percent ).unwrap().number(), Num::new( 25.0 ) );
+		assert_eq!( one.to_unit( &Unit::PerMille ).unwrap().number(), Num::new( 250.0 ) );
+		assert!( one.to_unit( &Unit::Meter ).is_err() );
+
+		let ratio = Qty::new( 1.0.into(), &Unit::Meter ) / Qty::new( 4.0.into(), &Unit::Meter );
+		assert_eq!( ratio.unit(), &Unit::One );
+		assert_eq!( ratio.to_unit( &Unit::Percent ).unwrap().number(), Num::new( 25.0 ) );
+		assert_eq!( ratio.to_string(), "0.25" );
+
+		let ratio = Qty::new( 2.0.into(), &Unit::Meter ) / Qty::new( 4.0.into(), &Unit::Meter );
+		assert_eq!( ratio.number().prefix(), Prefix::Nothing );
+		assert_eq!( ratio.to_string(), "0.5" );
+
+		assert_eq!( "50 %".parse::<Qty>().unwrap(), percent );
+	}
+
+	#[test]
+	fn qty_angle() {
+		use std::f64::consts::PI;
+
+		let half = Qty::new( 180.0.into(), &Unit::Degree ).to_unit( &Unit::Radian ).unwrap();
+		assert!( ( half.number().as_f64() - PI ).abs() < 1e-15 );
+
+		let right = Qty::new( 90.0.into(), &Unit::Degree ).to_unit( &Unit::Radian ).unwrap();
+		assert!( ( right.number().as_f64() - PI / 2.0 ).abs() < 1e-15 );
+		assert!( ( Qty::new( 100.0.into(), &Unit::Gradian ).to_unit( &Unit::Degree ).unwrap().number().as_f64() - 90.0 ).abs() < 1e-12 );
+
+		for val in [ 0.0, 1.0, 45.0, 90.0, 359.9, -720.0 ] {
+			let deg = Qty::new( val.into(), &Unit::Degree );
+			let back = deg.to_unit( &Unit::Radian ).unwrap().to_unit( &Unit::Degree ).unwrap();
+			assert!( ( back.number().as_f64() - val ).abs() < 1e-12 );
+			let back = deg.to_unit( &Unit::Gradian ).unwrap().to_unit( &Unit::Degree ).unwrap();
+			assert!( ( back.number().as_f64() - val ).abs() < 1e-12 );
+		}
+
+		assert_eq!( Qty::new( 1.5.into(), &Unit::Radian ).to_string(), "1.5 rad" );
+		assert_eq!( Qty::from_str( "2 mrad" ).unwrap(), Qty::new( Num::new( 2.0 ).with_prefix( Prefix::Milli ), &Unit::Radian ) );
+		assert!( Qty::new( 1.0.into(), &Unit::Radian ).to_unit( &Unit::One ).is_err() );
+	}
+
+	#[test]
+	fn qty_photometry() {
+		let illuminance = Qty::new( 20_000.0.into(), &Unit::Lux ).shortened().unwrap();
+		assert_eq!( illuminance.to_string(), "20 klx" );
+		assert_eq!( illuminance.number().prefix(), Prefix::Kilo );
+		assert_eq!( Qty::new( 800.0.into(), &Unit::Lumen ).to_string(), "800 lm" );
+
+		assert!( Qty::new( 1.0.into(), &Unit::Lux ).to_unit( &Unit::Lumen ).is_err() );
+		assert!( Qty::new( 1.0.into(), &Unit::Lumen ).to_unit( &Unit::Candela ).is_err() );
+		assert_eq!(
+			( Qty::new( 800.0.into(), &Unit::Lumen ) / Qty::new( 4.0.into(), &Unit::compound( vec![ ( Unit::Meter, 2 ) ] ) ) ).to_unit( &Unit::Lux ).unwrap(),
+			Qty::new( 200.0.into(), &Unit::Lux )
+		);
+	}
+
+	#[test]
+	fn qty_builder() {
+		assert_eq!(
+			Qty::builder( 9.9 ).prefix( Prefix::Milli ).unit( Unit::Ampere ).build(),
+			Qty::new( Num::new( 9.9 ).with_prefix( Prefix::Milli ), &Unit::Ampere )
+		);
+		assert_eq!( Qty::builder( 3.0 ).unit( Unit::Meter ).build(), Qty::new( 3.0.into(), &Unit::Meter ) );
+		assert_eq!( Qty::builder( 2.0 ).build(), Qty::new( 2.0.into(), &Unit::One ) );
+		assert_eq!( QtyBuilder::new().build(), Qty::new( 0.0.into(), &Unit::One ) );
+		assert_eq!( QtyBuilder::new().value( 1.5 ).unit( Unit::Second ).build(), Qty::builder( 1.5 ).unit( Unit::Second ).build() );
+
+		// The kilogram is handled like in `Qty::new()`.
+		let mass = Qty::builder( 5.0 ).prefix( Prefix::Milli ).unit( Unit::Kilogram ).build();
+		assert_eq!( mass, Qty::new( Num::new( 5.0 ).with_prefix( Prefix::Milli ), &Unit::Kilogram ) );
+		assert_eq!( mass.unit(), &Unit::Gram );
+		assert_eq!( Qty::builder( 1.0 ).prefix( Prefix::Kilo ).unit( Unit::Gram ).build().unit(), &Unit::Kilogram );
+	}
+
+	#[test]
+	fn qty_floor_ceil_to_prefix() {
+		let cases = [
+			( 1500.0, Prefix::Kilo, Prefix::Mega ),
+			( 1000.0, Prefix::Kilo, Prefix::Kilo ),
+			( -1500.0, Prefix::Kilo, Prefix::Mega ),
+			( 2.5e7, Prefix::Mega, Prefix::Giga ),
+			( 1.0, Prefix::Nothing, Prefix::Nothing ),
+			( 5.0, Prefix::Nothing, Prefix::Deca ),
+			( 150.0, Prefix::Hecto, Prefix::Kilo ),
+			( 0.5, Prefix::Deci, Prefix::Nothing ),
+			( 0.005, Prefix::Milli, Prefix::Centi ),
+			( 0.001, Prefix::Milli, Prefix::Milli ),
+			( 2.5e-4, Prefix::Micro, Prefix::Milli ),
+			( 7e-6, Prefix::Micro, Prefix::Milli ),
+			( 3e-8, Prefix::Nano, Prefix::Micro ),
+		];
+		for ( val, floor, ceil ) in cases {
+			let qty = Qty::new( val.into(), &Unit::Meter );
+			assert_eq!( qty.floor_to_prefix(), floor, "floor of {}", val );
+			assert_eq!( qty.ceil_to_prefix(), ceil, "ceil of {}", val );
+		}
+
+		// The prefix of the quantity does not matter.
+		let qty = Qty::new( Num::new( 1.5 ).with_prefix( Prefix::Milli ), &Unit::Second );
+		assert_eq!( qty.floor_to_prefix(), Prefix::Milli );
+		assert_eq!( qty.ceil_to_prefix(), Prefix::Centi );
+
+		assert_eq!( Qty::new( 1e40.into(), &Unit::Meter ).floor_to_prefix(), Prefix::Quetta );
+		assert_eq!( Qty::new( 1e-40.into(), &Unit::Meter ).ceil_to_prefix(), Prefix::Quecto );
+		assert_eq!( Qty::new( 0.0.into(), &Unit::Meter ).floor_to_prefix(), Prefix::Nothing );
+		assert_eq!( Qty::new( f64::NAN.into(), &Unit::Meter ).ceil_to_prefix(), Prefix::Nothing );
+
+		// 2e6 m² are 2 km².
+		let area = Qty::new( 2e6.into(), &Unit::compound( vec![ ( Unit::Meter, 2 ) ] ) );
+		assert_eq!( area.floor_to_prefix(), Prefix::Kilo );
+		assert_eq!( area.ceil_to_prefix(), Prefix::Mega );
+	}
+
+	#[test]
+	fn qty_to_engineering() {
+		assert_eq!( Qty::new( 0.5.into(), &Unit::Meter ).to_engineering().unwrap(), Qty::new( Num::new( 500.0 ).with_prefix( Prefix::Milli ), &Unit::Meter ) );
+		assert_eq!( Qty::new( 0.5.into(), &Unit::Meter ).to_engineering().unwrap().number().prefix(), Prefix::Milli );
+		assert_eq!( Qty::new( 1500.0.into(), &Unit::Meter ).to_engineering().unwrap().to_string(), "1.5 km" );
+		assert_eq!( Qty::new( ( -0.02 ).into(), &Unit::Ampere ).to_engineering().unwrap().to_string(), "-20 mA" );
+		assert_eq!( Qty::new( 0.0.into(), &Unit::Meter ).to_engineering().unwrap().number().prefix(), Prefix::Nothing );
+		assert_eq!( Qty::new( 0.5.into(), &Unit::Kilogram ).to_engineering().unwrap().to_string(), "500 g" );
+		assert!( Qty::new( 1e40.into(), &Unit::Meter ).to_engineering().is_err() );
+
+		// Deci, centi, deca and hecto are never produced, not even if the quantity already uses one of them.
+		let non_thousand = [ Prefix::Centi, Prefix::Deci, Prefix::Deca, Prefix::Hecto ];
+		for prefix in Prefix::all().filter( |x| x.exp().abs() <= 24 ) {
+			for mantissa in [ 0.07, 0.5, 1.0, 3.3, 45.0, 999.0, -12.0 ] {
+				let qty = Qty::new( Num::new( mantissa ).with_prefix( prefix ), &Unit::Meter );
+				let eng = qty.clone().to_engineering().unwrap();
+				assert!( !non_thousand.contains( &eng.number().prefix() ), "{} became {}", qty, eng );
+				assert_eq!( eng.number().prefix().exp() % 3, 0 );
+				assert!( ( 1.0..1000.0 ).contains( &eng.number().mantissa().abs() ), "{} became {}", qty, eng );
+				assert!( ( eng.as_f64() - qty.as_f64() ).abs() <= 1e-9 * qty.as_f64().abs() );
+			}
+		}
+
+		// 0.5 m² are 500000 mm².
+		let area = Qty::new( 0.5.into(), &Unit::compound( vec![ ( Unit::Meter, 2 ) ] ) ).to_engineering().unwrap();
+		assert_eq!( area.number().prefix(), Prefix::Milli );
+	}
+
+	#[test]
+	fn qty_shortened_with() {
+		let qty = Qty::new( 0.05.into(), &Unit::Meter );
+		assert_eq!( qty.clone().shortened_with( PrefixSet::thousands() ).unwrap(), Qty::new( Num::new( 50.0 ).with_prefix( Prefix::Milli ), &Unit::Meter ) );
+		assert_eq!( qty.clone().shortened_with( PrefixSet::all() ).unwrap().number().prefix(), Prefix::Centi );
+		assert_eq!( qty.clone().shortened_with( PrefixSet::all() ).unwrap(), qty.clone().shortened().unwrap().to_prefix( Prefix::Centi ) );
+		assert!( qty.shortened_with( PrefixSet::empty() ).is_err() );
+
+		for val in [ 0.5, 0.05, 5.0, 50.0, 500.0, 1500.0, -0.02, 3e-7 ] {
+			let qty = Qty::new( val.into(), &Unit::Meter );
+			assert_eq!( qty.clone().shortened_with( PrefixSet::thousands() ).unwrap(), qty.clone().to_engineering().unwrap() );
+		}
+
+		let only_kilo = PrefixSet::empty().with( Prefix::Kilo ).with( Prefix::Mega );
+		assert_eq!( Qty::new( 1500.0.into(), &Unit::Meter ).shortened_with( only_kilo ).unwrap().to_string(), "1.5 km" );
+		assert_eq!( Qty::new( 1.5e9.into(), &Unit::Meter ).shortened_with( only_kilo ).unwrap().to_string(), "1500 Mm" );
+		// Values below all allowed prefixes use the smallest allowed one.
+		assert_eq!( Qty::new( 20.0.into(), &Unit::Meter ).shortened_with( only_kilo ).unwrap().to_string(), "0.02 km" );
+		assert_eq!( Qty::new( 0.0.into(), &Unit::Meter ).shortened_with( only_kilo ).unwrap().number().prefix(), Prefix::Kilo );
+		assert_eq!( Qty::new( Num::new( 0.0 ).with_prefix( Prefix::Kilo ), &Unit::Meter ).shortened_with( PrefixSet::all() ).unwrap().number().prefix(), Prefix::Nothing );
+
+		// 0.002 m⁻¹ are 2 km⁻¹.
+		let inverse = Qty::new( 0.002.into(), &Unit::compound( vec![ ( Unit::Meter, -1 ) ] ) ).shortened_with( PrefixSet::thousands() ).unwrap();
+		assert_eq!( inverse.number().prefix(), Prefix::Kilo );
+	}
+
+	#[test]
+	fn qty_try_from_tuple() {
+		assert_eq!( Qty::try_from( ( 9.9, "km" ) ).unwrap(), Qty::new( Num::new( 9.9 ).with_prefix( Prefix::Kilo ), &Unit::Meter ) );
+		assert_eq!( Qty::try_from( ( 9.9, "km" ) ).unwrap().to_string(), "9.9 km" );
+		assert_eq!( Qty::try_from( ( 2.5, "mA" ) ).unwrap(), Qty::new( Num::new( 2.5 ).with_prefix( Prefix::Milli ), &Unit::Ampere ) );
+		assert_eq!( Qty::try_from( ( 70.0, "kg" ) ).unwrap(), Qty::new( 70.0.into(), &Unit::Kilogram ) );
+		assert_eq!( Qty::try_from( ( 70.0, "kg" ) ).unwrap().unit(), &Unit::Kilogram );
+		assert_eq!( Qty::try_from( ( 5.0, "mg" ) ).unwrap().to_string(), "5 mg" );
+		assert_eq!( Qty::try_from( ( -1.0, " h " ) ).unwrap(), Qty::new( ( -1.0 ).into(), &Unit::Hour ) );
+
+		assert!( matches!( Qty::try_from( ( 1.0, "xyz" ) ), Err( SinumError::Unit( UnitError::ParseFailure( _ ) ) ) ) );
+		assert!( Qty::try_from( ( 1.0, "" ) ).is_err() );
+		assert!( Qty::try_from( ( 1.0, "k m" ) ).is_err() );
+	}
+
+	#[test]
+	fn qty_information() {
+		assert_eq!( Qty::new( 1.0.into(), &Unit::Byte ).to_unit( &Unit::Bit ).unwrap(), Qty::new( 8.0.into(), &Unit::Bit ) );
+		assert_eq!( Qty::from_str( "16 kbit" ).unwrap().to_unit( &Unit::Byte ).unwrap().to_string(), "2 kB" );
+		assert!( Qty::new( 1.0.into(), &Unit::Byte ).to_unit( &Unit::Second ).is_err() );
+
+		// Decimal prefixes
+		assert_eq!( Qty::new( 2048.0.into(), &Unit::Byte ).shortened().unwrap().to_string(), "2.048 kB" );
+		assert_eq!( Qty::new( 1.5e9.into(), &Unit::Byte ).shortened().unwrap().to_string(), "1.5 GB" );
+		assert_eq!( Qty::from_str( "3 MB" ).unwrap(), Qty::new( Num::new( 3.0 ).with_prefix( Prefix::Mega ), &Unit::Byte ) );
+
+		// Binary prefixes
+		assert_eq!( Qty::new( 2048.0.into(), &Unit::Byte ).to_string_binary(), "2 KiB" );
+		assert_eq!( Qty::new( 1023.0.into(), &Unit::Byte ).to_string_binary(), "1023 B" );
+		assert_eq!( Qty::new( 1024.0.into(), &Unit::Byte ).shortened_binary(), ( 1.0, BinaryPrefix::Kibi ) );
+		assert_eq!( Qty::new( ( 5.0 * 1024.0 * 1024.0 ).into(), &Unit::Byte ).to_string_binary(), "5 MiB" );
+		assert_eq!( Qty::new( ( -3.0 * 1024f64.powi( 3 ) ).into(), &Unit::Byte ).to_string_binary(), "-3 GiB" );
+		assert_eq!( Qty::new( Num::new( 2.0 ).with_prefix( Prefix::Kilo ), &Unit::Byte ).shortened_binary(), ( 2000.0 / 1024.0, BinaryPrefix::Kibi ) );
+		assert_eq!( Qty::new( 1e30.into(), &Unit::Byte ).shortened_binary().1, BinaryPrefix::Yobi );
+		assert_eq!( Qty::new( 0.5.into(), &Unit::Byte ).to_string_binary(), "0.5 B" );
+		assert_eq!( Qty::new( 0.0.into(), &Unit::Byte ).shortened_binary(), ( 0.0, BinaryPrefix::Nothing ) );
+		assert_eq!( Qty::new( f64::INFINITY.into(), &Unit::Byte ).shortened_binary().1, BinaryPrefix::Nothing );
+
+		// 4096 B/s are 4 KiB/s.
+		let rate = Qty::new( 4096.0.into(), &Unit::compound( vec![ ( Unit::Byte, 1 ), ( Unit::Second, -1 ) ] ) );
+		assert_eq!( rate.shortened_binary(), ( 4.0, BinaryPrefix::Kibi ) );
+	}
+
+	#[test]
+	fn qty_try_new() {
+		let num = Num::new( 9.9 ).with_prefix( Prefix::Milli );
+		assert_eq!( Qty::try_new( num, &Unit::Ampere ).unwrap(), Qty::new( num, &Unit::Ampere ) );
+		assert_eq!( Qty::try_new( Num::new( 5.0 ).with_prefix( Prefix::Milli ), &Unit::Kilogram ).unwrap().to_string(), "5 g" );
+		assert_eq!( Qty::try_new( Num::new( -3.0 ), &Unit::Gram ).unwrap(), Qty::new( ( -3.0 ).into(), &Unit::Gram ) );
+
+		for value in [ f64::NAN, f64::INFINITY, f64::NEG_INFINITY ] {
+			assert!( matches!( Qty::try_new( Num::new( value ), &Unit::Meter ), Err( SinumError::Qty( QtyError::NotFinite( _ ) ) ) ) );
+			assert!( matches!( Qty::try_new( Num::new( value ).with_prefix( Prefix::Kilo ), &Unit::Meter ), Err( SinumError::Qty( QtyError::NotFinite( _ ) ) ) ) );
+		}
+
+		let widget = Unit::Custom( "widget".to_string() );
+		assert_eq!( Qty::try_new( Num::new( 2.0 ), &widget ).unwrap(), Qty::new( 2.0.into(), &widget ) );
+		assert!( matches!( Qty::try_new( num, &widget ), Err( SinumError::Qty( QtyError::PrefixedCustomUnit( _ ) ) ) ) );
+		let per_second = Unit::compound( vec![ ( widget.clone(), 1 ), ( Unit::Second, -1 ) ] );
+		assert!( matches!( Qty::try_new( num, &per_second ), Err( SinumError::Qty( QtyError::PrefixedCustomUnit( _ ) ) ) ) );
+		assert!( Qty::try_new( num, &Unit::compound( vec![ ( Unit::Meter, 1 ), ( widget, -1 ) ] ) ).is_ok() );
+	}
+
+	#[test]
+	fn qty_energy() {
+		assert_eq!( Qty::new( 1.0.into(), &Unit::Calorie ).to_unit( &Unit::Joule ).unwrap(), Qty::new( 4.184.into(), &Unit::Joule ) );
+		let ev = Qty::new( 1.0.into(), &Unit::Electronvolt ).to_unit( &Unit::Joule ).unwrap();
+		assert!( ( ev.number().as_f64() - 1.602176634e-19 ).abs() < 1e-30 );
+		let back = Qty::new( 4184.0.into(), &Unit::Joule ).to_unit( &Unit::Calorie ).unwrap();
+		assert!( ( back.number().as_f64() - 1000.0 ).abs() < 1e-9 );
+
+		assert_eq!( Qty::new( 1000.0.into(), &Unit::Electronvolt ).shortened().unwrap().to_string(), "1 keV" );
+		let mev = Qty::new( 2.5e6.into(), &Unit::Electronvolt ).shortened().unwrap();
+		assert_eq!( mev.to_string(), "2.5 MeV" );
+		assert_eq!( mev.number().prefix(), Prefix::Mega );
+		assert_eq!( Qty::from_str( "3 kcal" ).unwrap(), Qty::new( Num::new( 3.0 ).with_prefix( Prefix::Kilo ), &Unit::Calorie ) );
+		assert!( Qty::new( 1.0.into(), &Unit::Calorie ).to_unit( &Unit::Watt ).is_err() );
+	}
+
+	#[test]
+	fn qty_radiation() {
+		let activity = Qty::new( 1000.0.into(), &Unit::Becquerel ).shortened().unwrap();
+		assert_eq!( activity.to_string(), "1 kBq" );
+		assert_eq!( activity.number().prefix(), Prefix::Kilo );
+
+		let dose = Qty::new( 0.005.into(), &Unit::Gray ).shortened().unwrap();
+		assert_eq!( dose.to_string(), "5 mGy" );
+
+		assert!( Qty::new( 1.0.into(), &Unit::Gray ).to_unit( &Unit::Sievert ).is_err() );
+		assert!( Qty::new( 1.0.into(), &Unit::Becquerel ).to_unit( &Unit::Hertz ).is_err() );
+		assert_eq!( "2.5 MBq".parse::<Qty>().unwrap(), Qty::new( Num::new( 2.5 ).with_prefix( Prefix::Mega ), &Unit::Becquerel ) );
+	}
+
+	#[test]
+	fn qty_pressure() {
+		let atm = Qty::new( 1.0.into(), &Unit::Atmosphere );
+		assert_eq!( atm.to_unit( &Unit::Bar ).unwrap().number(), Num::new( 1.01325 ) );
+		assert_eq!( atm.to_unit( &Unit::Pascal ).unwrap().number(), Num::new( 101_325.0 ) );
+		assert!( ( atm.to_unit( &Unit::Torr ).unwrap().number().as_f64() - 760.0 ).abs() < 1e-9 );
+		assert!( ( atm.to_unit( &Unit::MillimeterMercury ).unwrap().number().as_f64() - 760.0 ).abs() < 1e-3 );
+		assert!( ( atm.to_unit( &Unit::Psi ).unwrap().number().as_f64() - 14.695_948_8 ).abs() < 1e-6 );
+
+		for unit in Unit::for_quantity( &PhysicalQuantity::Pressure ) {
+			for target in Unit::for_quantity( &PhysicalQuantity::Pressure ) {
+				let qty = Qty::new( 9.9.into(), &unit );
+				let res = qty.to_unit( &target ).unwrap().to_unit( &unit ).unwrap();
+				assert!( ( res.number().as_f64() - 9.9 ).abs() < 1e-12, "{} → {}", qty, target );
+			}
+		}
+
+		assert_eq!( "2.5 psi".parse::<Qty>().unwrap(), Qty::new( 2.5.into(), &Unit::Psi ) );
+		assert_eq!( Qty::new( 1.0.into(), &Unit::MillimeterMercury ).to_string(), "1 mmHg" );
+	}
+
+	#[test]
+	fn qty_imperial_length() {
+		let mile = Qty::new( 1.0.into(), &Unit::Mile );
+		assert_eq!( mile.to_unit( &Unit::Meter ).unwrap().number(), Num::new( 1609.344 ) );
+
+		let feet = mile.to_unit( &Unit::Foot ).unwrap();
+		assert!( ( feet.number().as_f64() - 5280.0 ).abs() < 1e-9 );
+		assert!( ( feet.to_unit( &Unit::Mile ).unwrap().number().as_f64() - 1.0 ).abs() < 1e-12 );
+
+		let yard = Qty::new( 1.0.into(), &Unit::Yard ).to_unit( &Unit::Inch ).unwrap();
+		assert!( ( yard.number().as_f64() - 36.0 ).abs() < 1e-9 );
+	}
+
+	#[test]
+	fn qty_weighted_mean() {
+		let mean = Qty::weighted_mean( &[
+			( Qty::new( 2.0.into(), &Unit::Kilogram ), 1.0 ),
+			( Qty::new( 500.0.into(), &Unit::Gram ), 2.0 ),
+			( Qty::new( Num::new( 4.0 ).with_prefix( Prefix::Milli ), &Unit::Tonne ), 1.0 ),
+		] ).unwrap();
+		assert!( ( mean.as_f64() - 1.75 ).abs() < 1e-12 );
+		assert_eq!( mean.unit(), &Unit::Kilogram );
+
+		assert!( matches!( Qty::weighted_mean( &[ ( Qty::new( 2.0.into(), &Unit::Kilogram ), 0.0 ) ] ), Err( SinumError::Qty( QtyError::ZeroWeight ) ) ) );
+		assert!( matches!(
+			Qty::weighted_mean( &[ ( Qty::new( 2.0.into(), &Unit::Kilogram ), 1.0 ), ( Qty::new( 2.0.into(), &Unit::Meter ), 1.0 ) ] ),
+			Err( SinumError::Unit( UnitError::UnitMismatch( _ ) ) )
+		) );
+	}
+
+	#[test]
+	fn qty_imperial_mass() {
+		let stone = Qty::new( 1.0.into(), &Unit::Stone );
+		assert!( ( stone.to_unit( &Unit::Pound ).unwrap().number().as_f64() - 14.0 ).abs() < 1e-12 );
+		assert!( ( Qty::new( 1.0.into(), &Unit::Pound ).to_unit( &Unit::Ounce ).unwrap().number().as_f64() - 16.0 ).abs() < 1e-12 );
+
+		let tonne = Qty::new( 1.0.into(), &Unit::Tonne ).to_unit( &Unit::Pound ).unwrap();
+		assert!( ( tonne.number().as_f64() - 1e3 / 0.45359237 ).abs() < 1e-9 );
+		assert!( ( tonne.to_unit( &Unit::Tonne ).unwrap().number().as_f64() - 1.0 ).abs() < 1e-12 );
+
+		let pound = Qty::new( Num::new( 2.0 ).with_prefix( Prefix::Kilo ), &Unit::Pound ).to_unit( &Unit::Tonne ).unwrap();
+		assert!( ( pound.as_f64() - 907.18474 ).abs() < 1e-9 );
+	}
+
+	#[test]
+	fn qty_time() {
+		assert_eq!( Qty::new( 90.0.into(), &Unit::Minute ).to_unit( &Unit::Hour ).unwrap(), Qty::new( 1.5.into(), &Unit::Hour ) );
+		assert_eq!( Qty::new( 1.0.into(), &Unit::Day ).to_unit( &Unit::Minute ).unwrap(), Qty::new( 1440.0.into(), &Unit::Minute ) );
+		assert_eq!( Qty::new( 1.0.into(), &Unit::Year ).to_unit( &Unit::Day ).unwrap(), Qty::new( 365.25.into(), &Unit::Day ) );
+		assert_eq!( "2 h".parse::<Qty>().unwrap(), Qty::new( 7200.0.into(), &Unit::Second ) );
+		assert_eq!( Qty::new( 2.0.into(), &Unit::Hour ).to_prefix( Prefix::Kilo ).to_string(), "0.002 kh" );
+	}
+
+	#[cfg( feature = "serde" )]
+	#[test]
+	fn qty_serde_human_readable() {
+		let qty = Qty::new( Num::new( 9.9 ).with_prefix( Prefix::Kilo ), &Unit::Meter );
+		let text = serde_json::to_string( &qty ).unwrap();
+		assert_eq!( text, r#""9.9 km""# );
+		let res: Qty = serde_json::from_str( &text ).unwrap();
+		assert_eq!( res, qty );
+		assert_eq!( res.number().prefix(), Prefix::Kilo );
+
+		for qty in [
+			Qty::new( Num::new( 1.234_567_890_123 ).with_prefix( Prefix::Micro ), &Unit::Ampere ),
+			Qty::new( 2.0.into(), &Unit::Kilogram ),
+			Qty::new( Num::new( -20.5 ), &Unit::Celsius ),
+			Qty::new( 20.0.into(), &Unit::Degree ),
+		] {
+			let res: Qty = serde_json::from_str( &serde_json::to_string( &qty ).unwrap() ).unwrap();
+			assert_eq!( res, qty );
+			assert_eq!( res.unit(), qty.unit() );
+			assert_eq!( res.number().prefix(), qty.number().prefix() );
+		}
+
+		for unit in Unit::all() {
+			for prefix in [ Prefix::Nothing, Prefix::Pico, Prefix::Milli, Prefix::Kilo, Prefix::Mega, Prefix::Exa ] {
+				let qty = Qty::new( Num::new( 5.0 ).with_prefix( prefix ), &unit );
+				let res: Qty = serde_json::from_str( &serde_json::to_string( &qty ).unwrap() ).unwrap();
+				assert_eq!( res, qty, "{}", qty );
+				assert_eq!( res.unit(), qty.unit(), "{}", qty );
+				assert_eq!( res.number().prefix(), qty.number().prefix(), "{}", qty );
+			}
+		}
+
+		// Units without a parsable symbol fall back to the struct form.
+		let qty = Qty::new( Num::new( 3.0 ).with_prefix( Prefix::Milli ), &Unit::compound( vec![ ( Unit::Meter, 1 ), ( Unit::Second, -1 ) ] ) );
+		let text = serde_json::to_string( &qty ).unwrap();
+		assert!( text.starts_with( r#"{"number":"# ) );
+		let res: Qty = serde_json::from_str( &text ).unwrap();
+		assert_eq!( res, qty );
+		assert_eq!( res.unit(), qty.unit() );
+
+		// Both forms are accepted.
+		let res: Qty = serde_json::from_str( r#"{"number":{"mantissa":9.9,"prefix":"Kilo"},"unit":"Meter"}"# ).unwrap();
+		assert_eq!( res, Qty::new( Num::new( 9.9 ).with_prefix( Prefix::Kilo ), &Unit::Meter ) );
+		assert!( serde_json::from_str::<Qty>( r#""9.9 xyz""# ).is_err() );
+	}
+
+	#[cfg( feature = "serde" )]
+	#[test]
+	fn qty_serde_binary() {
+		for qty in [
+			Qty::new( Num::new( 9.9 ).with_prefix( Prefix::Kilo ), &Unit::Meter ),
+			Qty::new( Num::new( -20.5 ), &Unit::Celsius ),
+			Qty::new( Num::new( 3.0 ).with_prefix( Prefix::Milli ), &Unit::compound( vec![ ( Unit::Meter, 1 ), ( Unit::Second, -1 ) ] ) ),
+		] {
+			let bytes = bincode::serialize( &qty ).unwrap();
+			assert_eq!( bytes, bincode::serialize( &( qty.number(), qty.unit() ) ).unwrap() );
+
+			let res: Qty = bincode::deserialize( &bytes ).unwrap();
+			assert_eq!( res, qty );
+			assert_eq!( res.unit(), qty.unit() );
+			assert_eq!( res.number().prefix(), qty.number().prefix() );
+		}
+	}
+
+	#[cfg( feature = "serde" )]
+	#[test]
+	fn qty_record_csv() {
+		let quantities = [
+			Qty::new( Num::new( 9.9 ).with_prefix( Prefix::Kilo ), &Unit::Meter ),
+			Qty::new( Num::new( 2.5 ).with_prefix( Prefix::Milli ), &Unit::Ampere ),
+			Qty::new( 20.0.into(), &Unit::Celsius ),
+		];
+
+		let mut writer = csv::Writer::from_writer( vec![] );
+		for qty in &quantities {
+			writer.serialize( QtyRecord::from( qty ) ).unwrap();
+		}
+		let text = String::from_utf8( writer.into_inner().unwrap() ).unwrap();
+
+		assert_eq!(
+			text,
+			"value,unit,physical_quantity\n9900.0,m,length\n0.0025,A,current\n20.0,°C,temperature\n"
+		);
+	}
+
+	#[test]
+	fn qty_round_to_significant_figures() {
+		assert_eq!( Qty::new( 1234.5.into(), &Unit::Meter ).round_to_significant_figures( 2 ).to_string(), "1200 m" );
+		assert_eq!( Qty::new( 1234.5.into(), &Unit::Meter ).round_to_significant_figures( 5 ).to_string(), "1234.5 m" );
+		assert_eq!( Qty::new( 0.00123456.into(), &Unit::Second ).round_to_significant_figures( 3 ).to_string(), "0.00123 s" );
+		assert_eq!( Qty::new( 987_654_321.0.into(), &Unit::Volt ).round_to_significant_figures( 1 ).to_string(), "1000000000 V" );
+		assert_eq!( Qty::new( Num::new( -45.678 ), &Unit::Celsius ).round_to_significant_figures( 2 ).to_string(), "-46 °C" );
+		assert_eq!( Qty::new( 0.0.into(), &Unit::Meter ).round_to_significant_figures( 2 ).to_string(), "0 m" );
+		assert_eq!( Qty::new( 7.5.into(), &Unit::Meter ).round_to_significant_figures( 0 ).to_string(), "8 m" );
+
+		let prefixed = Qty::new( Num::new( 123.456 ).with_prefix( Prefix::Micro ), &Unit::Ampere ).round_to_significant_figures( 2 );
+		assert_eq!( prefixed.number().prefix(), Prefix::Micro );
+		assert_eq!( prefixed.to_string(), "120 µA" );
+	}
+
+	#[test]
+	fn qty_clamp() {
+		let min = Qty::new( 1.0.into(), &Unit::Tonne );
+		let max = Qty::new( 2.0.into(), &Unit::Tonne );
+
+		let res = Qty::new( 1500.0.into(), &Unit::Kilogram ).clamp( min.clone(), max.clone() ).unwrap();
+		assert_eq!( res, Qty::new( 1500.0.into(), &Unit::Kilogram ) );
+		assert_eq!( res.unit(), &Unit::Kilogram );
+
+		let res = Qty::new( 500.0.into(), &Unit::Kilogram ).clamp( min.clone(), max.clone() ).unwrap();
+		assert_eq!( res, min );
+		assert_eq!( res.unit(), &Unit::Kilogram );
+		assert_eq!( res.to_string(), "1000 kg" );
+
+		let res = Qty::new( Num::new( 3.0 ).with_prefix( Prefix::Mega ), &Unit::Gram ).clamp( min.clone(), max.clone() ).unwrap();
+		assert_eq!( res, max );
+		assert_eq!( res.unit(), &Unit::Gram );
+		assert_eq!( res.number().prefix(), Prefix::Mega );
+
+		let res = Qty::new( 5.0.into(), &Unit::Celsius ).clamp( Qty::new( 283.15.into(), &Unit::Kelvin ), Qty::new( 20.0.into(), &Unit::Celsius ) ).unwrap();
+		assert!( ( res.number().mantissa() - 10.0 ).abs() < 1e-12 );
+		assert_eq!( res.unit(), &Unit::Celsius );
+
+		assert!( matches!(
+			Qty::new( 1.0.into(), &Unit::Kilogram ).clamp( min.clone(), Qty::new( 1.0.into(), &Unit::Second ) ),
+			Err( UnitError::UnitMismatch( _ ) )
+		) );
+	}
+
+	#[test]
+	#[should_panic]
+	fn qty_clamp_invalid() {
+		let _ = Qty::new( 1.0.into(), &Unit::Kilogram ).clamp( Qty::new( 2.0.into(), &Unit::Tonne ), Qty::new( 1.0.into(), &Unit::Tonne ) );
+	}
+
+	#[test]
+	fn qty_min_max() {
+		let tonne = Qty::new( 1.0.into(), &Unit::Tonne );
+		let kilogram = Qty::new( 900.0.into(), &Unit::Kilogram );
+		let milligram = Qty::new( Num::new( 5.0 ).with_prefix( Prefix::Milli ), &Unit::Gram );
+
+		let max = tonne.clone().max( kilogram.clone() ).unwrap();
+		assert_eq!( max.unit(), &Unit::Tonne );
+		assert_eq!( max.to_string(), "1 t" );
+
+		let min = kilogram.clone().min( milligram ).unwrap();
+		assert_eq!( min.to_string(), "5 mg" );
+
+		let pound = Qty::new( 1900.0.into(), &Unit::Pound );
+		assert_eq!( pound.clone().max( kilogram.clone() ).unwrap().unit(), &Unit::Kilogram );
+		assert_eq!( pound.min( kilogram ).unwrap().unit(), &Unit::Pound );
+
+		assert!( matches!( tonne.clone().max( Qty::new( 1.0.into(), &Unit::Second ) ), Err( UnitError::UnitMismatch( _ ) ) ) );
+		assert!( matches!( tonne.min( Qty::new( 1.0.into(), &Unit::Second ) ), Err( UnitError::UnitMismatch( _ ) ) ) );
+	}
+
+	#[test]
+	fn qty_prefix_range() {
+		let huge = Qty::new( 1e40.into(), &Unit::Meter );
+		assert!( huge.clone().shortened().is_err() );
+		assert!( !huge.fits_prefix( Prefix::Quetta ) );
+		assert_eq!( huge.clone().shortened_clamped(), huge );
+		assert_eq!( huge.shortened_clamped().number().prefix(), Prefix::Quetta );
+
+		let tiny = Qty::new( Num::new( 1e-40 ), &Unit::Meter );
+		assert!( tiny.clone().shortened().is_err() );
+		assert!( !tiny.fits_prefix( Prefix::Quecto ) );
+		assert_eq!( tiny.shortened_clamped().number().prefix(), Prefix::Quecto );
+
+		let largest = Qty::new( 999e30.into(), &Unit::Meter );
+		assert!( largest.fits_prefix( Prefix::Quetta ) );
+		assert_eq!( largest.shortened_clamped().number().prefix(), Prefix::Quetta );
+
+		let smallest = Qty::new( 1e-30.into(), &Unit::Meter );
+		assert!( smallest.fits_prefix( Prefix::Quecto ) );
+		assert_eq!( smallest.shortened_clamped().number().prefix(), Prefix::Quecto );
+
+		assert!( Qty::new( 0.0.into(), &Unit::Meter ).fits_prefix( Prefix::Nothing ) );
+		assert!( !Qty::new( 0.0.into(), &Unit::Meter ).fits_prefix( Prefix::Kilo ) );
+
+		let area = Qty::new( 5e6.into(), &Unit::compound( vec![ ( Unit::Meter, 2 ) ] ) );
+		assert!( area.fits_prefix( Prefix::Kilo ) );
+		assert!( !area.fits_prefix( Prefix::Mega ) );
+	}
+
+	#[test]
+	fn qty_saturating_to_prefix() {
+		let huge = Qty::new( 1e300.into(), &Unit::Ampere );
+		let res = huge.clone().saturating_to_prefix( Prefix::Quecto );
+		assert_eq!( res.number().prefix(), Prefix::Micro );
+		assert!( res.number().mantissa().is_normal() );
+		assert_eq!( huge.clone().saturating_to_prefix( Prefix::Quetta ).number().prefix(), Prefix::Quetta );
+
+		let tiny = Qty::new( 1e-300.into(), &Unit::Ampere );
+		let res = tiny.clone().saturating_to_prefix( Prefix::Quetta );
+		assert_eq!( res.number().prefix(), Prefix::Mega );
+		assert!( res.number().mantissa().is_normal() );
+		assert_eq!( tiny.clone().saturating_to_prefix( Prefix::Quecto ).number().prefix(), Prefix::Quecto );
+
+		// The value is not changed by the conversion.
+		assert!( ( huge.clone().saturating_to_prefix( Prefix::Quecto ).as_f64() / 1e300 - 1.0 ).abs() < 1e-12 );
+		assert!( ( tiny.saturating_to_prefix( Prefix::Quetta ).as_f64() / 1e-300 - 1.0 ).abs() < 1e-12 );
+
+		let thousand = Qty::new( 1000.0.into(), &Unit::Ampere );
+		assert_eq!( thousand.clone().saturating_to_prefix( Prefix::Kilo ), Qty::new( Num::new( 1.0 ).with_prefix( Prefix::Kilo ), &Unit::Ampere ) );
+		assert_eq!( thousand.clone().shortened_clamped(), Qty::new( Num::new( 1.0 ).with_prefix( Prefix::Kilo ), &Unit::Ampere ) );
+		assert_eq!( thousand.clone().saturating_to_prefix( Prefix::Nothing ), thousand );
+
+		assert_eq!( Qty::new( 0.0.into(), &Unit::Ampere ).saturating_to_prefix( Prefix::Kilo ).number().prefix(), Prefix::Kilo );
+		assert!( Qty::new( f64::INFINITY.into(), &Unit::Ampere ).saturating_to_prefix( Prefix::Kilo ).number().mantissa().is_infinite() );
+	}
+
+	#[test]
+	fn qty_normalized() {
+		for ( qty, res ) in [
+			( Qty::new( 1500.0.into(), &Unit::Gram ), "1.5 kg" ),
+			( Qty::new( 0.002.into(), &Unit::Kilogram ), "2 g" ),
+			( Qty::new( 0.0005.into(), &Unit::Tonne ), "500 g" ),
+			( Qty::new( 2500.0.into(), &Unit::Kilogram ), "2.5 t" ),
+			( Qty::new( 2.5e6.into(), &Unit::Kilogram ), "2.5 kt" ),
+			( Qty::new( Num::new( -1500.0 ), &Unit::Meter ), "-1.5 km" ),
+			( Qty::new( Num::new( -0.0 ), &Unit::Meter ), "0 m" ),
+			( Qty::new( Num::new( 0.25 ).with_prefix( Prefix::Kilo ), &Unit::Ampere ), "250 A" ),
+			( Qty::new( 3.0.into(), &Unit::Pound ), "3 lb" ),
+		] {
+			let normalized = qty.normalized().unwrap();
+			assert_eq!( normalized.to_string(), res );
+			assert_eq!( normalized.clone().normalized().unwrap().to_string(), res );
+			assert_eq!( normalized.clone().normalized().unwrap().number().prefix(), normalized.number().prefix() );
+		}
+	}
+
+	#[test]
+	fn qty_sum() {
+		let currents = vec![
+			Qty::new( Num::new( 2.0 ).with_prefix( Prefix::Milli ), &Unit::Ampere ),
+			Qty::new( 1.0.into(), &Unit::Ampere ),
+			Qty::new( Num::new( 500.0 ).with_prefix( Prefix::Micro ), &Unit::Ampere ),
+		];
+
+		let sum: Qty = currents.iter().sum();
+		assert!( ( sum.as_f64() - 1.0025 ).abs() < 1e-12 );
+		assert_eq!( sum.number().prefix(), Prefix::Milli );
+		assert_eq!( sum.unit(), &Unit::Ampere );
+		assert_eq!( currents.into_iter().sum::<Qty>(), sum );
+
+		assert_eq!( Qty::try_sum( [] ).unwrap(), Qty::new( 0.0.into(), &Unit::compound( vec![] ) ) );
+		assert!( matches!(
+			Qty::try_sum( [ Qty::new( 1.0.into(), &Unit::Ampere ), Qty::new( 1.0.into(), &Unit::Volt ) ] ),
+			Err( UnitError::UnitMismatch( _ ) )
+		) );
+	}
+
+	#[test]
+	#[should_panic]
+	fn qty_sum_mismatch_panics() {
+		let _ = [ Qty::new( 1.0.into(), &Unit::Ampere ), Qty::new( 1.0.into(), &Unit::Volt ) ].iter().sum::<Qty>();
+	}
+
+	#[test]
+	fn qty_finite() {
+		let inf = Qty::new( 1.0.into(), &Unit::Ampere ) / 0.0;
+		assert!( inf.is_infinite() && !inf.is_finite() && !inf.is_nan() );
+
+		let nan = Qty::new( 0.0.into(), &Unit::Ampere ) / 0.0;
+		assert!( nan.is_nan() && !nan.is_finite() && !nan.is_infinite() );
+
+		let qty = Qty::new( Num::new( -2.0 ).with_prefix( Prefix::Milli ), &Unit::Kelvin );
+		assert!( qty.is_finite() && !qty.is_nan() && !qty.is_infinite() );
+	}
+
+	#[test]
+	fn qty_volume() {
+		let milliliter = Qty::new( Num::new( 1000.0 ).with_prefix( Prefix::Milli ), &Unit::Liter );
+		assert_eq!( milliliter.to_string(), "1000 mL" );
+		assert_eq!( milliliter.clone().to_prefix( Prefix::Nothing ).to_string(), "1 L" );
+		assert_eq!( milliliter.clone().shortened().unwrap(), Qty::new( 1.0.into(), &Unit::Liter ) );
+		assert_eq!( milliliter.clone().shortened().unwrap().to_string(), "1 L" );
+
+		let cubic = milliliter.to_unit( &Unit::compound( vec![ ( Unit::Meter, 3 ) ] ) ).unwrap();
+		assert!( ( cubic.as_f64() - 1e-3 ).abs() < 1e-15 );
+		assert_eq!( cubic.clone().to_prefix( Prefix::Centi ).to_string_rounded( Some( CLEAN_DISPLAY_DECIMALS ) ), "1000 cm³" );
+		assert!( ( cubic.to_unit( &Unit::Liter ).unwrap().number().as_f64() - 1.0 ).abs() < 1e-12 );
+		assert_eq!( "250 mL".parse::<Qty>().unwrap(), Qty::new( 0.25.into(), &Unit::Liter ) );
+	}
+
+	#[test]
+	fn qty_group_by_quantity() {
+		let values = [
+			Qty::new( 1.0.into(), &Unit::Meter ),
+			Qty::new( 2.0.into(), &Unit::Second ),
+			Qty::new( Num::new( 3.0 ).with_prefix( Prefix::Kilo ), &Unit::Meter ),
+			Qty::new( 4.0.into(), &Unit::Tonne ),
+			Qty::new( 5.0.into(), &Unit::Hour ),
+			Qty::new( 6.0.into(), &Unit::Mile ),
+			Qty::new( 7.0.into(), &Unit::compound( vec![ ( Unit::Joule, 1 ), ( Unit::Second, -1 ) ] ) ),
+			Qty::new( 8.0.into(), &Unit::Watt ),
+		];
+		let groups = group_by_quantity( &values );
+
+		assert_eq!( groups.len(), 4 );
+		assert_eq!( groups[&PhysicalQuantity::Length], vec![ values[0].clone(), values[2].clone(), values[5].clone() ] );
+		assert_eq!( groups[&PhysicalQuantity::Time], vec![ values[1].clone(), values[4].clone() ] );
+		assert_eq!( groups[&PhysicalQuantity::Mass], vec![ values[3].clone() ] );
+		assert_eq!( groups[&PhysicalQuantity::Power], vec![ values[6].clone(), values[7].clone() ] );
+		assert_eq!( groups[&PhysicalQuantity::Power][0].unit(), values[6].unit() );
+		assert!( !groups.contains_key( &PhysicalQuantity::Current ) );
+
+		assert!( group_by_quantity( &[] ).is_empty() );
+	}
+
+	#[test]
+	fn qty_merge_equal_units() {
+		let values = [
+			Qty::new( 2.0.into(), &Unit::Kilogram ),
+			Qty::new( Num::new( 500.0 ).with_prefix( Prefix::Milli ), &Unit::Gram ),
+			Qty::new( 1.5.into(), &Unit::Kilogram ),
+			Qty::new( 1.0.into(), &Unit::Liter ),
+			Qty::new( 2.0.into(), &Unit::Gram ),
+			Qty::new( 1.0.into(), &Unit::Pound ),
+		];
+		let merged = merge_equal_units( &values );
+
+		assert_eq!( merged, vec![
+			Qty::new( 3.5.into(), &Unit::Kilogram ),
+			Qty::new( Num::new( 2500.0 ).with_prefix( Prefix::Milli ), &Unit::Gram ),
+			Qty::new( 1.0.into(), &Unit::Liter ),
+			Qty::new( 1.0.into(), &Unit::Pound ),
+		] );
+		assert_eq!( merged[0].unit(), &Unit::Kilogram );
+		assert_eq!( merged[1].number().prefix(), Prefix::Milli );
+		assert_eq!( merged[1].unit(), &Unit::Gram );
+		assert_eq!( merged[3].unit(), &Unit::Pound );
+
+		assert!( merge_equal_units( &[] ).is_empty() );
+	}
+
+	#[test]
+	fn qty_sort_by_base_value() {
+		let mut values = [
+			Qty::new( 3.0.into(), &Unit::Foot ),
+			Qty::new( 1.0.into(), &Unit::Hour ),
+			Qty::new( Num::new( 1.0 ).with_prefix( Prefix::Kilo ), &Unit::Meter ),
+			Qty::new( 90.0.into(), &Unit::Minute ),
+			Qty::new( 1000.0.into(), &Unit::Meter ),
+			Qty::new( 1.0.into(), &Unit::Meter ),
+		];
+		sort_by_base_value( &mut values );
+
+		assert_eq!( values.iter().map( |x| x.to_string() ).collect::<Vec<String>>(), vec![ "3 ft", "1 m", "1 km", "1000 m", "1 h", "90 min" ] );
+		assert!( values[..4].iter().all( |x| x.phys() == PhysicalQuantity::Length ) );
+		assert!( values[4..].iter().all( |x| x.phys() == PhysicalQuantity::Time ) );
+	}
+
+	#[test]
+	fn qty_relative_to() {
+		let reference = Qty::new( 6.0.into(), &Unit::Ampere );
+		assert_eq!( Qty::new( 3.0.into(), &Unit::Ampere ).relative_to( &reference ).unwrap(), 0.5 );
+		assert_eq!( Qty::new( 12.0.into(), &Unit::Ampere ).relative_to( &reference ).unwrap(), 2.0 );
+		assert_eq!( Qty::new( Num::new( 3.0 ).with_prefix( Prefix::Milli ), &Unit::Ampere ).relative_to( &reference ).unwrap(), 0.0005 );
+		assert_eq!( Qty::new( 500.0.into(), &Unit::Kilogram ).relative_to( &Qty::new( 2.0.into(), &Unit::Tonne ) ).unwrap(), 0.25 );
+		assert_eq!( Qty::new( 0.0.into(), &Unit::Celsius ).relative_to( &Qty::new( 273.15.into(), &Unit::Kelvin ) ).unwrap(), 1.0 );
+		assert!( Qty::new( 3.0.into(), &Unit::Ampere ).relative_to( &Qty::new( 0.0.into(), &Unit::Ampere ) ).unwrap().is_infinite() );
+
+		assert!( matches!(
+			Qty::new( 3.0.into(), &Unit::Ampere ).relative_to( &Qty::new( 6.0.into(), &Unit::Second ) ),
+			Err( UnitError::UnitMismatch( _ ) )
+		) );
+	}
+
+	#[test]
+	fn qty_within() {
+		let target = Qty::new( 10.0.into(), &Unit::Kilogram );
+		let tolerance = Qty::new( 0.5.into(), &Unit::Kilogram );
+
+		assert!( Qty::new( 10.3.into(), &Unit::Kilogram ).within( &target, &tolerance ).unwrap() );
+		assert!( Qty::new( 9.7.into(), &Unit::Kilogram ).within( &target, &tolerance ).unwrap() );
+		assert!( !Qty::new( 11.0.into(), &Unit::Kilogram ).within( &target, &tolerance ).unwrap() );
+		assert!( Qty::new( 10_200.0.into(), &Unit::Gram ).within( &target, &Qty::new( Num::new( 0.5 ).with_prefix( Prefix::Milli ), &Unit::Tonne ) ).unwrap() );
+
+		let celsius = Qty::new( 20.3.into(), &Unit::Celsius );
+		assert!( celsius.within( &Qty::new( 293.15.into(), &Unit::Kelvin ), &Qty::new( 0.5.into(), &Unit::Celsius ) ).unwrap() );
+		assert!( !celsius.within( &Qty::new( 293.15.into(), &Unit::Kelvin ), &Qty::new( 0.2.into(), &Unit::Celsius ) ).unwrap() );
+
+		assert!( matches!( target.within( &Qty::new( 10.0.into(), &Unit::Meter ), &tolerance ), Err( UnitError::UnitMismatch( _ ) ) ) );
+		assert!( matches!( target.within( &target, &Qty::new( 1.0.into(), &Unit::Meter ) ), Err( UnitError::UnitMismatch( _ ) ) ) );
+	}
+
+	#[test]
+	fn qty_frequency() {
+		assert_eq!( Qty::new( 2500.0.into(), &Unit::Hertz ).shortened().unwrap().to_string(), "2.5 kHz" );
+		assert_eq!( Qty::new( 2.0e6.into(), &Unit::Hertz ).shortened().unwrap().number().prefix(), Prefix::Mega );
+		assert_eq!( "2 MHz".parse::<Qty>().unwrap(), Qty::new( 2e6.into(), &Unit::Hertz ) );
+
+		let per_second = Qty::new( 1.0.into(), &Unit::Meter ) / Qty::new( 2.0.into(), &Unit::Meter ) / Qty::new( 1.0.into(), &Unit::Second );
+		assert_eq!( per_second.to_unit( &Unit::Hertz ).unwrap(), Qty::new( 0.5.into(), &Unit::Hertz ) );
+	}
+
+	#[test]
+	fn qty_base_components() {
+		use PhysicalQuantity as P;
+
+		let newton = Unit::compound( vec![ ( Unit::Kilogram, 1 ), ( Unit::Meter, 1 ), ( Unit::Second, -2 ) ] );
+		assert_eq!( Qty::new( 1.0.into(), &newton ).base_components(), vec![ ( P::Mass, 1 ), ( P::Length, 1 ), ( P::Time, -2 ) ] );
+		assert_eq!( Qty::new( 1.0.into(), &Unit::Watt ).base_components(), vec![ ( P::Mass, 1 ), ( P::Length, 2 ), ( P::Time, -3 ) ] );
+		assert_eq!( Qty::new( 1.0.into(), &Unit::Pascal ).base_components(), vec![ ( P::Mass, 1 ), ( P::Length, -1 ), ( P::Time, -2 ) ] );
+		assert_eq!( Qty::new( 1.0.into(), &Unit::Coulomb ).base_components(), vec![ ( P::Current, 1 ), ( P::Time, 1 ) ] );
+		assert_eq!( Qty::new( 1.0.into(), &Unit::Liter ).base_components(), vec![ ( P::Length, 3 ) ] );
+		assert_eq!( Qty::new( 1.0.into(), &Unit::Hertz ).base_components(), vec![ ( P::Time, -1 ) ] );
+		assert_eq!( Qty::new( 1.0.into(), &Unit::Celsius ).base_components(), vec![ ( P::Temperature, 1 ) ] );
+
+		let dimensionless = Qty::new( 1.0.into(), &Unit::Meter ) / Qty::new( 1.0.into(), &Unit::Mile );
+		assert_eq!( dimensionless.base_components(), vec![] );
+
+		// The product of compatible derived units can be converted into them.
+		let energy = Qty::new( 2.0.into(), &Unit::Watt ) * Qty::new( 3.0.into(), &Unit::Second );
+		assert_eq!( energy.to_unit( &Unit::Joule ).unwrap(), Qty::new( 6.0.into(), &Unit::Joule ) );
+	}
+
+	#[test]
+	fn qty_coarsest_exact_unit() {
+		let res = Qty::new( 5000.0.into(), &Unit::Kilogram ).coarsest_exact_unit();
+		assert_eq!( res.unit(), &Unit::Tonne );
+		assert_eq!( res.number().mantissa(), 5.0 );
+
+		let res = Qty::new( 5001.0.into(), &Unit::Kilogram ).coarsest_exact_unit();
+		assert_eq!( res.unit(), &Unit::Kilogram );
+		assert_eq!( res.number().mantissa(), 5001.0 );
 
-		Self::new( val.into(), &self.unit.base() )
-			.to_unit( &self.unit ).unwrap()
-			.to_prefix( self.number.prefix() )
+		let res = Qty::new( 5001.0.into(), &Unit::Gram ).coarsest_exact_unit();
+		assert_eq!( res.unit(), &Unit::Gram );
+		assert_eq!( res.to_string(), "5001 g" );
+
+		let res = Qty::new( 5000.0.into(), &Unit::Gram ).coarsest_exact_unit();
+		assert_eq!( res.to_string(), "5 kg" );
+
+		let res = Qty::new( Num::new( 2.0 ).with_prefix( Prefix::Mega ), &Unit::Gram ).coarsest_exact_unit();
+		assert_eq!( res.to_string(), "2 t" );
+
+		// Never choosing a finer unit.
+		let res = Qty::new( 5.5.into(), &Unit::Kilogram ).coarsest_exact_unit();
+		assert_eq!( res.to_string(), "5.5 kg" );
+
+		assert_eq!( Qty::new( 36.0.into(), &Unit::Inch ).coarsest_exact_unit().to_string(), "1 yd" );
+		assert_eq!( Qty::new( 48.0.into(), &Unit::Inch ).coarsest_exact_unit().to_string(), "4 ft" );
+		assert_eq!( Qty::new( 90.0.into(), &Unit::Minute ).coarsest_exact_unit().to_string(), "90 min" );
+		assert_eq!( Qty::new( 172_800.0.into(), &Unit::Second ).coarsest_exact_unit().to_string(), "2 d" );
+		assert_eq!( Qty::new( 20.0.into(), &Unit::Celsius ).coarsest_exact_unit().to_string(), "20 °C" );
 	}
-}
 
-impl Neg for Qty {
-	type Output = Self;
+	#[test]
+	fn qty_convert() {
+		let qty = Qty::new( Num::new( 1.5 ).with_prefix( Prefix::Kilo ), &Unit::Meter );
+		assert_eq!( qty.convert( &Unit::Meter, Prefix::Centi ).unwrap().to_string(), "150000 cm" );
+		assert_eq!( qty.convert( &Unit::Foot, Prefix::Nothing ).unwrap(), qty );
+		assert_eq!( qty.convert( &Unit::Foot, Prefix::Nothing ).unwrap().unit(), &Unit::Foot );
+		assert!( matches!( qty.convert( &Unit::Kelvin, Prefix::Nothing ), Err( SinumError::Unit( _ ) ) ) );
+	}
 
-	fn neg( self ) -> Self::Output {
-		let val = -self.as_f64();
-		let num = Num::new( val ).to_prefix( self.number.prefix() );
+	#[test]
+	fn unit_lens() {
+		let cases = [
+			( Unit::Kilogram, Unit::Gram ),
+			( Unit::Tonne, Unit::Pound ),
+			( Unit::Celsius, Unit::Fahrenheit ),
+			( Unit::Fahrenheit, Unit::Kelvin ),
+			( Unit::Hour, Unit::Second ),
+			( Unit::Bar, Unit::Pascal ),
+			( Unit::Liter, Unit::compound( vec![ ( Unit::Meter, 3 ) ] ) ),
+		];
+		for ( source, target ) in cases {
+			let lens = UnitLens::new( &source, &target ).unwrap();
+			assert_eq!( lens.source(), &source );
+			assert_eq!( lens.target(), &target );
+			for val in [ -40.0, 0.0, 0.5, 9.9, 1234.5 ] {
+				for prefix in [ Prefix::Nothing, Prefix::Milli, Prefix::Kilo ] {
+					let qty = Qty::new( Num::new( val ).with_prefix( prefix ), &source );
+					let res = lens.apply( &qty );
+					let expected = qty.to_unit( &target ).unwrap();
+					assert_eq!( res, expected, "{} → {}", qty, target );
+					assert_eq!( res.number().prefix(), expected.number().prefix(), "{} → {}", qty, target );
+					assert_eq!( res.unit(), expected.unit(), "{} → {}", qty, target );
+				}
+			}
+		}
 
-		Self::new( num, &self.unit.base() ).to_unit( &self.unit ).unwrap()
+		let lens = UnitLens::new( &Unit::Mile, &Unit::Meter ).unwrap();
+		assert_eq!( lens.factor(), 1609.344 );
+		assert_eq!( lens.apply( &Qty::new( 1.0.into(), &Unit::Foot ) ), Qty::new( 0.3048.into(), &Unit::Meter ) );
+		assert!( UnitLens::new( &Unit::Meter, &Unit::Second ).is_err() );
 	}
-}
 
-impl fmt::Display for Qty {
-	fn fmt( &self, f: &mut fmt::Formatter ) -> fmt::Result {
-		match self.number.prefix() {
-			Prefix::Nothing => write!( f, "{} {}", self.number, self.unit.to_string_sym() ),
-			_ => write!( f, "{}{}", self.number, self.unit.to_string_sym() ),
+	#[test]
+	#[should_panic]
+	fn unit_lens_wrong_unit() {
+		let lens = UnitLens::new( &Unit::Mile, &Unit::Meter ).unwrap();
+		lens.apply( &Qty::new( 1.0.into(), &Unit::Second ) );
+	}
+
+	#[test]
+	fn qty_round_trip_error() {
+		for unit in Unit::all() {
+			for via in Unit::for_quantity( &unit.phys() ) {
+				for val in [ 1.0, 9.9, -273.0, 1234.5678, 1e-6, 1e9 ] {
+					let qty = Qty::new( val.into(), &unit );
+					let err = qty.round_trip_error( &via ).unwrap();
+					assert!( err <= qty.as_f64().abs().max( 1.0 ) * 1e-14, "{} via {}: {}", qty, via, err );
+				}
+			}
 		}
+
+		// Units differing only by the prefix do not need any conversion.
+		let qty = Qty::new( Num::new( 9.9 ).with_prefix( Prefix::Kilo ), &Unit::Meter );
+		assert_eq!( qty.round_trip_error( &Unit::Meter ).unwrap(), 0.0 );
+		assert_eq!( qty.clone().to_prefix( Prefix::Nothing ).to_prefix( Prefix::Kilo ), qty );
+		assert!( qty.round_trip_error( &Unit::Second ).is_err() );
 	}
-}
 
-#[cfg( feature = "tex" )]
-impl Latex for Qty {
-	/// Return a string that represents this `Qty` as LaTeX string.
-	fn to_latex( &self, options: &TexOptions ) -> String {
-		self.to_latex_sym( options )
+	#[test]
+	fn qty_to_base() {
+		let mass = Qty::new( 9.9.into(), &Unit::Tonne ).to_base();
+		assert_eq!( mass.unit(), &Unit::Kilogram );
+		assert_eq!( mass.number(), Num::new( 9900.0 ) );
+		assert_eq!( mass.number().prefix(), Prefix::Nothing );
+		assert_eq!( Qty::new( Num::new( 5.0 ).with_prefix( Prefix::Kilo ), &Unit::Gram ).to_base().number(), Num::new( 5.0 ) );
+
+		let length = Qty::new( 1.0.into(), &Unit::Lightyear ).to_base();
+		assert_eq!( length.unit(), &Unit::Meter );
+		assert_eq!( length.number(), Num::new( 9_460_730_472_580_800.0 ) );
+		let length = Qty::new( Num::new( 2.5 ).with_prefix( Prefix::Kilo ), &Unit::Meter ).to_base();
+		assert_eq!( length.number().prefix(), Prefix::Nothing );
+		assert_eq!( length.number().mantissa(), 2500.0 );
+
+		let pressure = Qty::new( 1.5.into(), &Unit::Bar ).to_base();
+		assert_eq!( pressure.unit(), &Unit::Pascal );
+		assert_eq!( pressure.number(), Num::new( 150_000.0 ) );
+
+		let temp = Qty::new( 20.0.into(), &Unit::Celsius ).to_base();
+		assert_eq!( temp.unit(), &Unit::Kelvin );
+		assert_eq!( temp.number(), Num::new( 293.15 ) );
 	}
-}
 
-#[cfg( feature = "tex" )]
-impl LatexSym for Qty {
-	/// Return a string that represents this `Qty` as LaTeX command (requiring the usage of the `{siunitx}` package in LaTeX).
-	///
-	/// # Example
-	/// ```
-	/// # use sinum::LatexSym;
-	/// # use sinum::{Qty, Unit, Num, Prefix, TexOptions};
-	/// assert_eq!( Qty::new( 9.9.into(), &Unit::Ampere ).to_latex_sym( &TexOptions::none() ), r"\qty{9.9}{\ampere}".to_string() );
-	/// assert_eq!(
-	///     Qty::new( Num::new( 9.9 ).with_prefix( Prefix::Milli ), &Unit::Ampere ).to_latex_sym( &TexOptions::none() ),
-	///     r"\qty{9.9}{\milli\ampere}".to_string()
-	/// );
-	/// ```
-	///
-	/// # Kilogram
-	///
-	/// The base unit for mass, the kilogram is a special case, since it already has a prefix (kilo), that has to be taken into account.
-	/// ```
-	/// # use sinum::LatexSym;
-	/// # use sinum::{Qty, Unit, Num, Prefix, TexOptions};
-	/// assert_eq!( Qty::new( 9.9.into(), &Unit::Kilogram ).to_latex_sym( &TexOptions::new() ), r"\qty{9.9}{\kilogram}".to_string() );
-	/// assert_eq!(
-	///     Qty::new( Num::new( 9.9 ).with_prefix( Prefix::Kilo ), &Unit::Kilogram ).to_latex_sym( &TexOptions::new() ),
-	///     r"\qty{9.9}{\mega\gram}".to_string()
-	/// );
-	/// assert_eq!(
-	///     Qty::new( Num::new( 9.9 ).with_prefix( Prefix::Milli ), &Unit::Kilogram ).to_latex_sym(
-	///         &TexOptions::new()
-	///             .minimum_decimal_digits( 1 )
-	///     ),
-	///     r"\qty{9.9}{\gram}".to_string()
-	/// );
-	/// assert_eq!(
-	///     Qty::new( Num::new( 9.9 ).with_prefix( Prefix::Micro ), &Unit::Kilogram ).to_latex_sym( &TexOptions::new() ),
-	///     r"\qty{9.9}{\milli\gram}".to_string()
-	/// );
-	/// assert_eq!( Qty::new(
-	///     Num::new( 9.9 ).with_prefix( Prefix::Milli ), &Unit::Gram ).to_latex_sym( &TexOptions::new() ),
-	///     r"\qty{9.9}{\milli\gram}".to_string()
-	/// );
-	/// assert_eq!(
-	///     Qty::new( Num::new( 9.9 ).with_prefix( Prefix::Kilo ), &Unit::Gram ).to_latex_sym( &TexOptions::new() ),
-	///     r"\qty{9.9}{\kilogram}".to_string()
-	/// );
-	/// ```
-	fn to_latex_sym( &self, options: &TexOptions ) -> String {
-		let mantissa = match options.minimum_decimal_digits {
-			Some( x ) => format!( "{:.1$}", self.number.mantissa(), x as usize ),
-			None => self.number.mantissa().to_string(),
-		};
-		format!(
-			r"\qty{}{{{}}}{{{}{}}}",
-			options,
-			mantissa,
-			self.number.prefix().to_latex_sym( options ),
-			self.unit.to_latex_sym( options )
-		)
+	#[test]
+	fn qty_to_unit_shortened() {
+		let qty = Qty::new( Num::new( 1_609_344.0 ).with_prefix( Prefix::Milli ), &Unit::Meter );
+
+		let miles = qty.to_unit_shortened( &Unit::Mile ).unwrap();
+		assert_eq!( miles.unit(), &Unit::Mile );
+		assert_eq!( miles.number().prefix(), Prefix::Nothing );
+		assert!( ( miles.number().mantissa() - 1.0 ).abs() < 1e-12 );
+
+		let meters = miles.to_unit_shortened( &Unit::Meter ).unwrap();
+		assert_eq!( meters.unit(), &Unit::Meter );
+		assert_eq!( meters.number().prefix(), Prefix::Kilo );
+		assert!( ( meters.number().mantissa() - 1.609344 ).abs() < 1e-12 );
+
+		assert!( matches!( qty.to_unit_shortened( &Unit::Second ), Err( SinumError::Unit( _ ) ) ) );
+		assert!( matches!( Qty::new( 1e40.into(), &Unit::Meter ).to_unit_shortened( &Unit::Meter ), Err( SinumError::Prefix( _ ) ) ) );
 	}
-}
 
+	#[test]
+	fn qty_scale() {
+		let length = Qty::new( Num::new( 9.0 ).with_prefix( Prefix::Kilo ), &Unit::Meter );
+		let ratio = Num::new( 1.0 ) / Num::new( 3.0 );
+		let scaled = length.scale( ratio );
+		assert!( ( scaled.as_f64() - 3e3 ).abs() < 1e-9 );
+		assert_eq!( scaled.number().prefix(), Prefix::Kilo );
+		assert_eq!( scaled.unit(), &Unit::Meter );
 
+		let mass = Qty::new( 4.0.into(), &Unit::Tonne ).scale( Num::new( 25.0 ).with_prefix( Prefix::Centi ) );
+		assert_eq!( mass, Qty::new( 1.0.into(), &Unit::Tonne ) );
+		assert_eq!( mass.unit(), &Unit::Tonne );
+	}
 
+	#[test]
+	fn qty_compound() {
+		let area = Qty::new( 2.0.into(), &Unit::Meter ) * Qty::new( 3.0.into(), &Unit::Meter );
+		assert_eq!( area.as_f64(), 6.0 );
+		assert_eq!( area.unit(), &Unit::Compound( vec![ ( Unit::Meter, 2 ) ] ) );
+		assert_eq!( area.to_string(), "6 m²".to_string() );
+		assert_eq!( area.phys(), PhysicalQuantity::Compound( vec![ ( PhysicalQuantity::Length, 2 ) ] ) );
 
-//=============================================================================
-// Testing
+		let length = area.clone() / Qty::new( 1.0.into(), &Unit::Meter );
+		assert_eq!( length.unit(), &Unit::Meter );
+		assert_eq!( length, Qty::new( 6.0.into(), &Unit::Meter ) );
 
+		let volume = area * Qty::new( Num::new( 50.0 ).with_prefix( Prefix::Centi ), &Unit::Meter );
+		assert_eq!( volume.as_f64(), 3.0 );
+		assert_eq!( volume.to_string(), "3 m³".to_string() );
 
-#[cfg( test )]
-mod tests {
-	use super::*;
+		// Units of the same physical quantity are merged.
+		let mass = Qty::new( 2.0.into(), &Unit::Tonne ) * Qty::new( 500.0.into(), &Unit::Kilogram );
+		assert_eq!( mass.unit(), &Unit::Compound( vec![ ( Unit::Tonne, 2 ) ] ) );
+		assert_eq!( mass.as_f64(), 1e6 );
 
-	use crate::Prefix;
+		// Dimensionless results.
+		let ratio = Qty::new( 3.0.into(), &Unit::Ampere ) / Qty::new( Num::new( 6.0 ).with_prefix( Prefix::Kilo ), &Unit::Ampere );
+		assert_eq!( ratio.as_f64(), 5e-4 );
+		assert_eq!( ratio.to_string(), "0.0005".to_string() );
+	}
 
 	#[test]
-	fn qty_eq() {
-		assert!( Qty::new( 10e3.into(), &Unit::Kilogram ) == Qty::new( 10.0.into(), &Unit::Tonne ) );
+	fn qty_compound_prefix() {
+		let area = Qty::new( Num::new( 1.5 ).with_prefix( Prefix::Kilo ), &Unit::compound( vec![ ( Unit::Meter, 2 ) ] ) );
+		assert_eq!( area.as_f64(), 1.5e6 );
+		assert_eq!( area.clone().to_prefix( Prefix::Nothing ).number().mantissa(), 1.5e6 );
+		assert_eq!( area.clone().to_prefix( Prefix::Nothing ).shortened().unwrap().number().prefix(), Prefix::Kilo );
+		assert_eq!( area.to_string_eng(), "1.5×10^6 m²".to_string() );
+
+		let rate = Qty::new( 5000.0.into(), &Unit::compound( vec![ ( Unit::Second, -1 ) ] ) ).shortened().unwrap();
+		assert_eq!( rate.number().prefix(), Prefix::Milli );
+		assert_eq!( rate.to_string(), "5 ms⁻¹".to_string() );
+		assert_eq!( rate.as_f64(), 5000.0 );
+
+		// The kilogram special case also applies to the leading unit of compound units.
+		let density = Qty::new( Num::new( 3.0 ).with_prefix( Prefix::Milli ), &Unit::compound( vec![ ( Unit::Kilogram, 1 ), ( Unit::Meter, -3 ) ] ) );
+		assert_eq!( density.to_string(), "3 g·m⁻³".to_string() );
+		assert_eq!( density.as_f64(), 3e-3 );
 	}
 
 	#[test]
-	fn siqty_as_f64() {
-		// `as_f64()` returns the value with regard to the base unit.
-		assert_eq!( Qty::new( 9.9.into(), &Unit::Tonne ).as_f64(), 9.9e3 );
-		assert_eq!( Qty::new( Num::new( 8.0 ).with_prefix( Prefix::Milli ), &Unit::Gram ).as_f64(), 8.0e-6 );
+	fn qty_checked_add_sub() {
+		let amp = Qty::new( 1.0.into(), &Unit::Ampere );
+		let sec = Qty::new( 1.0.into(), &Unit::Second );
+
+		assert!( matches!( amp.clone().checked_add( sec.clone() ), Err( UnitError::UnitMismatch( _ ) ) ) );
+		assert!( matches!( amp.clone().checked_sub( sec ), Err( UnitError::UnitMismatch( _ ) ) ) );
+		assert_eq!( amp.clone().checked_add( amp.clone() ).unwrap(), Qty::new( 2.0.into(), &Unit::Ampere ) );
+		assert_eq!( amp.clone().checked_sub( amp ).unwrap(), Qty::new( 0.0.into(), &Unit::Ampere ) );
 	}
 
 	#[test]
-	fn siqty_string() {
-		assert_eq!( Qty::new( 9.9.into(), &Unit::Ampere ).to_string(), "9.9 A".to_string() );
-		assert_eq!( Qty::new( Num::new( 9.9 ).with_prefix( Prefix::Kilo ), &Unit::Meter ).to_string(), "9.9 km".to_string() );
-		assert_eq!( Qty::new( 9.9.into(), &Unit::Kelvin ).to_string(), "9.9 K".to_string() );
+	#[should_panic]
+	fn qty_add_mismatch_panics() {
+		let _ = Qty::new( 1.0.into(), &Unit::Ampere ) + Qty::new( 1.0.into(), &Unit::Second );
+	}
+
+	#[test]
+	fn qty_ops_ref() {
+		let a = Qty::new( Num::new( 8.0 ).with_prefix( Prefix::Milli ), &Unit::Gram );
+		let b = Qty::new( 4.0.into(), &Unit::Tonne );
+
+		assert_eq!( &a + &b, a.clone() + b.clone() );
+		assert_eq!( &a - &b, a.clone() - b.clone() );
+		assert_eq!( &a * &b, a.clone() * b.clone() );
+		assert_eq!( &a / &b, a.clone() / b.clone() );
+		assert_eq!( &a * 2.5, a.clone() * 2.5 );
+		assert_eq!( ( &a * 2.5 ).number().prefix(), ( a.clone() * 2.5 ).number().prefix() );
+		assert_eq!( &b / 4.0, b.clone() / 4.0 );
+		assert_eq!( -&a, -a.clone() );
+		assert_eq!( ( -&a ).unit(), a.unit() );
+
+		// Affine units
+		let c = Qty::new( 20.0.into(), &Unit::Celsius );
+		let d = Qty::new( 10.0.into(), &Unit::Celsius );
+		for ( by_ref, owned, expected ) in [
+			( -&c, -c.clone(), -20.0 ),
+			( &c * 2.0, c.clone() * 2.0, 40.0 ),
+			( &c / 2.0, c.clone() / 2.0, 10.0 ),
+			( &c + &d, c.clone() + d.clone(), 30.0 ),
+			( &c - &d, c.clone() - d.clone(), 10.0 ),
+		] {
+			assert_eq!( by_ref, owned );
+			assert_eq!( by_ref.unit(), &Unit::Celsius );
+			assert!( ( by_ref.number().mantissa() - expected ).abs() < 1e-9, "{}", by_ref );
+		}
+
+		assert_eq!( &a - &a, Qty::new( 0.0.into(), &Unit::Gram ) );
+
+		// The operands are still usable and unchanged.
+		assert_eq!( a, Qty::new( Num::new( 8.0 ).with_prefix( Prefix::Milli ), &Unit::Gram ) );
+		assert_eq!( b, Qty::new( 4.0.into(), &Unit::Tonne ) );
 	}
 
 	// The weight/mass is a special case.
@@ -721,6 +3782,255 @@ mod tests {
 		assert_eq!( Qty::new( Num::new( 9.9 ).with_prefix( Prefix::Kilo ), &Unit::Gram ).to_latex_sym( &TexOptions::new() ), r"\qty{9.9}{\kilogram}".to_string() );
 	}
 
+	#[test]
+	fn qty_with_prefix() {
+		let qty = Qty::new( Num::new( 2.5 ).with_prefix( Prefix::Kilo ), &Unit::Meter );
+
+		let with = qty.clone().with_prefix( Prefix::Milli );
+		assert_eq!( with.number(), Num::new( 2.5 ).with_prefix( Prefix::Milli ) );
+		assert_eq!( with.as_f64(), 0.0025 );
+
+		let to = qty.clone().to_prefix( Prefix::Milli );
+		assert_eq!( to.number().mantissa(), 2_500_000.0 );
+		assert_eq!( to.as_f64(), qty.as_f64() );
+		assert_ne!( with.as_f64(), to.as_f64() );
+
+		assert_eq!( qty.clone().with_prefix( Prefix::Kilo ), qty );
+		assert_eq!( qty.clone().with_prefix( Prefix::Nothing ).to_string(), "2.5 m" );
+
+		let mass = Qty::new( 2.0.into(), &Unit::Kilogram );
+		assert_eq!( mass.clone().with_prefix( Prefix::Milli ), Qty::new( Num::new( 2.0 ).with_prefix( Prefix::Milli ), &Unit::Gram ) );
+		assert_eq!( mass.clone().with_prefix( Prefix::Kilo ), mass );
+		assert_eq!( mass.clone().with_prefix( Prefix::Kilo ).unit(), &Unit::Kilogram );
+		assert_eq!( mass.clone().with_prefix( Prefix::Nothing ).to_string(), "2 g" );
+		assert_eq!( mass.clone().with_prefix( Prefix::Mega ).to_string(), "2 Mg" );
+		assert_eq!( mass.to_prefix( Prefix::Mega ).as_f64(), 2.0 );
+
+		let area = Qty::new( 3.0.into(), &Unit::compound( vec![ ( Unit::Meter, 2 ) ] ) ).with_prefix( Prefix::Kilo );
+		assert_eq!( area.to_string(), "3 km²" );
+		assert_eq!( area.as_f64(), 3e6 );
+	}
+
+	#[test]
+	fn qty_ordered() {
+		use std::collections::HashSet;
+
+		let set: HashSet<OrderedQty> = [
+			Qty::new( 1.0.into(), &Unit::Tonne ),
+			Qty::new( 1000.0.into(), &Unit::Kilogram ),
+			Qty::new( Num::new( 1.0 ).with_prefix( Prefix::Mega ), &Unit::Gram ),
+			Qty::new( 1000.0.into(), &Unit::Meter ),
+			Qty::new( Num::new( 1.0 ).with_prefix( Prefix::Kilo ), &Unit::Meter ),
+			Qty::new( 1000.0.into(), &Unit::Second ),
+		].into_iter().map( OrderedQty ).collect();
+		assert_eq!( set.len(), 3 );
+		assert!( set.contains( &OrderedQty( Qty::new( 1.0.into(), &Unit::Tonne ) ) ) );
+		assert!( !set.contains( &OrderedQty( Qty::new( 1.0.into(), &Unit::Kilogram ) ) ) );
+
+		assert_eq!( OrderedQty( Qty::new( 0.0.into(), &Unit::Meter ) ), OrderedQty::from( Qty::new( ( -0.0 ).into(), &Unit::Foot ) ) );
+		assert_ne!( OrderedQty( Qty::new( 1.0.into(), &Unit::Meter ) ), OrderedQty( Qty::new( 1.0.into(), &Unit::Second ) ) );
+		assert!( OrderedQty( Qty::new( 1.0.into(), &Unit::Mile ) ) > OrderedQty( Qty::new( 1.0.into(), &Unit::Meter ) ) );
+	}
+
+	#[test]
+	fn qty_as_unit_f64() {
+		let length = Qty::new( 1500.0.into(), &Unit::Meter );
+		assert_eq!( length.as_unit_f64( &Unit::Meter ).unwrap(), 1500.0 );
+		assert_eq!( length.as_unit_f64( &Unit::Meter ).unwrap() / 1000.0, 1.5 );
+		assert!( ( length.as_unit_f64( &Unit::Foot ).unwrap() - 1500.0 / 0.3048 ).abs() < 1e-9 );
+		assert_eq!( Qty::new( Num::new( 2.0 ).with_prefix( Prefix::Kilo ), &Unit::Foot ).as_unit_f64( &Unit::Meter ).unwrap(), 609.6 );
+
+		let gram = Qty::new( 1500.0.into(), &Unit::Gram );
+		assert_eq!( gram.as_unit_f64( &Unit::Kilogram ).unwrap(), 1.5 );
+		assert_eq!( gram.as_unit_f64( &Unit::Gram ).unwrap(), 1500.0 );
+		assert_eq!( Qty::new( 2.0.into(), &Unit::Kilogram ).as_unit_f64( &Unit::Gram ).unwrap(), 2000.0 );
+		assert_eq!( Qty::new( 2.0.into(), &Unit::Kilogram ).as_unit_f64( &Unit::Kilogram ).unwrap(), 2.0 );
+		assert!( ( Qty::new( Num::new( 2.0 ).with_prefix( Prefix::Milli ), &Unit::Gram ).as_unit_f64( &Unit::Kilogram ).unwrap() - 2e-6 ).abs() < 1e-18 );
+		assert!( ( Qty::new( Num::new( 2.0 ).with_prefix( Prefix::Kilo ), &Unit::Tonne ).as_unit_f64( &Unit::Kilogram ).unwrap() - 2e6 ).abs() < 1e-6 );
+
+		let area = Qty::new( Num::new( 2.0 ).with_prefix( Prefix::Kilo ), &Unit::compound( vec![ ( Unit::Meter, 2 ) ] ) );
+		assert_eq!( area.as_unit_f64( &Unit::compound( vec![ ( Unit::Meter, 2 ) ] ) ).unwrap(), 2e6 );
+
+		assert!( ( Qty::new( 25.0.into(), &Unit::Celsius ).as_unit_f64( &Unit::Kelvin ).unwrap() - 298.15 ).abs() < 1e-12 );
+		assert!( matches!( length.as_unit_f64( &Unit::Gram ), Err( UnitError::UnitMismatch( _ ) ) ) );
+	}
+
+	#[test]
+	fn qty_convert_all() {
+		let tonne = Qty::new( 1.0.into(), &Unit::Tonne );
+		let all = tonne.convert_all();
+
+		assert_eq!( all.len(), Unit::for_quantity( &PhysicalQuantity::Mass ).len() );
+		for unit in [ Unit::Kilogram, Unit::Gram, Unit::Tonne ] {
+			let qty = all.iter().find( |x| x.unit() == &unit ).unwrap();
+			assert_eq!( qty, &tonne );
+		}
+		assert!( all.iter().all( |x| x.phys() == PhysicalQuantity::Mass ) );
+		assert!( all.iter().all( |x| ( x.as_f64() - 1000.0 ).abs() < 1e-9 ) );
+
+		assert!( Qty::new( 1.0.into(), &Unit::Custom( "apple".to_string() ) ).convert_all().is_empty() );
+		assert!( Qty::new( 1.0.into(), &Unit::compound( vec![ ( Unit::Meter, 2 ) ] ) ).convert_all().is_empty() );
+		assert_eq!( Qty::new( 1.0.into(), &Unit::compound( vec![ ( Unit::Meter, 3 ) ] ) ).convert_all(), vec![ Qty::new( 1000.0.into(), &Unit::Liter ) ] );
+	}
+
+	#[test]
+	fn qty_parts() {
+		let qty = Qty::new( Num::new( 9.9 ).with_prefix( Prefix::Milli ), &Unit::Ampere );
+		let ( mantissa, prefix, unit ) = qty.parts();
+
+		assert_eq!( mantissa, 9.9 );
+		assert_eq!( prefix, Prefix::Milli );
+		assert_eq!( unit, &Unit::Ampere );
+		assert_eq!( Qty::new( Num::new( mantissa ).with_prefix( prefix ), unit ), qty );
+
+		assert_eq!( Qty::new( 2.0.into(), &Unit::Kilogram ).parts(), ( 2.0, Prefix::Nothing, &Unit::Kilogram ) );
+	}
+
+	#[test]
+	fn qty_abs_diff() {
+		let kilo = Qty::new( 1.0.into(), &Unit::Kilogram );
+		let gram = Qty::new( 1100.0.into(), &Unit::Gram );
+
+		let diff = kilo.clone().abs_diff( gram.clone() ).unwrap();
+		assert_eq!( diff.unit(), &Unit::Kilogram );
+		assert!( ( diff.number().as_f64() - 0.1 ).abs() < 1e-12 );
+
+		let diff = gram.clone().abs_diff( kilo.clone() ).unwrap();
+		assert_eq!( diff.unit(), &Unit::Gram );
+		assert!( ( diff.number().as_f64() - 100.0 ).abs() < 1e-9 );
+
+		let diff = Qty::new( Num::new( 500.0 ).with_prefix( Prefix::Milli ), &Unit::Gram ).abs_diff( gram.clone() ).unwrap();
+		assert_eq!( diff.number().prefix(), Prefix::Milli );
+		assert!( ( diff.number().mantissa() - 1_099_500.0 ).abs() < 1e-6 );
+
+		assert_eq!( kilo.clone().abs_diff( kilo.clone() ).unwrap(), Qty::new( 0.0.into(), &Unit::Kilogram ) );
+		assert!( kilo.abs_diff( Qty::new( 1.0.into(), &Unit::Meter ) ).is_err() );
+	}
+
+	#[test]
+	fn qty_powi() {
+		let length = Qty::new( 3.0.into(), &Unit::Meter );
+
+		let area = length.clone().powi( 2 );
+		assert_eq!( area.as_f64(), 9.0 );
+		assert_eq!( area.unit(), &Unit::compound( vec![ ( Unit::Meter, 2 ) ] ) );
+		assert_eq!( area.to_string(), "9 m²" );
+
+		let volume = length.clone().powi( 3 );
+		assert_eq!( volume.as_f64(), 27.0 );
+		assert_eq!( volume.quantity(), PhysicalQuantity::Volume );
+		assert_eq!( volume.to_string(), "27 m³" );
+		assert_eq!( volume.to_unit( &Unit::Liter ).unwrap().number(), Num::new( 27_000.0 ) );
+
+		let volume = Qty::new( Num::new( 2.0 ).with_prefix( Prefix::Centi ), &Unit::Meter ).powi( 3 );
+		assert_eq!( volume.to_string(), "8 cm³" );
+		assert!( ( volume.as_f64() - 8e-6 ).abs() < 1e-18 );
+
+		assert_eq!( length.clone().powi( 1 ), length );
+		assert_eq!( length.clone().powi( 1 ).unit(), &Unit::Meter );
+		assert_eq!( length.clone().powi( 0 ).unit(), &Unit::One );
+
+		let unity = Qty::new( Num::new( 2.0 ).with_prefix( Prefix::Kilo ), &Unit::Meter ).powi( 0 );
+		assert_eq!( unity.as_f64(), 1.0 );
+		assert_eq!( unity.number().prefix(), Prefix::Nothing );
+		assert_eq!( unity.to_string(), "1" );
+		assert_eq!( length.clone().powi( -1 ), length.clone().recip() );
+		assert_eq!( area.powi( 2 ).unit(), &Unit::compound( vec![ ( Unit::Meter, 4 ) ] ) );
+
+		let speed = Qty::new( 2.0.into(), &Unit::Meter ) / Qty::new( 1.0.into(), &Unit::Second );
+		assert_eq!( speed.powi( 2 ).to_string(), "4 m²·s⁻²" );
+		assert_eq!( Qty::new( 20.0.into(), &Unit::Celsius ).powi( 1 ).unit(), &Unit::Celsius );
+	}
+
+	#[test]
+	#[should_panic]
+	fn qty_powi_affine() {
+		Qty::new( 20.0.into(), &Unit::Celsius ).powi( 2 );
+	}
+
+	#[test]
+	fn qty_recip() {
+		let rate = Qty::new( 4.0.into(), &Unit::Second ).recip();
+		assert_eq!( rate.as_f64(), 0.25 );
+		assert_eq!( rate.unit(), &Unit::compound( vec![ ( Unit::Second, -1 ) ] ) );
+		assert_eq!( rate.quantity(), PhysicalQuantity::Frequency );
+		assert_eq!( rate.clone().recip(), Qty::new( 4.0.into(), &Unit::Second ) );
+		assert_eq!( rate.recip().unit(), &Unit::Second );
+
+		let rate = Qty::new( Num::new( 4.0 ).with_prefix( Prefix::Kilo ), &Unit::Meter ).recip();
+		assert_eq!( rate.as_f64(), 0.25e-3 );
+		assert_eq!( rate.number().prefix(), Prefix::Kilo );
+		assert_eq!( rate.to_string(), "0.25 km⁻¹" );
+
+		let rate = Qty::new( Num::new( 2.0 ).with_prefix( Prefix::Milli ), &Unit::Gram ).recip();
+		assert_eq!( rate.as_f64(), 500e3 );
+		assert_eq!( Qty::new( 2.0.into(), &Unit::Kilogram ).recip().as_f64(), 0.5 );
+
+		let speed = Qty::new( 1.0.into(), &Unit::Meter ) / Qty::new( 2.0.into(), &Unit::Second );
+		assert_eq!( speed.recip().as_f64(), 2.0 );
+		assert_eq!( Qty::new( 0.0.into(), &Unit::Second ).recip().as_f64(), f64::INFINITY );
+	}
+
+	#[test]
+	#[should_panic]
+	fn qty_recip_affine() {
+		Qty::new( 20.0.into(), &Unit::Celsius ).recip();
+	}
+
+	#[test]
+	fn qty_temperature_rate() {
+		let rate = Qty::new( 5.0.into(), &Unit::Kelvin ) / Qty::new( 2.0.into(), &Unit::Second );
+		assert_eq!( rate.as_f64(), 2.5 );
+		assert_eq!( rate.unit(), &Unit::compound( vec![ ( Unit::Kelvin, 1 ), ( Unit::Second, -1 ) ] ) );
+		assert_eq!( rate.to_string(), "2.5 K·s⁻¹" );
+
+		// Rates in compound units are converted like temperature differences.
+		let celsius_rate = rate.to_unit( &Unit::compound( vec![ ( Unit::Celsius, 1 ), ( Unit::Second, -1 ) ] ) ).unwrap();
+		assert_eq!( celsius_rate.number().mantissa(), 2.5 );
+		let fahrenheit_rate = rate.to_unit( &Unit::compound( vec![ ( Unit::Fahrenheit, 1 ), ( Unit::Minute, -1 ) ] ) ).unwrap();
+		assert!( ( fahrenheit_rate.number().mantissa() - 270.0 ).abs() < 1e-9 );
+
+		// Absolute temperatures in affine units are rejected.
+		assert!( matches!(
+			Qty::new( 5.0.into(), &Unit::Celsius ).checked_div( Qty::new( 2.0.into(), &Unit::Second ) ),
+			Err( UnitError::AffineUnit( Unit::Celsius ) )
+		) );
+		assert!( matches!(
+			Qty::new( 2.0.into(), &Unit::Second ).checked_mul( Qty::new( 5.0.into(), &Unit::Fahrenheit ) ),
+			Err( UnitError::AffineUnit( Unit::Fahrenheit ) )
+		) );
+		assert_eq!(
+			Qty::new( 5.0.into(), &Unit::Kelvin ).checked_div( Qty::new( 2.0.into(), &Unit::Second ) ).unwrap(),
+			rate
+		);
+	}
+
+	#[test]
+	#[should_panic]
+	fn qty_temperature_rate_affine() {
+		let _ = Qty::new( 5.0.into(), &Unit::Celsius ) / Qty::new( 2.0.into(), &Unit::Second );
+	}
+
+	#[test]
+	fn qty_mul_prefix() {
+		let km = Qty::new( Num::new( 2.0 ).with_prefix( Prefix::Kilo ), &Unit::Meter ) * Qty::new( Num::new( 3.0 ).with_prefix( Prefix::Kilo ), &Unit::Meter );
+		assert_eq!( km.as_f64(), 6e6 );
+		assert_eq!( km.number().mantissa(), 6.0 );
+		assert_eq!( km.number().prefix(), Prefix::Kilo );
+		assert_eq!( km.to_string(), "6 km²".to_string() );
+
+		let amp = Qty::new( Num::new( 500.0 ).with_prefix( Prefix::Milli ), &Unit::Ampere ) * Qty::new( 2.0.into(), &Unit::Ampere );
+		assert_eq!( amp.as_f64(), 1.0 );
+		assert_eq!( amp.number().prefix(), Prefix::Nothing );
+	}
+
+	#[cfg( feature = "tex" )]
+	#[test]
+	fn qty_latex_electrical() {
+		assert_eq!( Qty::new( 5.0.into(), &Unit::Volt ).to_latex_sym( &TexOptions::new() ), r"\qty{5}{\volt}".to_string() );
+		assert_eq!( Qty::new( Num::new( 9.9 ).with_prefix( Prefix::Milli ), &Unit::Coulomb ).to_latex_sym( &TexOptions::new() ), r"\qty{9.9}{\milli\coulomb}".to_string() );
+	}
+
 	#[test]
 	fn qty_string_engineering() {
 		assert_eq!( Qty::new( 9.9.into(), &Unit::Ampere ).to_string_eng(), "9.9 A".to_string() );
@@ -728,6 +4038,199 @@ mod tests {
 		assert_eq!( Qty::new( Num::new( 9.9 ).with_prefix( Prefix::Milli ), &Unit::Kelvin ).to_string_eng(), "9.9×10^-3 K".to_string() );
 	}
 
+	#[test]
+	fn qty_display_alternate() {
+		let qty = Qty::new( Num::new( 9.9 ).with_prefix( Prefix::Milli ), &Unit::Ampere );
+		assert_eq!( format!( "{}", qty ), "9.9 mA" );
+		assert_eq!( format!( "{:#}", qty ), "9.9×10^-3 A" );
+		assert_eq!( format!( "{:#}", qty ), qty.to_string_eng() );
+
+		let qty = Qty::new( 9.9.into(), &Unit::Ampere );
+		assert_eq!( format!( "{}", qty ), format!( "{:#}", qty ) );
+		assert_eq!( format!( "{:#}", Qty::new( Num::new( 1.5 ).with_prefix( Prefix::Kilo ), &Unit::compound( vec![ ( Unit::Meter, 2 ) ] ) ) ), "1.5×10^6 m²" );
+	}
+
+	#[test]
+	fn qty_display_rounding() {
+		let x = Qty::new( Num::new( 1.23456789 ).with_prefix( Prefix::Milli ), &Unit::Second );
+		assert_eq!( x.to_string(), "1.23456789 ms" );
+		assert_eq!( x.to_string_si_space(), "1.23456789\u{202F}ms" );
+		assert_eq!( x.to_string_rounded( None ), "1.23456789 ms" );
+		assert_eq!( x.to_string_rounded( Some( CLEAN_DISPLAY_DECIMALS ) ), "1.234568 ms" );
+
+		let x = Qty::new( 20.123456789.into(), &Unit::Degree );
+		assert_eq!( x.to_string(), "20.123456789°" );
+		assert_eq!( x.to_string_rounded( Some( 1 ) ), "20.1°" );
+	}
+
+	#[cfg( feature = "i18n" )]
+	#[test]
+	fn qty_display_locale() {
+		use unic_langid::langid;
+
+		let english = langid!( "en-US" );
+		let german = langid!( "de-DE" );
+
+		let x = Qty::new( 9.9.into(), &Unit::Meter );
+		assert_eq!( x.to_string_locale( &english ), "9.9 meter" );
+		assert_eq!( x.to_string_locale( &german ), "9,9 Meter" );
+
+		let x = Qty::new( Num::new( -2.5 ).with_prefix( Prefix::Milli ), &Unit::Ampere );
+		assert_eq!( x.to_string_locale( &english ), "-2.5 milliampere" );
+		assert_eq!( x.to_string_locale( &german ), "-2,5 Milliampere" );
+
+		assert_eq!( Qty::new( 1.5.into(), &Unit::Kilogram ).to_string_locale( &german ), "1,5 Kilogramm" );
+		assert_eq!( Qty::new( 1.5.into(), &Unit::AstronomicalUnit ).to_string_locale( &german ), "1,5 Astronomische Einheit" );
+		assert_eq!( Qty::new( 0.5.into(), &Unit::One ).to_string_locale( &german ), "0,5" );
+		assert_eq!( Qty::new( 12.0.into(), &Unit::Percent ).to_string_locale( &german ), "12 Prozent" );
+		assert_eq!( Qty::new( Num::new( f64::INFINITY ).with_prefix( Prefix::Kilo ), &Unit::Meter ).to_string_locale( &german ), "∞ Meter" );
+
+		assert_eq!( Num::new( 1234.5 ).to_string_locale( &german ), "1234,5" );
+		assert_eq!( Num::new( 1234.5 ).with_prefix( Prefix::Mega ).to_string_locale( &english ), "1234.5 M" );
+	}
+
+	#[cfg( feature = "i18n" )]
+	#[test]
+	fn qty_display_locale_sym() {
+		use unic_langid::langid;
+
+		let english = langid!( "en-US" );
+		let german = langid!( "de-DE" );
+		let french = langid!( "fr-FR" );
+
+		let x = Qty::new( Num::new( 9.9 ).with_prefix( Prefix::Kilo ), &Unit::Meter );
+		assert_eq!( x.to_string_locale_sym( &english ), x.to_string() );
+		assert_eq!( x.to_string_locale_sym( &german ), "9,9\u{A0}km" );
+		assert_eq!( x.to_string_locale_sym( &french ), "9,9\u{202F}km" );
+
+		let x = Qty::new( Num::new( 2.5 ).with_prefix( Prefix::Micro ), &Unit::Ampere );
+		assert_eq!( x.to_string_locale_sym( &german ), "2,5\u{A0}µA" );
+		assert_eq!( x.to_string_locale_sym( &french ), "2,5\u{202F}µA" );
+
+		// Units written without a space and dimensionless quantities do not use a separator.
+		assert_eq!( Qty::new( 22.5.into(), &Unit::Degree ).to_string_locale_sym( &french ), "22,5°" );
+		assert_eq!( Qty::new( 0.5.into(), &Unit::One ).to_string_locale_sym( &german ), "0,5" );
+
+		assert_eq!( Qty::new( 9.9.into(), &Unit::Meter ).to_string_locale( &french ), "9,9 mètre" );
+		assert_eq!( x.to_string_locale( &french ), "2,5 microampère" );
+	}
+
+	#[test]
+	fn qty_display_non_finite() {
+		let qty = Qty::new( 1.0.into(), &Unit::Ampere ) / 0.0;
+		assert!( !qty.is_finite() );
+		assert_eq!( qty.to_string(), "∞ A" );
+		assert_eq!( ( -qty.clone() ).to_string(), "-∞ A" );
+		assert_eq!( ( qty.clone() * 0.0 ).to_string(), "NaN A" );
+		assert_eq!( qty.to_string_eng(), "∞ A" );
+		assert_eq!( qty.to_string_compact(), "∞ A" );
+
+		let qty = Qty::new( Num::new( f64::INFINITY ).with_prefix( Prefix::Kilo ), &Unit::Meter );
+		assert_eq!( qty.to_string(), "∞ m" );
+		assert_eq!( qty.to_string_si_space(), "∞\u{202F}m" );
+		assert_eq!( qty.to_string_eng(), "∞ m" );
+		assert_eq!( Qty::new( f64::NAN.into(), &Unit::Degree ).to_string(), "NaN°" );
+
+		// The strings can be parsed again.
+		assert_eq!( "∞ A".parse::<Qty>().unwrap().as_f64(), f64::INFINITY );
+		assert_eq!( "-∞ A".parse::<Qty>().unwrap().as_f64(), f64::NEG_INFINITY );
+		assert!( "NaN A".parse::<Qty>().unwrap().is_nan() );
+	}
+
+	#[test]
+	fn qty_string_compact() {
+		assert_eq!( Qty::new( 9900.0.into(), &Unit::Meter ).to_string_compact(), "9.9 km" );
+		assert_eq!( Qty::new( Num::new( 9.9 ).with_prefix( Prefix::Kilo ), &Unit::Meter ).to_string_compact(), "9.9 km" );
+		assert_eq!( Qty::new( 5.0.into(), &Unit::Meter ).to_string_compact(), "5 m" );
+		assert_eq!( Qty::new( 0.002.into(), &Unit::Ampere ).to_string_compact(), "2 mA" );
+		assert_eq!( Qty::new( 1.5e-9.into(), &Unit::Second ).to_string_compact(), "1.5 ns" );
+		assert_eq!( Qty::new( 2e40.into(), &Unit::Meter ).to_string_compact(), "2×10^40 m" );
+		assert_eq!( Qty::new( Num::new( -1.234 ).with_prefix( Prefix::Mega ), &Unit::Volt ).to_string_compact(), "-1.234 MV" );
+		assert_eq!( Qty::new( Num::new( -9900.0 ), &Unit::Meter ).to_string_compact(), "-9.9 km" );
+		assert_eq!( Qty::new( 0.0.into(), &Unit::Meter ).to_string_compact(), "0 m" );
+		assert_eq!( Qty::new( 20.0.into(), &Unit::Degree ).to_string_compact(), "20°" );
+
+		// No precision is lost.
+		for value in [ 1.234567891e-7, 9.87654321e5, 123.456789, -4.2e12, 1.0000001e40 ] {
+			let compact = Qty::new( value.into(), &Unit::Meter ).to_string_compact();
+			let parsed = match compact.split_once( "×10^" ) {
+				Some( ( mantissa, exp ) ) => {
+					let exp = exp.trim_end_matches( " m" ).parse::<i32>().unwrap();
+					mantissa.parse::<f64>().unwrap() * 10_f64.powi( exp )
+				},
+				None => compact.parse::<Qty>().unwrap().as_f64(),
+			};
+			assert!( ( parsed - value ).abs() <= value.abs() * 1e-12, "{}", compact );
+		}
+	}
+
+	#[cfg( feature = "tex" )]
+	#[test]
+	fn qty_latex_text() {
+		let options = TexOptions::new();
+		assert_eq!( Qty::new( 9.9.into(), &Unit::Meter ).to_latex_text( &options ), "9.9 meter" );
+		assert_eq!( Qty::new( Num::new( 2.0 ).with_prefix( Prefix::Milli ), &Unit::Ampere ).to_latex_text( &options ), "2 milliampere" );
+		assert_eq!( Qty::new( Num::new( 5.0 ).with_prefix( Prefix::Mega ), &Unit::Watt ).to_latex_text( &options ), "5 megawatt" );
+		assert_eq!( Qty::new( 20.0.into(), &Unit::Celsius ).to_latex_text( &options ), "20 degree Celsius" );
+
+		// Kilogram
+		assert_eq!( Qty::new( 9.9.into(), &Unit::Kilogram ).to_latex_text( &options ), "9.9 kilogram" );
+		assert_eq!( Qty::new( Num::new( 9.9 ).with_prefix( Prefix::Kilo ), &Unit::Gram ).to_latex_text( &options ), "9.9 kilogram" );
+		assert_eq!( Qty::new( Num::new( 9.9 ).with_prefix( Prefix::Kilo ), &Unit::Kilogram ).to_latex_text( &options ), "9.9 megagram" );
+		assert_eq!( Qty::new( Num::new( 9.9 ).with_prefix( Prefix::Micro ), &Unit::Kilogram ).to_latex_text( &options ), "9.9 milligram" );
+
+		assert_eq!( Qty::new( Num::new( 500.0 ).with_prefix( Prefix::Micro ), &Unit::One ).to_latex_text( &options ), "500 micro" );
+		assert_eq!( Qty::new( 0.5.into(), &Unit::One ).to_latex_text( &options ), "0.5" );
+		assert_eq!( Qty::new( 9.0.into(), &Unit::Meter ).to_latex_text( &TexOptions::new().minimum_decimal_digits( 1 ) ), "9.0 meter" );
+	}
+
+	#[cfg( feature = "tex" )]
+	#[test]
+	fn qty_latex_notation() {
+		let qty = Qty::new( Num::new( 9.9 ).with_prefix( Prefix::Milli ), &Unit::Ampere );
+		let prefixed = TexOptions::new().notation( Notation::Prefixed );
+		let engineering = TexOptions::new().notation( Notation::Engineering );
+		let scientific = TexOptions::new().notation( Notation::Scientific );
+
+		assert_eq!( qty.to_latex( &TexOptions::new() ), r"\qty{9.9}{\milli\ampere}".to_string() );
+		assert_eq!( qty.to_latex( &prefixed ), r"\qty{9.9}{\milli\ampere}".to_string() );
+		assert_eq!( qty.to_latex( &engineering ), r"\qty{9.9e-3}{\ampere}".to_string() );
+		assert_eq!( qty.to_latex( &scientific ), r"\qty{9.9e-3}{\ampere}".to_string() );
+
+		let qty = Qty::new( Num::new( 99.0 ).with_prefix( Prefix::Milli ), &Unit::Ampere );
+		assert_eq!( qty.to_latex( &prefixed ), r"\qty{99}{\milli\ampere}".to_string() );
+		assert_eq!( qty.to_latex( &engineering ), r"\qty{99e-3}{\ampere}".to_string() );
+		assert_eq!( qty.to_latex( &scientific ), r"\qty{9.9e-2}{\ampere}".to_string() );
+
+		let qty = Qty::new( Num::new( 25.0 ).with_prefix( Prefix::Kilo ), &Unit::Meter );
+		assert_eq!( qty.to_latex( &prefixed ), r"\qty{25}{\kilo\meter}".to_string() );
+		assert_eq!( qty.to_latex( &engineering ), r"\qty{25e3}{\meter}".to_string() );
+		assert_eq!( qty.to_latex( &scientific ), r"\qty{2.5e4}{\meter}".to_string() );
+
+		let qty = Qty::new( 3.5.into(), &Unit::Second );
+		assert_eq!( qty.to_latex( &engineering ), r"\qty{3.5}{\second}".to_string() );
+		assert_eq!( qty.to_latex( &scientific ), r"\qty{3.5}{\second}".to_string() );
+		assert_eq!( Qty::new( 0.0.into(), &Unit::Second ).to_latex( &scientific ), r"\qty{0}{\second}".to_string() );
+	}
+
+	#[cfg( feature = "tex" )]
+	#[test]
+	fn qty_latex_decimals() {
+		let qty = Qty::new( Num::new( 9.0 ).with_prefix( Prefix::Milli ), &Unit::Ampere );
+		let options = TexOptions::new().minimum_decimal_digits( 2 );
+		assert_eq!( qty.to_latex_sym( &options ), r"\qty{9.00}{\milli\ampere}".to_string() );
+
+		let options = TexOptions::new().minimum_decimal_digits( 2 ).drop_zero_decimal( true );
+		assert_eq!( qty.to_latex_sym( &options ), r"\qty[drop-zero-decimal]{9}{\milli\ampere}".to_string() );
+		assert_eq!( qty.to_latex_eng( &options ), r"\qty[drop-zero-decimal]{9e-3}{\ampere}".to_string() );
+		assert_eq!( qty.to_latex( &options.notation( Notation::Scientific ) ), r"\qty[drop-zero-decimal]{9e-3}{\ampere}".to_string() );
+
+		let qty = Qty::new( Num::new( 9.5 ).with_prefix( Prefix::Milli ), &Unit::Ampere );
+		let options = TexOptions::new().minimum_decimal_digits( 2 ).drop_zero_decimal( true );
+		assert_eq!( qty.to_latex_sym( &options ), r"\qty[drop-zero-decimal]{9.50}{\milli\ampere}".to_string() );
+		assert_eq!( qty.to_latex_eng( &options ), r"\qty[drop-zero-decimal]{9.50e-3}{\ampere}".to_string() );
+	}
+
 	#[cfg( feature = "tex" )]
 	#[test]
 	fn qty_latex_engineering() {
@@ -735,4 +4238,21 @@ mod tests {
 		assert_eq!( Qty::new( Num::new( 9.9 ).with_prefix( Prefix::Kilo ), &Unit::Meter ).to_latex_eng( &TexOptions::new() ), r"\qty{9.9e3}{\meter}".to_string() );
 		assert_eq!( Qty::new( Num::new( 9.9 ).with_prefix( Prefix::Milli ), &Unit::Kelvin ).to_latex_eng( &TexOptions::new() ), r"\qty{9.9e-3}{\kelvin}".to_string() );
 	}
+
+	#[cfg( feature = "tex" )]
+	#[test]
+	fn qty_latex_commands() {
+		let qty = Qty::new( Num::new( 9.9 ).with_prefix( Prefix::Milli ), &Unit::Ampere );
+		let legacy = TexOptions::new().legacy_commands( true );
+
+		assert_eq!( qty.to_latex_sym( &TexOptions::new() ), r"\qty{9.9}{\milli\ampere}" );
+		assert_eq!( qty.to_latex_sym( &TexOptions::new().legacy_commands( false ) ), r"\qty{9.9}{\milli\ampere}" );
+		assert_eq!( qty.to_latex_sym( &legacy ), r"\SI{9.9}{\milli\ampere}" );
+		assert_eq!( qty.to_latex_eng( &legacy ), r"\SI{9.9e-3}{\ampere}" );
+		assert_eq!( qty.to_latex( &legacy.notation( Notation::Scientific ) ), r"\SI{9.9e-3}{\ampere}" );
+
+		let options = TexOptions::new().exponent_product( r"\cdot" );
+		assert_eq!( qty.to_latex_eng( &options ), r"\qty[exponent-product=\cdot]{9.9e-3}{\ampere}" );
+		assert_eq!( qty.to_latex_eng( &options.legacy_commands( true ) ), r"\SI[exponent-product=\cdot]{9.9e-3}{\ampere}" );
+	}
 }
@@ -0,0 +1,117 @@
+//! Provides a `serde` adapter converting quantities to their base unit when deserializing.
+//!
+//! Use this module with `#[serde( with = "sinum::serde_base" )]` on fields of type `Qty`. Deserialized quantities are always given in the base unit (e.g. kilogram for the units of mass) without a prefix, so downstream code never has to handle prefixes or derived units. Quantities are accepted in every form the default `Deserialize` implementation of `Qty` accepts, including strings like "5 t" in human-readable formats. Serialization is identical to the default `Serialize` implementation.
+//!
+//! This module is only available, if the **`serde`** feature has been enabled.
+//!
+//! # Example
+//! ```
+//! # use serde::Deserialize;
+//! # use sinum::{Qty, Unit};
+//! #[derive( Deserialize )]
+//! struct Load {
+//!     #[serde( with = "sinum::serde_base" )]
+//!     mass: Qty,
+//! }
+//!
+//! let load: Load = serde_json::from_str( r#"{ "mass": "5 t" }"# ).unwrap();
+//! assert_eq!( load.mass.unit(), &Unit::Kilogram );
+//! assert_eq!( load.mass, Qty::new( 5000.0.into(), &Unit::Kilogram ) );
+//! ```
+
+
+
+
+//=============================================================================
+// Crates
+
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::Qty;
+
+
+
+
+//=============================================================================
+// Functions
+
+
+/// Serializes `qty` exactly like the default `Serialize` implementation of `Qty`.
+pub fn serialize<S>( qty: &Qty, serializer: S ) -> Result<S::Ok, S::Error>
+where
+	S: Serializer,
+{
+	qty.serialize( serializer )
+}
+
+/// Deserializes a `Qty` and converts it to its base unit without a prefix.
+pub fn deserialize<'de, D>( deserializer: D ) -> Result<Qty, D::Error>
+where
+	D: Deserializer<'de>,
+{
+	Ok( Qty::deserialize( deserializer )?.to_base() )
+}
+
+
+
+
+//=============================================================================
+// Testing
+
+
+#[cfg( test )]
+mod tests {
+	use super::*;
+
+	use crate::{Num, Prefix, Unit};
+
+	#[derive( Serialize, Deserialize, PartialEq, Debug )]
+	struct Record {
+		#[serde( with = "crate::serde_base" )]
+		qty: Qty,
+	}
+
+	#[derive( Serialize, Deserialize, PartialEq, Debug )]
+	struct RecordDefault {
+		qty: Qty,
+	}
+
+	#[test]
+	fn serde_base_text() {
+		let record: Record = serde_json::from_str( r#"{ "qty": "5 t" }"# ).unwrap();
+		assert_eq!( record.qty, Qty::new( 5000.0.into(), &Unit::Kilogram ) );
+		assert_eq!( record.qty.unit(), &Unit::Kilogram );
+		assert_eq!( record.qty.number().prefix(), Prefix::Nothing );
+
+		let record: Record = serde_json::from_str( r#"{ "qty": "9.9 km" }"# ).unwrap();
+		assert_eq!( record.qty.unit(), &Unit::Meter );
+		assert_eq!( record.qty.number().prefix(), Prefix::Nothing );
+		assert_eq!( record.qty.to_string(), "9900 m" );
+
+		let record: Record = serde_json::from_str( r#"{ "qty": "25 °C" }"# ).unwrap();
+		assert_eq!( record.qty.unit(), &Unit::Kelvin );
+		assert_eq!( record.qty, Qty::new( 298.15.into(), &Unit::Kelvin ) );
+
+		assert!( serde_json::from_str::<Record>( r#"{ "qty": "5 xyz" }"# ).is_err() );
+	}
+
+	#[test]
+	fn serde_base_struct() {
+		let qty = Qty::new( Num::new( 5.0 ).with_prefix( Prefix::Milli ), &Unit::Tonne );
+		let text = serde_json::to_string( &Record { qty: qty.clone() } ).unwrap();
+
+		// Serialization is not affected.
+		assert_eq!( text, serde_json::to_string( &RecordDefault { qty: qty.clone() } ).unwrap() );
+
+		let record: Record = serde_json::from_str( &text ).unwrap();
+		assert_eq!( record.qty, Qty::new( 5.0.into(), &Unit::Kilogram ) );
+		assert_eq!( record.qty.unit(), &Unit::Kilogram );
+		assert_eq!( record.qty.number().prefix(), Prefix::Nothing );
+
+		// The default deserializer preserves the stored form.
+		let record: RecordDefault = serde_json::from_str( &text ).unwrap();
+		assert_eq!( record.qty.unit(), &Unit::Tonne );
+		assert_eq!( record.qty.number().prefix(), Prefix::Milli );
+	}
+}
@@ -0,0 +1,243 @@
+//! Provides a `serde` representation of quantities following the conventions of the `{siunitx}` LaTeX package.
+//!
+//! Use this module with `#[serde( with = "sinum::serde_siunitx" )]` on fields of type `Qty`. A quantity is represented by the number in the form expected by `\qty` (the prefix being expressed as exponent of ten) and the `{siunitx}` command of the unit:
+//!
+//! ```json
+//! { "qty": "9.9e3", "unit": "\\meter" }
+//! ```
+//!
+//! Both values can be inserted directly into `\qty{…}{…}`. The submodule `num` provides the same representation of the number for fields of type `Num`.
+//!
+//! This module is only available, if the **`serde`** and **`tex`** features have been enabled.
+//!
+//! # Example
+//! ```
+//! # use serde::{Serialize, Deserialize};
+//! # use sinum::{Qty, Num, Unit, Prefix};
+//! #[derive( Serialize, Deserialize )]
+//! struct Measurement {
+//!     #[serde( with = "sinum::serde_siunitx" )]
+//!     distance: Qty,
+//! }
+//!
+//! let data = Measurement { distance: Qty::new( Num::new( 9.9 ).with_prefix( Prefix::Kilo ), &Unit::Meter ) };
+//! let text = serde_json::to_string( &data ).unwrap();
+//! assert_eq!( text, r#"{"distance":{"qty":"9.9e3","unit":"\\meter"}}"# );
+//!
+//! let data: Measurement = serde_json::from_str( &text ).unwrap();
+//! assert_eq!( data.distance, Qty::new( 9900.0.into(), &Unit::Meter ) );
+//! ```
+
+
+
+
+//=============================================================================
+// Crates
+
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::de::Error;
+
+use crate::{LatexSym, Num, Prefix, Qty, TexOptions, Unit};
+
+
+
+
+//=============================================================================
+// Structs
+
+
+/// The `{siunitx}` representation of a `Qty`.
+#[derive( Serialize, Deserialize )]
+struct QtySiunitx {
+	qty: String,
+	unit: String,
+}
+
+
+
+
+//=============================================================================
+// Functions
+
+
+/// Returns the number `mantissa`·10^`exp` as expected by `{siunitx}`.
+fn to_string_siunitx( mantissa: f64, exp: i32 ) -> String {
+	match exp {
+		0 => mantissa.to_string(),
+		x => format!( "{}e{}", mantissa, x ),
+	}
+}
+
+/// Parses a number as expected by `{siunitx}`. Exponents of ten are translated back into the prefix raised to `power`, if possible.
+fn from_str_siunitx( s: &str, power: i8 ) -> Option<Num> {
+	let s = s.trim();
+
+	if let Some( ( mantissa, exp ) ) = s.split_once( [ 'e', 'E' ] ) {
+		if let ( Ok( mantissa ), Ok( exp ) ) = ( mantissa.parse::<f64>(), exp.parse::<i8>() ) {
+			if power != 0 && exp % power == 0 {
+				if let Ok( prefix ) = Prefix::try_from( exp / power ) {
+					return Some( Num::new( mantissa ).with_prefix( prefix ) );
+				}
+			}
+		}
+	}
+
+	s.parse::<f64>().ok().map( Num::new )
+}
+
+/// Serializes `qty` as `{ "qty": "9.9e3", "unit": "\\meter" }`. Returns an error for custom units (including compound units containing a custom unit), since these do not have a `{siunitx}` command.
+pub fn serialize<S>( qty: &Qty, serializer: S ) -> Result<S::Ok, S::Error>
+where
+	S: Serializer,
+{
+	let is_custom = |x: &Unit| matches!( x, Unit::Custom( _ ) );
+	let has_custom = match qty.unit() {
+		Unit::Compound( parts ) => parts.iter().any( |( x, _ )| is_custom( x ) ),
+		x => is_custom( x ),
+	};
+	if has_custom {
+		return Err( <S::Error as serde::ser::Error>::custom( format!( "The unit `{}` cannot be represented by a `{{siunitx}}` command", qty.unit() ) ) );
+	}
+
+	let power = qty.unit().leading().1 as i32;
+	let repr = QtySiunitx {
+		qty: to_string_siunitx( qty.number().mantissa(), qty.number().prefix().exp() as i32 * power ),
+		unit: qty.unit().to_latex_sym( &TexOptions::none() ),
+	};
+
+	repr.serialize( serializer )
+}
+
+/// Deserializes a `Qty` from `{ "qty": "9.9e3", "unit": "\\meter" }`. If the exponent of ten represents a prefix, the quantity uses this prefix.
+pub fn deserialize<'de, D>( deserializer: D ) -> Result<Qty, D::Error>
+where
+	D: Deserializer<'de>,
+{
+	let repr = QtySiunitx::deserialize( deserializer )?;
+
+	let unit = Unit::from_latex_sym( &repr.unit ).map_err( D::Error::custom )?;
+	let number = from_str_siunitx( &repr.qty, unit.leading().1 )
+		.ok_or_else( || D::Error::custom( format!( "Not a valid number: {}", repr.qty ) ) )?;
+
+	Ok( Qty::new( number, &unit ) )
+}
+
+
+
+
+//=============================================================================
+// Modules
+
+
+/// Provides the `{siunitx}` representation of a `Num` as string like "9.9e3". Use this module with `#[serde( with = "sinum::serde_siunitx::num" )]` on fields of type `Num`.
+pub mod num {
+	use super::*;
+
+	/// Serializes `num` as string like "9.9e3".
+	pub fn serialize<S>( num: &Num, serializer: S ) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		to_string_siunitx( num.mantissa(), num.prefix().exp() as i32 ).serialize( serializer )
+	}
+
+	/// Deserializes a `Num` from a string like "9.9e3". If the exponent of ten represents a prefix, the number uses this prefix.
+	pub fn deserialize<'de, D>( deserializer: D ) -> Result<Num, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		let text = String::deserialize( deserializer )?;
+
+		from_str_siunitx( &text, 1 ).ok_or_else( || D::Error::custom( format!( "Not a valid number: {}", text ) ) )
+	}
+}
+
+
+
+
+//=============================================================================
+// Testing
+
+
+#[cfg( test )]
+mod tests {
+	use super::*;
+
+	use crate::Latex;
+
+	#[derive( Serialize, Deserialize, PartialEq, Debug )]
+	struct Record {
+		#[serde( with = "crate::serde_siunitx" )]
+		qty: Qty,
+	}
+
+	#[derive( Serialize, Deserialize, PartialEq, Debug )]
+	struct RecordNum {
+		#[serde( with = "crate::serde_siunitx::num" )]
+		num: Num,
+	}
+
+	#[test]
+	fn siunitx_serialize() {
+		let record = Record { qty: Qty::new( Num::new( 9.9 ).with_prefix( Prefix::Kilo ), &Unit::Meter ) };
+		assert_eq!( serde_json::to_string( &record ).unwrap(), r#"{"qty":{"qty":"9.9e3","unit":"\\meter"}}"# );
+
+		let record = Record { qty: Qty::new( 2.5.into(), &Unit::Kilogram ) };
+		assert_eq!( serde_json::to_string( &record ).unwrap(), r#"{"qty":{"qty":"2.5","unit":"\\kilogram"}}"# );
+
+		let record = Record { qty: Qty::new( Num::new( 3.0 ).with_prefix( Prefix::Centi ), &Unit::compound( vec![ ( Unit::Meter, 2 ) ] ) ) };
+		assert_eq!( serde_json::to_string( &record ).unwrap(), r#"{"qty":{"qty":"3e-4","unit":"\\meter\\squared"}}"# );
+
+		let record = RecordNum { num: Num::new( 1.5 ).with_prefix( Prefix::Micro ) };
+		assert_eq!( serde_json::to_string( &record ).unwrap(), r#"{"num":"1.5e-6"}"# );
+
+		let apple = Unit::Custom( "apple".to_string() );
+		assert!( serde_json::to_string( &Record { qty: Qty::new( 5.0.into(), &apple ) } ).is_err() );
+		assert!( serde_json::to_string( &Record { qty: Qty::new( 5.0.into(), &Unit::compound( vec![ ( apple, 1 ), ( Unit::Second, -1 ) ] ) ) } ).is_err() );
+	}
+
+	#[test]
+	fn siunitx_round_trip() {
+		for qty in [
+			Qty::new( Num::new( 9.9 ).with_prefix( Prefix::Kilo ), &Unit::Meter ),
+			Qty::new( Num::new( 1.25 ).with_prefix( Prefix::Milli ), &Unit::Ampere ),
+			Qty::new( Num::new( -20.5 ), &Unit::Celsius ),
+			Qty::new( Num::new( 5.0 ).with_prefix( Prefix::Mega ), &Unit::Gram ),
+			Qty::new( Num::new( 3.0 ).with_prefix( Prefix::Centi ), &Unit::compound( vec![ ( Unit::Meter, 2 ) ] ) ),
+			Qty::new( Num::new( 7.0 ), &Unit::compound( vec![ ( Unit::Meter, 1 ), ( Unit::Second, -2 ) ] ) ),
+		] {
+			let text = serde_json::to_string( &Record { qty: qty.clone() } ).unwrap();
+			let res: Record = serde_json::from_str( &text ).unwrap();
+			assert_eq!( res.qty, qty );
+			assert_eq!( res.qty.unit(), qty.unit() );
+			assert_eq!( res.qty.number().prefix(), qty.number().prefix() );
+
+			// The deserialized quantity renders the same `\qty` command.
+			assert_eq!( res.qty.to_latex( &TexOptions::new() ), qty.to_latex( &TexOptions::new() ) );
+		}
+
+		let num = Num::new( 4.2 ).with_prefix( Prefix::Giga );
+		let res: RecordNum = serde_json::from_str( &serde_json::to_string( &RecordNum { num } ).unwrap() ).unwrap();
+		assert_eq!( res.num, num );
+		assert_eq!( res.num.prefix(), Prefix::Giga );
+	}
+
+	#[test]
+	fn siunitx_deserialize() {
+		let res: Record = serde_json::from_str( r#"{ "qty": { "qty": "9.9e3", "unit": "\\meter" } }"# ).unwrap();
+		assert_eq!( res.qty.to_latex( &TexOptions::new() ), r"\qty{9.9}{\kilo\meter}" );
+
+		// Exponents without a matching prefix.
+		let res: Record = serde_json::from_str( r#"{ "qty": { "qty": "1.5e4", "unit": "\\second" } }"# ).unwrap();
+		assert_eq!( res.qty, Qty::new( 15e3.into(), &Unit::Second ) );
+		assert_eq!( res.qty.number().prefix(), Prefix::Nothing );
+
+		let res: Record = serde_json::from_str( r#"{ "qty": { "qty": "2e3", "unit": "\\meter\\squared" } }"# ).unwrap();
+		assert_eq!( res.qty.as_f64(), 2e3 );
+		assert_eq!( res.qty.number().prefix(), Prefix::Nothing );
+
+		assert!( serde_json::from_str::<Record>( r#"{ "qty": { "qty": "x", "unit": "\\meter" } }"# ).is_err() );
+		assert!( serde_json::from_str::<Record>( r#"{ "qty": { "qty": "1", "unit": "\\kilo\\meter" } }"# ).is_err() );
+	}
+}
@@ -0,0 +1,167 @@
+//! Provides a `serde` representation of prefixes and units by their symbols.
+//!
+//! By default, `Prefix` and `Unit` are represented by the names of their variants like "Kilo" or "Kilogram". Use the submodules of this module with `#[serde( with = "sinum::serde_sym::prefix" )]` on fields of type `Prefix` and `#[serde( with = "sinum::serde_sym::unit" )]` on fields of type `Unit` to represent them by their symbols like "k" or "kg" instead.
+//!
+//! Custom and compound units cannot be parsed from their symbol and therefore cannot be serialized by this module.
+//!
+//! This module is only available, if the **`serde`** feature has been enabled.
+//!
+//! # Example
+//! ```
+//! # use serde::{Serialize, Deserialize};
+//! # use sinum::{Prefix, Unit};
+//! #[derive( Serialize, Deserialize )]
+//! struct Config {
+//!     #[serde( with = "sinum::serde_sym::prefix" )]
+//!     prefix: Prefix,
+//!     #[serde( with = "sinum::serde_sym::unit" )]
+//!     unit: Unit,
+//! }
+//!
+//! let config = Config { prefix: Prefix::Milli, unit: Unit::Kilogram };
+//! let text = serde_json::to_string( &config ).unwrap();
+//! assert_eq!( text, r#"{"prefix":"m","unit":"kg"}"# );
+//!
+//! let config: Config = serde_json::from_str( &text ).unwrap();
+//! assert_eq!( config.prefix, Prefix::Milli );
+//! assert_eq!( config.unit, Unit::Kilogram );
+//! ```
+
+
+
+
+//=============================================================================
+// Crates
+
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::de::Error;
+
+
+
+
+//=============================================================================
+// Modules
+
+
+/// Provides the representation of a `Prefix` by its symbol like "k". Use this module with `#[serde( with = "sinum::serde_sym::prefix" )]` on fields of type `Prefix`.
+pub mod prefix {
+	use super::*;
+
+	use crate::Prefix;
+
+	/// Serializes `prefix` as its symbol like "k" (see `Prefix::to_string_sym()`). `Prefix::Nothing` is represented by an empty string.
+	pub fn serialize<S>( prefix: &Prefix, serializer: S ) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		prefix.to_string_sym().serialize( serializer )
+	}
+
+	/// Deserializes a `Prefix` from its symbol like "k" (see `Prefix::from_symbol()`). An empty string represents `Prefix::Nothing`.
+	pub fn deserialize<'de, D>( deserializer: D ) -> Result<Prefix, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		let text = String::deserialize( deserializer )?;
+
+		match text.as_str() {
+			"" => Ok( Prefix::Nothing ),
+			x => Prefix::from_symbol( x ).map_err( D::Error::custom ),
+		}
+	}
+}
+
+
+/// Provides the representation of a `Unit` by its symbol like "kg". Use this module with `#[serde( with = "sinum::serde_sym::unit" )]` on fields of type `Unit`.
+pub mod unit {
+	use super::*;
+
+	use crate::Unit;
+
+	/// Serializes `unit` as its symbol like "kg" (see `Unit::to_string_sym()`). Returns an error for custom and compound units.
+	pub fn serialize<S>( unit: &Unit, serializer: S ) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		if let Unit::Custom( _ ) | Unit::Compound( _ ) = unit {
+			return Err( <S::Error as serde::ser::Error>::custom( format!( "The unit `{}` cannot be represented by its symbol", unit ) ) );
+		}
+
+		unit.to_string_sym().serialize( serializer )
+	}
+
+	/// Deserializes a `Unit` from its symbol like "kg". Symbols are compared case-sensitively first (see `Unit::from_symbol()`), since e.g. "a" represents the year while "A" represents the ampere. Otherwise the names of the units are accepted as well (see `Unit::from_str()`).
+	pub fn deserialize<'de, D>( deserializer: D ) -> Result<Unit, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		let text = String::deserialize( deserializer )?;
+
+		match Unit::from_symbol( &text ) {
+			Ok( x ) => Ok( x ),
+			// `Unit::One` is represented by its empty symbol.
+			Err( _ ) if text.is_empty() => Ok( Unit::One ),
+			Err( _ ) => text.parse::<Unit>().map_err( D::Error::custom ),
+		}
+	}
+}
+
+
+
+
+//=============================================================================
+// Testing
+
+
+#[cfg( test )]
+mod tests {
+	use super::*;
+
+	use crate::{Prefix, Unit};
+
+	#[derive( Serialize, Deserialize, PartialEq, Debug )]
+	struct Record {
+		#[serde( with = "crate::serde_sym::prefix" )]
+		prefix: Prefix,
+		#[serde( with = "crate::serde_sym::unit" )]
+		unit: Unit,
+	}
+
+	#[test]
+	fn serde_sym_text() {
+		let record = Record { prefix: Prefix::Mega, unit: Unit::Celsius };
+		assert_eq!( serde_json::to_string( &record ).unwrap(), r#"{"prefix":"M","unit":"°C"}"# );
+
+		let record: Record = serde_json::from_str( r#"{ "prefix": "µ", "unit": "mmHg" }"# ).unwrap();
+		assert_eq!( record, Record { prefix: Prefix::Micro, unit: Unit::MillimeterMercury } );
+
+		let record: Record = serde_json::from_str( r#"{ "prefix": "", "unit": "meter" }"# ).unwrap();
+		assert_eq!( record, Record { prefix: Prefix::Nothing, unit: Unit::Meter } );
+
+		let record: Record = serde_json::from_str( r#"{ "prefix": "k", "unit": "a" }"# ).unwrap();
+		assert_eq!( record, Record { prefix: Prefix::Kilo, unit: Unit::Year } );
+		let record: Record = serde_json::from_str( r#"{ "prefix": "k", "unit": "A" }"# ).unwrap();
+		assert_eq!( record, Record { prefix: Prefix::Kilo, unit: Unit::Ampere } );
+
+		assert!( serde_json::from_str::<Record>( r#"{ "prefix": "K", "unit": "m" }"# ).is_err() );
+		assert!( serde_json::from_str::<Record>( r#"{ "prefix": "k", "unit": "xyz" }"# ).is_err() );
+		assert!( serde_json::to_string( &Record { prefix: Prefix::Kilo, unit: Unit::Custom( "apple".to_string() ) } ).is_err() );
+		assert!( serde_json::to_string( &Record { prefix: Prefix::Kilo, unit: Unit::compound( vec![ ( Unit::Meter, 2 ) ] ) } ).is_err() );
+	}
+
+	#[test]
+	fn serde_sym_round_trip() {
+		for prefix in Prefix::all() {
+			let record = Record { prefix, unit: Unit::Meter };
+			let text = serde_json::to_string( &record ).unwrap();
+			assert_eq!( serde_json::from_str::<Record>( &text ).unwrap(), record );
+		}
+
+		for unit in Unit::all() {
+			let record = Record { prefix: Prefix::Nothing, unit };
+			let text = serde_json::to_string( &record ).unwrap();
+			assert_eq!( serde_json::from_str::<Record>( &text ).unwrap(), record );
+		}
+	}
+}
@@ -7,6 +7,7 @@
 // Crates
 
 
+use std::f64::consts::PI;
 use std::fmt;
 use std::str::FromStr;
 
@@ -21,6 +22,8 @@ use thiserror::Error;
 #[cfg( all( feature = "i18n", feature = "tex" ) )] use crate::LatexLocale;
 #[cfg( feature = "tex" )] use crate::TexOptions;
 
+use crate::Prefix;
+
 #[cfg( feature = "i18n" )] use crate::DisplayLocale;
 #[cfg( feature = "i18n" )] use crate::LOCALES;
 
@@ -38,6 +41,15 @@ pub enum UnitError {
 
 	#[error( "Not a valid unit: {0}" )]
 	ParseFailure( String ),
+
+	#[error( "Not a valid unit: {input} (did you mean: {})", .suggestions.join( ", " ) )]
+	ParseFailureSuggest { input: String, suggestions: Vec<String> },
+
+	#[error( "Not a valid physical quantity: {0}" )]
+	QuantityParseFailure( String ),
+
+	#[error( "The affine unit `{0}` cannot be part of a product or quotient" )]
+	AffineUnit( Unit ),
 }
 
 
@@ -47,9 +59,15 @@ pub enum UnitError {
 // Enums
 
 
-#[derive( PartialEq, Eq, Debug )]
-pub(super) enum PhysicalQuantity {
+/// Represents the physical quantities measured by the units.
+///
+/// Derived quantities like the energy are equivalent to the product of the base quantities they are derived from (see `Qty::base_components()`).
+#[derive( Clone, PartialOrd, Ord, PartialEq, Eq, Hash, Debug )]
+pub enum PhysicalQuantity {
 	Custom,
+	/// The product of physical quantities raised to the respective exponent, e.g. an area as length².
+	Compound( Vec<( PhysicalQuantity, i8 )> ),
+	// Base quantities
 	Current,
 	LuminousIntensity,
 	Temperature,
@@ -57,44 +75,177 @@ pub(super) enum PhysicalQuantity {
 	Length,
 	Amount,
 	Time,
+	// Derived quantities
+	/// Derived from mass·length⁻¹·time⁻².
 	Pressure,
+	/// The dose equivalent. Derived from length²·time⁻².
 	Radiation,
+	/// The absorbed dose. Derived from length²·time⁻² like the dose equivalent, but kept distinct by convention, so gray and sievert cannot be converted into each other.
+	AbsorbedDose,
+	/// The activity of a radioactive source. Derived from time⁻¹ like the frequency, but kept distinct by convention, so becquerel and hertz cannot be converted into each other.
+	Radioactivity,
+	/// The luminous flux. Derived from the luminous intensity, since the solid angle is dimensionless, but kept distinct by convention, so lumen and candela cannot be converted into each other.
+	LuminousFlux,
+	/// Derived from luminous intensity·length⁻².
+	Illuminance,
+	/// Derived from current⁻¹·mass·length²·time⁻³.
+	Voltage,
+	/// Derived from mass·length²·time⁻³.
+	Power,
+	/// Derived from mass·length²·time⁻².
+	Energy,
+	/// Derived from current·time.
+	Charge,
+	Angle,
+	/// Derived from length³.
+	Volume,
+	/// Derived from time⁻¹.
+	Frequency,
+	/// The amount of information like the size of a file. This is not a physical quantity in the strict sense and not part of the SI.
+	Information,
+	/// Ratios of identical physical quantities without any dimension.
+	Dimensionless,
 }
 
-// impl PhysicalQuantity {
-// 	/// Returns the available units for this `PhysicalQuantity` and the factor to the base unit.
-// 	pub(super) fn units( &self ) -> BTreeSet<Unit> {
-// 		match self {
-// 			Self::Custom => BTreeSet::new(),
-// 			Self::Current => BTreeSet::from( [
-// 				Unit::Ampere,
-// 			] ),
-// 			Self::LuminousIntensity => BTreeSet::from( [
-// 				Unit::Candela,
-// 			] ),
-// 			Self::Temperature => BTreeSet::from( [
-// 				Unit::Kelvin,
-// 			] ),
-// 			Self::Mass => BTreeSet::from( [
-// 				Unit::Gram,
-// 				Unit::Kilogram,
-// 				Unit::Tonne,
-// 			] ),
-// 			Self::Length => BTreeSet::from( [
-// 				Unit::Meter,
-// 			] ),
-// 			Self::Amount => BTreeSet::from( [
-// 				Unit::Mole,
-// 			] ),
-// 			Self::Time => BTreeSet::from( [
-// 				Unit::Second,
-// 			] ),
-// 			Self::Radiation => BTreeSet::from( [
-// 				Unit::Sievert,
-// 			] ),
-// 		}
-// 	}
-// }
+impl PhysicalQuantity {
+	/// Returns the base quantities `self` is derived from, raised to the respective exponent and sorted. Quantities that are not derived are returned as they are with an exponent of 1.
+	pub(super) fn dimensions( &self ) -> Vec<( PhysicalQuantity, i8 )> {
+		match self {
+			Self::Compound( x ) => x.clone(),
+			Self::Pressure =>  vec![ ( Self::Mass, 1 ), ( Self::Length, -1 ), ( Self::Time, -2 ) ],
+			Self::Radiation | Self::AbsorbedDose => vec![ ( Self::Length, 2 ), ( Self::Time, -2 ) ],
+			Self::Voltage =>   vec![ ( Self::Current, -1 ), ( Self::Mass, 1 ), ( Self::Length, 2 ), ( Self::Time, -3 ) ],
+			Self::Power =>     vec![ ( Self::Mass, 1 ), ( Self::Length, 2 ), ( Self::Time, -3 ) ],
+			Self::Energy =>    vec![ ( Self::Mass, 1 ), ( Self::Length, 2 ), ( Self::Time, -2 ) ],
+			Self::Charge =>    vec![ ( Self::Current, 1 ), ( Self::Time, 1 ) ],
+			Self::Volume =>    vec![ ( Self::Length, 3 ) ],
+			Self::Frequency | Self::Radioactivity => vec![ ( Self::Time, -1 ) ],
+			Self::LuminousFlux => vec![ ( Self::LuminousIntensity, 1 ) ],
+			Self::Illuminance => vec![ ( Self::LuminousIntensity, 1 ), ( Self::Length, -2 ) ],
+			Self::Dimensionless => Vec::new(),
+			x => vec![ ( x.clone(), 1 ) ],
+		}
+	}
+
+	/// Returns the `PhysicalQuantity` represented by the product of `dims`. This is the counterpart of `dimensions()`.
+	///
+	/// Dimensions shared by several physical quantities result in the dose equivalent (length²·time⁻²), the frequency (time⁻¹) and the luminous intensity respectively.
+	pub(super) fn from_dimensions( mut dims: Vec<( PhysicalQuantity, i8 )> ) -> Self {
+		dims.retain( |( _, e )| *e != 0 );
+		dims.sort();
+
+		match dims.as_slice() {
+			[] => Self::Dimensionless,
+			[ ( phys, 1 ) ] => phys.clone(),
+			[ ( Self::Mass, 1 ), ( Self::Length, -1 ), ( Self::Time, -2 ) ] => Self::Pressure,
+			[ ( Self::Length, 2 ), ( Self::Time, -2 ) ] => Self::Radiation,
+			[ ( Self::Current, -1 ), ( Self::Mass, 1 ), ( Self::Length, 2 ), ( Self::Time, -3 ) ] => Self::Voltage,
+			[ ( Self::Mass, 1 ), ( Self::Length, 2 ), ( Self::Time, -3 ) ] => Self::Power,
+			[ ( Self::Mass, 1 ), ( Self::Length, 2 ), ( Self::Time, -2 ) ] => Self::Energy,
+			[ ( Self::Current, 1 ), ( Self::Time, 1 ) ] => Self::Charge,
+			[ ( Self::Length, 3 ) ] => Self::Volume,
+			[ ( Self::Time, -1 ) ] => Self::Frequency,
+			[ ( Self::LuminousIntensity, 1 ), ( Self::Length, -2 ) ] => Self::Illuminance,
+			_ => Self::Compound( dims ),
+		}
+	}
+
+	/// Returns the smallest value (with respect to the base unit) that is physically possible for this `PhysicalQuantity` or `None` if there is no such lower bound.
+	pub(super) fn minimum( &self ) -> Option<f64> {
+		match self {
+			Self::LuminousIntensity |
+				Self::LuminousFlux |
+				Self::Illuminance |
+				Self::Temperature |
+				Self::Mass |
+				Self::Amount |
+				Self::Volume |
+				Self::Information => Some( 0.0 ),
+			_ => None,
+		}
+	}
+}
+
+impl fmt::Display for PhysicalQuantity {
+	fn fmt( &self, f: &mut fmt::Formatter ) -> fmt::Result {
+		match self {
+			Self::Custom =>            write!( f, "custom" ),
+			Self::Compound( parts ) => write!( f, "{}", parts.iter()
+				.map( |( p, e )| format!( "{}{}", p, superscript( *e ) ) )
+				.collect::<Vec<String>>()
+				.join( "·" )
+			),
+			Self::Current =>           write!( f, "current" ),
+			Self::LuminousIntensity => write!( f, "luminous intensity" ),
+			Self::Temperature =>       write!( f, "temperature" ),
+			Self::Mass =>              write!( f, "mass" ),
+			Self::Length =>            write!( f, "length" ),
+			Self::Amount =>            write!( f, "amount of substance" ),
+			Self::Time =>              write!( f, "time" ),
+			Self::Pressure =>          write!( f, "pressure" ),
+			Self::Radiation =>         write!( f, "radiation" ),
+			Self::AbsorbedDose =>      write!( f, "absorbed dose" ),
+			Self::Radioactivity =>     write!( f, "radioactivity" ),
+			Self::LuminousFlux =>      write!( f, "luminous flux" ),
+			Self::Illuminance =>       write!( f, "illuminance" ),
+			Self::Voltage =>           write!( f, "voltage" ),
+			Self::Power =>             write!( f, "power" ),
+			Self::Energy =>            write!( f, "energy" ),
+			Self::Charge =>            write!( f, "charge" ),
+			Self::Angle =>             write!( f, "angle" ),
+			Self::Volume =>            write!( f, "volume" ),
+			Self::Frequency =>         write!( f, "frequency" ),
+			Self::Information =>       write!( f, "information" ),
+			Self::Dimensionless =>     write!( f, "dimensionless" ),
+		}
+	}
+}
+
+impl FromStr for PhysicalQuantity {
+	type Err = UnitError;
+
+	/// Parses a physical quantity from its name as returned by `to_string()`, like "length" or "luminous intensity". The name is case-insensitive. Compound physical quantities cannot be parsed.
+	///
+	/// # Example
+	/// ```
+	/// # use std::str::FromStr;
+	/// # use sinum::{PhysicalQuantity, Unit};
+	/// assert_eq!( PhysicalQuantity::from_str( "length" ).unwrap(), PhysicalQuantity::Length );
+	/// assert_eq!( "Mass".parse::<PhysicalQuantity>().unwrap(), PhysicalQuantity::Mass );
+	/// assert!( Unit::for_quantity( &"pressure".parse().unwrap() ).contains( &Unit::Bar ) );
+	/// assert!( PhysicalQuantity::from_str( "apple" ).is_err() );
+	/// ```
+	fn from_str( s: &str ) -> Result<Self, Self::Err> {
+		let result = match s.trim().to_lowercase().as_str() {
+			"custom" => Self::Custom,
+			"current" => Self::Current,
+			"luminous intensity" => Self::LuminousIntensity,
+			"temperature" => Self::Temperature,
+			"mass" => Self::Mass,
+			"length" => Self::Length,
+			"amount of substance" | "amount" => Self::Amount,
+			"time" => Self::Time,
+			"pressure" => Self::Pressure,
+			"radiation" => Self::Radiation,
+			"absorbed dose" => Self::AbsorbedDose,
+			"radioactivity" => Self::Radioactivity,
+			"luminous flux" => Self::LuminousFlux,
+			"illuminance" => Self::Illuminance,
+			"voltage" => Self::Voltage,
+			"power" => Self::Power,
+			"energy" => Self::Energy,
+			"charge" => Self::Charge,
+			"angle" => Self::Angle,
+			"volume" => Self::Volume,
+			"frequency" => Self::Frequency,
+			"information" => Self::Information,
+			"dimensionless" => Self::Dimensionless,
+			_ => return Err( UnitError::QuantityParseFailure( s.to_string() ) ),
+		};
+
+		Ok( result )
+	}
+}
 
 impl From<Unit> for PhysicalQuantity {
 	/// Returns the `PhysicalQuantity` that is measured by `item`.
@@ -109,6 +260,8 @@ impl From<Unit> for PhysicalQuantity {
 #[derive( Clone, PartialOrd, Ord, PartialEq, Eq, Hash, Debug )]
 pub enum Unit {
 	Custom( String ),
+	/// The product of units raised to the respective exponent, e.g. m² or m·s⁻¹. Use `Unit::compound()` to create a simplified compound unit.
+	Compound( Vec<( Unit, i8 )> ),
 	// Base units
 	Ampere,
 	Candela,
@@ -117,37 +270,416 @@ pub enum Unit {
 	Meter,
 	Mole,
 	Second,
+	// Additional temperature units
+	Celsius,
+	Fahrenheit,
 	// Additional mass units
 	Gram,
 	Tonne,
+	// Imperial mass units
+	Pound,
+	Ounce,
+	Stone,
 	// Additional length units
 	AstronomicalUnit,
 	Lightyear,
 	Parsec,
+	// Imperial length units
+	Inch,
+	Foot,
+	Yard,
+	Mile,
+	// Additional time units
+	/// Like the other time units besides the second, the minute is rarely used with a prefix. Prefixes still work as for any other unit (see `Qty::to_prefix()`).
+	Minute,
+	Hour,
+	Day,
+	/// The Julian year of 365.25 days.
+	Year,
 	//
 	Pascal,
 	Bar,
+	Atmosphere,
+	MillimeterMercury,
+	Torr,
+	Psi,
 	Sievert,
+	Gray,
+	Becquerel,
+	Lumen,
+	Lux,
+	// Electrical units
+	Volt,
+	Watt,
+	Joule,
+	Electronvolt,
+	Calorie,
+	Coulomb,
+	// Angle units
+	Radian,
+	Degree,
+	Gradian,
+	// Volume units
+	Liter,
+	// Frequency units
+	Hertz,
+	// Information units
+	Bit,
+	Byte,
+	// Dimensionless units
+	One,
+	Percent,
+	PerMille,
 }
 
 impl Unit {
+	/// All units apart from custom and compound units in the order of their declaration.
+	pub const ALL: [Unit; 52] = [
+		Unit::Ampere,
+		Unit::Candela,
+		Unit::Kelvin,
+		Unit::Kilogram,
+		Unit::Meter,
+		Unit::Mole,
+		Unit::Second,
+		Unit::Celsius,
+		Unit::Fahrenheit,
+		Unit::Gram,
+		Unit::Tonne,
+		Unit::Pound,
+		Unit::Ounce,
+		Unit::Stone,
+		Unit::AstronomicalUnit,
+		Unit::Lightyear,
+		Unit::Parsec,
+		Unit::Inch,
+		Unit::Foot,
+		Unit::Yard,
+		Unit::Mile,
+		Unit::Minute,
+		Unit::Hour,
+		Unit::Day,
+		Unit::Year,
+		Unit::Pascal,
+		Unit::Bar,
+		Unit::Atmosphere,
+		Unit::MillimeterMercury,
+		Unit::Torr,
+		Unit::Psi,
+		Unit::Sievert,
+		Unit::Gray,
+		Unit::Becquerel,
+		Unit::Lumen,
+		Unit::Lux,
+		Unit::Volt,
+		Unit::Watt,
+		Unit::Joule,
+		Unit::Electronvolt,
+		Unit::Calorie,
+		Unit::Coulomb,
+		Unit::Radian,
+		Unit::Degree,
+		Unit::Gradian,
+		Unit::Liter,
+		Unit::Hertz,
+		Unit::Bit,
+		Unit::Byte,
+		Unit::One,
+		Unit::Percent,
+		Unit::PerMille,
+	];
+
+	/// Units whose LaTeX command returned by `to_latex_sym()` is not provided by the `{siunitx}` package.
+	#[cfg( feature = "tex" )]
+	pub(crate) const LATEX_UNDECLARED: [Unit; 18] = [
+		Unit::Mole,
+		Unit::Fahrenheit,
+		Unit::Lightyear,
+		Unit::Parsec,
+		Unit::Inch,
+		Unit::Foot,
+		Unit::Yard,
+		Unit::Mile,
+		Unit::Pound,
+		Unit::Ounce,
+		Unit::Stone,
+		Unit::Year,
+		Unit::Atmosphere,
+		Unit::Torr,
+		Unit::Psi,
+		Unit::Calorie,
+		Unit::Gradian,
+		Unit::PerMille,
+	];
+
+	/// Returns an iterator over all units apart from custom and compound units in the order of their declaration.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::Unit;
+	/// assert_eq!( Unit::all().next(), Some( Unit::Ampere ) );
+	/// assert!( Unit::all().all( |x| x != Unit::Custom( "apple".to_string() ) ) );
+	/// ```
+	pub fn all() -> impl Iterator<Item = Unit> {
+		Self::ALL.into_iter()
+	}
+
+	/// Returns all units measuring the physical quantity `phys` in the order of their declaration. Custom and compound units are never returned.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{PhysicalQuantity, Unit};
+	/// assert_eq!( Unit::for_quantity( &PhysicalQuantity::Time ), vec![ Unit::Second, Unit::Minute, Unit::Hour, Unit::Day, Unit::Year ] );
+	/// assert!( Unit::for_quantity( &PhysicalQuantity::Custom ).is_empty() );
+	/// ```
+	pub fn for_quantity( phys: &PhysicalQuantity ) -> Vec<Unit> {
+		Self::all()
+			.filter( |x| x.phys() == *phys )
+			.collect()
+	}
+
+	/// Returns the `\DeclareSIUnit` command defining the LaTeX command of `self` or `None` if this command is already provided by the `{siunitx}` package.
+	#[cfg( feature = "tex" )]
+	pub(crate) fn latex_declaration( &self ) -> Option<String> {
+		let sym = match self {
+			Self::Mole =>       "mol",
+			Self::Fahrenheit => r"\degree F",
+			Self::Lightyear =>  "ly",
+			Self::Parsec =>     "pc",
+			Self::Inch =>       "in",
+			Self::Foot =>       "ft",
+			Self::Yard =>       "yd",
+			Self::Mile =>       "mi",
+			Self::Pound =>      "lb",
+			Self::Ounce =>      "oz",
+			Self::Stone =>      "st",
+			Self::Year =>       "a",
+			Self::Atmosphere => "atm",
+			Self::Torr =>       "Torr",
+			Self::Psi =>        "psi",
+			Self::Calorie =>    "cal",
+			Self::Gradian =>    "gon",
+			Self::PerMille =>   r"\text{\textperthousand}",
+			_ => return None,
+		};
+
+		Some( format!( r"\DeclareSIUnit{{{}}}{{{}}}", self.to_latex_sym( &TexOptions::none() ), sym ) )
+	}
+
+	/// Creates a `Unit` from its `{siunitx}` representation as returned by `to_latex_sym()`. Compound units using `\per`, `\squared`, `\cubed` and `\tothe{n}` are supported, prefix commands like `\kilo` are not.
+	///
+	/// This method is only available, if the **`tex`** feature has been enabled.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::Unit;
+	/// assert_eq!( Unit::from_latex_sym( r"\meter" ).unwrap(), Unit::Meter );
+	/// assert_eq!(
+	///     Unit::from_latex_sym( r"\meter\per\second\squared" ).unwrap(),
+	///     Unit::compound( vec![ ( Unit::Meter, 1 ), ( Unit::Second, -2 ) ] )
+	/// );
+	/// assert!( Unit::from_latex_sym( r"\kilo\meter" ).is_err() );
+	/// ```
+	#[cfg( feature = "tex" )]
+	pub fn from_latex_sym( s: &str ) -> Result<Self, UnitError> {
+		let err = || UnitError::ParseFailure( s.to_string() );
+
+		if s.trim().is_empty() {
+			return Ok( Self::One );
+		}
+
+		let mut parts: Vec<( Unit, i8 )> = Vec::new();
+		let mut per = false;
+		for cmd in s.trim().strip_prefix( '\\' ).ok_or_else( err )?.split( '\\' ) {
+			let power = match cmd {
+				"per" if !per => {
+					per = true;
+					continue;
+				},
+				"squared" => 2,
+				"cubed" => 3,
+				x if x.starts_with( "tothe{" ) && x.ends_with( '}' ) => x[6..x.len() - 1].parse::<i8>().map_err( |_| err() )?,
+				x => {
+					let cmd = format!( r"\{}", x );
+					let unit = Self::ALL.iter()
+						.find( |u| u.to_latex_sym( &TexOptions::none() ) == cmd )
+						.ok_or_else( err )?;
+					parts.push( ( unit.clone(), if per { -1 } else { 1 } ) );
+					per = false;
+					continue;
+				},
+			};
+
+			// Powers apply to the preceding unit.
+			match parts.last_mut() {
+				Some( ( _, exp ) ) if !per && exp.abs() == 1 => *exp *= power,
+				_ => return Err( err() ),
+			}
+		}
+
+		if parts.is_empty() || per {
+			return Err( err() );
+		}
+
+		Ok( Self::compound( parts ) )
+	}
+
+	/// Creates a compound unit from the units in `parts` raised to the respective exponent.
+	///
+	/// Nested compound units are flattened, identical units are merged by adding their exponents and units with an exponent of zero are dropped. If only a single unit with exponent 1 remains, this unit is returned instead of a compound unit.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::Unit;
+	/// let area = Unit::compound( vec![ ( Unit::Meter, 1 ), ( Unit::Meter, 1 ) ] );
+	/// assert_eq!( area, Unit::Compound( vec![ ( Unit::Meter, 2 ) ] ) );
+	/// assert_eq!( area.to_string_sym(), "m²" );
+	///
+	/// assert_eq!( Unit::compound( vec![ ( area, 1 ), ( Unit::Meter, -1 ) ] ), Unit::Meter );
+	/// ```
+	pub fn compound( parts: Vec<( Unit, i8 )> ) -> Self {
+		Self::combine( parts, false )
+	}
+
+	/// Creates the unit of the product of quantities measured in the units of `parts`.
+	///
+	/// In contrast to `compound()`, units representing the same physical quantity are also merged into the unit occurring first (e.g. t·kg becomes t²). This changes the factor of the unit, so the numeric value has to be calculated anew in the resulting unit.
+	pub(super) fn product( parts: Vec<( Unit, i8 )> ) -> Self {
+		Self::combine( parts, true )
+	}
+
+	fn combine( parts: Vec<( Unit, i8 )>, merge_phys: bool ) -> Self {
+		let mut flat = Vec::new();
+		for ( unit, exp ) in parts {
+			match unit {
+				Self::Compound( inner ) => flat.extend( inner.into_iter().map( |( u, e )| ( u, e * exp ) ) ),
+				// The unit one does not change a product.
+				Self::One => {},
+				_ => flat.push( ( unit, exp ) ),
+			}
+		}
+
+		let mut res: Vec<( Unit, i8 )> = Vec::new();
+		for ( unit, exp ) in flat {
+			let pos = res.iter().position( |( u, _ )| {
+				*u == unit || ( merge_phys && u.phys() != PhysicalQuantity::Custom && u.phys() == unit.phys() )
+			} );
+			match pos {
+				Some( idx ) => res[idx].1 += exp,
+				None => res.push( ( unit, exp ) ),
+			}
+		}
+		res.retain( |( _, e )| *e != 0 );
+
+		match res.as_slice() {
+			[] => Self::One,
+			[ ( unit, 1 ) ] => unit.clone(),
+			_ => Self::Compound( res ),
+		}
+	}
+
+	/// Returns the unit a prefix is applied to and the exponent the prefix is raised to. For compound units this is the leading unit, since a prefix applies to the first unit including its exponent (1 km² = 1 (km)² = 10⁶ m²).
+	pub(super) fn leading( &self ) -> ( &Unit, i8 ) {
+		match self {
+			Self::Compound( parts ) if !parts.is_empty() => ( &parts[0].0, parts[0].1 ),
+			_ => ( self, 1 ),
+		}
+	}
+
+	/// Returns `self` with the leading unit (see `leading()`) replaced by `unit`.
+	pub(super) fn with_leading( &self, unit: Unit ) -> Self {
+		match self {
+			Self::Compound( parts ) if !parts.is_empty() => {
+				let mut parts = parts.clone();
+				parts[0].0 = unit;
+				Self::Compound( parts )
+			},
+			_ => unit,
+		}
+	}
+
+	/// Returns the `PhysicalQuantity` that is measured by `self`. Units measuring the same physical quantity can be converted into each other.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Unit, PhysicalQuantity};
+	/// assert_eq!( Unit::Tonne.quantity(), PhysicalQuantity::Mass );
+	/// assert_eq!( Unit::Foot.quantity(), Unit::Meter.quantity() );
+	/// assert_ne!( Unit::Second.quantity(), Unit::Meter.quantity() );
+	/// ```
+	pub fn quantity( &self ) -> PhysicalQuantity {
+		self.phys()
+	}
+
 	/// Returns the `PhysicalQuantity` that is measured by `self`.
 	pub(super) fn phys( &self ) -> PhysicalQuantity {
 		match self {
 			Self::Custom( _ ) => PhysicalQuantity::Custom,
+			Self::Compound( parts ) => {
+				let mut dims: Vec<( PhysicalQuantity, i8 )> = Vec::new();
+				for ( unit, exp ) in parts {
+					for ( phys, e ) in unit.phys().dimensions() {
+						match dims.iter_mut().find( |( p, _ )| *p == phys ) {
+							Some( dim ) => dim.1 += e * exp,
+							None => dims.push( ( phys, e * exp ) ),
+						}
+					}
+				}
+
+				PhysicalQuantity::from_dimensions( dims )
+			},
 			// Base units
 			Self::Ampere =>    PhysicalQuantity::Current,
 			Self::Candela =>   PhysicalQuantity::LuminousIntensity,
-			Self::Kelvin =>    PhysicalQuantity::Temperature,
-			Self::Kilogram | Self::Gram | Self::Tonne => PhysicalQuantity::Mass,
+			Self::Kelvin | Self::Celsius | Self::Fahrenheit => PhysicalQuantity::Temperature,
+			Self::Kilogram |
+				Self::Gram |
+				Self::Tonne |
+				Self::Pound |
+				Self::Ounce |
+				Self::Stone => PhysicalQuantity::Mass,
 			Self::Meter |
 				Self::AstronomicalUnit |
 				Self::Lightyear |
-				Self::Parsec => PhysicalQuantity::Length,
+				Self::Parsec |
+				Self::Inch |
+				Self::Foot |
+				Self::Yard |
+				Self::Mile => PhysicalQuantity::Length,
 			Self::Mole =>      PhysicalQuantity::Amount,
-			Self::Second =>    PhysicalQuantity::Time,
-			Self::Pascal | Self::Bar => PhysicalQuantity::Pressure,
+			Self::Second |
+				Self::Minute |
+				Self::Hour |
+				Self::Day |
+				Self::Year => PhysicalQuantity::Time,
+			Self::Pascal |
+				Self::Bar |
+				Self::Atmosphere |
+				Self::MillimeterMercury |
+				Self::Torr |
+				Self::Psi => PhysicalQuantity::Pressure,
 			Self::Sievert =>   PhysicalQuantity::Radiation,
+			Self::Gray =>      PhysicalQuantity::AbsorbedDose,
+			Self::Becquerel => PhysicalQuantity::Radioactivity,
+			Self::Lumen =>     PhysicalQuantity::LuminousFlux,
+			Self::Lux =>       PhysicalQuantity::Illuminance,
+			// Electrical units
+			Self::Volt =>      PhysicalQuantity::Voltage,
+			Self::Watt =>      PhysicalQuantity::Power,
+			Self::Joule | Self::Electronvolt | Self::Calorie => PhysicalQuantity::Energy,
+			Self::Coulomb =>   PhysicalQuantity::Charge,
+			// Angle units
+			Self::Radian | Self::Degree | Self::Gradian => PhysicalQuantity::Angle,
+			// Volume units
+			Self::Liter =>     PhysicalQuantity::Volume,
+			// Frequency units
+			Self::Hertz =>     PhysicalQuantity::Frequency,
+			// Information units
+			Self::Bit | Self::Byte => PhysicalQuantity::Information,
+			// Dimensionless units
+			Self::One =>       PhysicalQuantity::Dimensionless,
+			Self::Percent =>   PhysicalQuantity::Dimensionless,
+			Self::PerMille =>  PhysicalQuantity::Dimensionless,
 		}
 	}
 
@@ -155,6 +687,9 @@ impl Unit {
 	pub(super) fn factor( &self ) -> f64 {
 		match self {
 			Self::Custom( _ ) => 1.0,
+			Self::Compound( parts ) => parts.iter()
+				.map( |( u, e )| u.factor().powi( *e as i32 ) )
+				.product(),
 			// Base units
 			Self::Ampere |
 				Self::Candela |
@@ -164,20 +699,76 @@ impl Unit {
 				Self::Mole |
 				Self::Second |
 				Self::Pascal |
-				Self::Sievert => 1.0,
+				Self::Sievert |
+				Self::Gray |
+				Self::Becquerel |
+				Self::Lumen |
+				Self::Lux |
+				Self::Volt |
+				Self::Watt |
+				Self::Joule |
+				Self::Coulomb |
+				Self::Radian |
+				Self::Hertz |
+				Self::Bit |
+				Self::One => 1.0,
+			Self::Byte => 8.0,
+			Self::Liter => 1e-3,
+			Self::Celsius => 1.0,
+			Self::Fahrenheit => 5.0 / 9.0,
 			Self::Gram => 1e-3,
 			Self::Tonne => 1e3,
+			Self::Pound => 0.45359237,
+			Self::Ounce => 0.028349523125,
+			Self::Stone => 6.35029318,
 			Self::AstronomicalUnit => 149_597_870_700.0,
 			Self::Lightyear => 9_460_730_472_580_800.0,
 			Self::Parsec => 30.85677581e15,
+			Self::Inch => 0.0254,
+			Self::Foot => 0.3048,
+			Self::Yard => 0.9144,
+			Self::Mile => 1609.344,
+			Self::Minute => 60.0,
+			Self::Hour => 3600.0,
+			Self::Day => 86400.0,
+			Self::Year => 31_557_600.0,
 			Self::Bar => 1e5,
+			Self::Atmosphere => 101_325.0,
+			Self::MillimeterMercury => 133.322387415,
+			Self::Torr => 101_325.0 / 760.0,
+			Self::Psi => 6894.757293168,
+			Self::Percent => 1e-2,
+			Self::PerMille => 1e-3,
+			Self::Degree => PI / 180.0,
+			Self::Gradian => PI / 200.0,
+			Self::Electronvolt => 1.602176634e-19,
+			Self::Calorie => 4.184,
+		}
+	}
+
+	/// Returns the offset (in terms of the base unit) that has to be added after multiplying with `factor()` to convert a value of this unit into the base unit.
+	///
+	/// This is only non-zero for affine units like the temperatures in degree Celsius or degree Fahrenheit, whose zero point differs from the zero point of the base unit.
+	pub(super) fn offset( &self ) -> f64 {
+		match self {
+			Self::Celsius => 273.15,
+			Self::Fahrenheit => 273.15 - 32.0 * 5.0 / 9.0,
+			_ => 0.0,
 		}
 	}
 
+	/// Returns `true` if the zero point of `self` differs from the zero point of its base unit, like for degree Celsius. Compound units are never affine, since they represent differences like temperature rates.
+	pub(super) fn is_affine( &self ) -> bool {
+		self.offset() != 0.0
+	}
+
 	/// Returns the base unit of the unit.
 	pub(super) fn base( &self ) -> Self {
 		match self {
 			Self::Custom( x ) => Self::Custom( x.clone() ),
+			Self::Compound( parts ) => Self::compound(
+				parts.iter().map( |( u, e )| ( u.base(), *e ) ).collect()
+			),
 			// Base units
 			Self::Ampere =>    Self::Ampere,
 			Self::Candela =>   Self::Candela,
@@ -187,12 +778,58 @@ impl Unit {
 			Self::Mole =>      Self::Mole,
 			Self::Second =>    Self::Second,
 			//
+			Self::Celsius | Self::Fahrenheit => Self::Kelvin,
 			Self::Gram | Self::Tonne => Self::Kilogram,
+			Self::Pound | Self::Ounce | Self::Stone => Self::Kilogram,
 			Self::AstronomicalUnit | Self::Lightyear | Self::Parsec => Self::Meter,
+			Self::Inch | Self::Foot | Self::Yard | Self::Mile => Self::Meter,
+			Self::Minute | Self::Hour | Self::Day | Self::Year => Self::Second,
 			//
 			Self::Pascal =>    Self::Pascal,
 			Self::Bar =>       Self::Pascal,
+			Self::Atmosphere => Self::Pascal,
+			Self::MillimeterMercury => Self::Pascal,
+			Self::Torr =>      Self::Pascal,
+			Self::Psi =>       Self::Pascal,
 			Self::Sievert =>   Self::Sievert,
+			Self::Gray =>      Self::Gray,
+			Self::Becquerel => Self::Becquerel,
+			Self::Lumen =>     Self::Lumen,
+			Self::Lux =>       Self::Lux,
+			// Electrical units
+			Self::Volt =>      Self::Volt,
+			Self::Watt =>      Self::Watt,
+			Self::Joule =>     Self::Joule,
+			Self::Electronvolt => Self::Joule,
+			Self::Calorie =>   Self::Joule,
+			Self::Coulomb =>   Self::Coulomb,
+			// Angle units
+			Self::Radian =>    Self::Radian,
+			Self::Degree =>    Self::Radian,
+			Self::Gradian =>   Self::Radian,
+			// Volume units
+			Self::Liter =>     Self::compound( vec![ ( Self::Meter, 3 ) ] ),
+			// Frequency units
+			Self::Hertz =>     Self::Hertz,
+			// Information units
+			Self::Bit =>       Self::Bit,
+			Self::Byte =>      Self::Bit,
+			// Dimensionless units
+			Self::One =>       Self::One,
+			Self::Percent =>   Self::One,
+			Self::PerMille =>  Self::One,
+		}
+	}
+
+	/// Returns the units of the same system of units that are interchangeable with `self`, ordered from the finest to the coarsest unit. Units without any relatives return only themselves.
+	pub(super) fn family( &self ) -> Vec<Self> {
+		match self {
+			Self::Gram | Self::Kilogram | Self::Tonne => vec![ Self::Gram, Self::Kilogram, Self::Tonne ],
+			Self::Ounce | Self::Pound | Self::Stone => vec![ Self::Ounce, Self::Pound, Self::Stone ],
+			Self::Inch | Self::Foot | Self::Yard | Self::Mile => vec![ Self::Inch, Self::Foot, Self::Yard, Self::Mile ],
+			Self::Second | Self::Minute | Self::Hour | Self::Day => vec![ Self::Second, Self::Minute, Self::Hour, Self::Day ],
+			Self::Pascal | Self::Bar => vec![ Self::Pascal, Self::Bar ],
+			_ => vec![ self.clone() ],
 		}
 	}
 
@@ -207,6 +844,10 @@ impl Unit {
 	pub fn to_string_sym( &self ) -> String {
 		let res = match self {
 			Self::Custom( x ) => x,
+			Self::Compound( parts ) => return parts.iter()
+				.map( |( u, e )| format!( "{}{}", u.to_string_sym(), superscript( *e ) ) )
+				.collect::<Vec<String>>()
+				.join( "·" ),
 			// Base units
 			Self::Ampere =>    "A",
 			Self::Candela =>   "cd",
@@ -215,43 +856,275 @@ impl Unit {
 			Self::Meter =>     "m",
 			Self::Mole =>      "mol",
 			Self::Second =>    "s",
+			// Additional temperature units
+			Self::Celsius =>   "°C",
+			Self::Fahrenheit => "°F",
 			// Additional mass units
 			Self::Gram =>      "g",
 			Self::Tonne =>     "t",
+			// Imperial mass units
+			Self::Pound =>     "lb",
+			Self::Ounce =>     "oz",
+			Self::Stone =>     "st",
 			// Additional length units
 			Self::AstronomicalUnit => "AU",
 			Self::Lightyear => "ly",
 			Self::Parsec =>    "pc",
+			// Imperial length units
+			Self::Inch =>      "in",
+			Self::Foot =>      "ft",
+			Self::Yard =>      "yd",
+			Self::Mile =>      "mi",
+			// Additional time units
+			Self::Minute =>    "min",
+			Self::Hour =>      "h",
+			Self::Day =>       "d",
+			Self::Year =>      "a",
 			//
 			Self::Pascal =>    "Pa",
 			Self::Bar =>       "bar",
+			Self::Atmosphere => "atm",
+			Self::MillimeterMercury => "mmHg",
+			Self::Torr =>      "Torr",
+			Self::Psi =>       "psi",
 			Self::Sievert =>   "Sv",
+			Self::Gray =>      "Gy",
+			Self::Becquerel => "Bq",
+			Self::Lumen =>     "lm",
+			Self::Lux =>       "lx",
+			// Electrical units
+			Self::Volt =>      "V",
+			Self::Watt =>      "W",
+			Self::Joule =>     "J",
+			Self::Electronvolt => "eV",
+			Self::Calorie =>   "cal",
+			Self::Coulomb =>   "C",
+			// Angle units
+			Self::Radian =>    "rad",
+			Self::Degree =>    "°",
+			Self::Gradian =>   "gon",
+			// Volume units
+			Self::Liter =>     "L",
+			// Frequency units
+			Self::Hertz =>     "Hz",
+			// Information units
+			Self::Bit =>       "bit",
+			Self::Byte =>      "B",
+			// Dimensionless units
+			Self::One =>       "",
+			Self::Percent =>   "%",
+			Self::PerMille =>  "‰",
 		};
 
 		res.to_string()
 	}
+
+	/// Returns whether a space has to be put between a number and the symbol of `self`.
+	///
+	/// Following the SI brochure, the symbol of a unit is separated from the number by a space. The plane angle in degree is the exception to this rule and is written directly after the number. Compound units follow the rule of their leading unit.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::Unit;
+	/// assert!( Unit::Celsius.space_before_symbol() );
+	/// assert!( !Unit::Degree.space_before_symbol() );
+	/// ```
+	pub fn space_before_symbol( &self ) -> bool {
+		match self {
+			Self::Compound( parts ) if !parts.is_empty() => parts[0].0.space_before_symbol(),
+			Self::Degree => false,
+			_ => true,
+		}
+	}
+
+	/// Parses `s` as the symbol of a unit like "kg" or "Pa". In contrast to `from_str()`, the comparison is case-sensitive, since e.g. "a" represents the year while "A" represents the ampere. Names of units are not accepted. If `s` is not a valid unit symbol, an `UnitError` will be returned.
+	///
+	/// Apart from the symbols returned by `to_string_sym()`, the common alternatives "l" (liter), "lbs" (pound), "yr" (year) and "deg" (degree) are accepted. Custom and compound units cannot be parsed.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::Unit;
+	/// assert_eq!( Unit::from_symbol( "Pa" ).unwrap(), Unit::Pascal );
+	/// assert_eq!( Unit::from_symbol( "a" ).unwrap(), Unit::Year );
+	/// assert_eq!( Unit::from_symbol( "A" ).unwrap(), Unit::Ampere );
+	/// assert!( Unit::from_symbol( "pa" ).is_err() );
+	/// assert!( Unit::from_symbol( "meter" ).is_err() );
+	/// ```
+	pub fn from_symbol( s: &str ) -> Result<Self, UnitError> {
+		let res = match s {
+			"l" => Self::Liter,
+			"lbs" => Self::Pound,
+			"yr" => Self::Year,
+			"deg" => Self::Degree,
+			// `Unit::One` does not have a symbol.
+			"" => return Err( UnitError::ParseFailure( s.to_string() ) ),
+			_ => return Self::all()
+				.find( |x| x.to_string_sym() == s )
+				.ok_or_else( || UnitError::ParseFailure( s.to_string() ) ),
+		};
+
+		Ok( res )
+	}
+
+	/// Returns the unit named `name`. The name is case-insensitive and might be given in plural or British spelling (see `from_str()`). Symbols are not accepted.
+	fn from_name( name: &str ) -> Option<Self> {
+		let res = match normalize_name( name ).as_str() {
+			"ampere" => Self::Ampere,
+			"candela" => Self::Candela,
+			"kelvin" => Self::Kelvin,
+			"kilogram" => Self::Kilogram,
+			"meter" => Self::Meter,
+			"mole" => Self::Mole,
+			"second" => Self::Second,
+			"celsius" | "degree celsius" => Self::Celsius,
+			"fahrenheit" | "degree fahrenheit" => Self::Fahrenheit,
+			"gram" => Self::Gram,
+			"tonne" => Self::Tonne,
+			"pound" => Self::Pound,
+			"ounce" => Self::Ounce,
+			"stone" => Self::Stone,
+			"astronomical unit" => Self::AstronomicalUnit,
+			"lightyear" => Self::Lightyear,
+			"parsec" => Self::Parsec,
+			"inch" => Self::Inch,
+			"foot" | "feet" => Self::Foot,
+			"yard" => Self::Yard,
+			"mile" => Self::Mile,
+			"minute" => Self::Minute,
+			"hour" => Self::Hour,
+			"day" => Self::Day,
+			"year" => Self::Year,
+			"pascal" => Self::Pascal,
+			"bar" => Self::Bar,
+			"atmosphere" => Self::Atmosphere,
+			"millimeter of mercury" => Self::MillimeterMercury,
+			"torr" => Self::Torr,
+			"pound per square inch" => Self::Psi,
+			"sievert" => Self::Sievert,
+			"gray" => Self::Gray,
+			"becquerel" => Self::Becquerel,
+			"lumen" => Self::Lumen,
+			"lux" => Self::Lux,
+			"volt" => Self::Volt,
+			"watt" => Self::Watt,
+			"joule" => Self::Joule,
+			"electronvolt" | "electron volt" => Self::Electronvolt,
+			"calorie" => Self::Calorie,
+			"coulomb" => Self::Coulomb,
+			"radian" => Self::Radian,
+			"degree" => Self::Degree,
+			"gradian" => Self::Gradian,
+			"liter" => Self::Liter,
+			"hertz" => Self::Hertz,
+			"bit" => Self::Bit,
+			"byte" => Self::Byte,
+			"one" => Self::One,
+			"percent" => Self::Percent,
+			"per mille" | "permille" => Self::PerMille,
+			_ => return None,
+		};
+
+		Some( res )
+	}
+
+	/// Parses `s` as a unit that might be preceded by a prefix symbol (like "km" or "mA") and returns the prefix alongside the unit. `Prefix::Nothing` is returned for units without a prefix.
+	///
+	/// Symbols are compared case-sensitively (see `Unit::from_symbol()`), so "pA" is a pico-ampere and not the pascal. Ambiguous strings are resolved with the following precedence:
+	///
+	/// 1. A unit symbol matching `s` as a whole, so "m" is the meter, "mol" the mole, "kg" the kilogram and "pc" the parsec (not a pico-coulomb).
+	/// 2. A one-character prefix symbol followed by a unit symbol, so "mm" is the millimeter.
+	/// 3. The two-character prefix symbol "da" followed by a unit symbol, so "dam" is the decameter.
+	/// 4. The case-insensitive name of a unit without any prefix, like "meters" (see `Unit::from_str()`).
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Unit, Prefix};
+	/// assert_eq!( Unit::parse_with_prefix( "km" ).unwrap(), ( Prefix::Kilo, Unit::Meter ) );
+	/// assert_eq!( Unit::parse_with_prefix( "m" ).unwrap(), ( Prefix::Nothing, Unit::Meter ) );
+	/// assert_eq!( Unit::parse_with_prefix( "kg" ).unwrap(), ( Prefix::Nothing, Unit::Kilogram ) );
+	/// assert_eq!( Unit::parse_with_prefix( "pA" ).unwrap(), ( Prefix::Pico, Unit::Ampere ) );
+	/// assert_eq!( Unit::parse_with_prefix( "Meters" ).unwrap(), ( Prefix::Nothing, Unit::Meter ) );
+	/// assert!( Unit::parse_with_prefix( "kxyz" ).is_err() );
+	/// ```
+	pub fn parse_with_prefix( s: &str ) -> Result<( Prefix, Self ), UnitError> {
+		if let Ok( unit ) = Self::from_symbol( s ) {
+			return Ok( ( Prefix::Nothing, unit ) );
+		}
+
+		// Prefix symbols consist of one ("k", "µ") or two ("da") characters.
+		for ( idx, _ ) in s.char_indices().skip( 1 ).take( 2 ) {
+			let ( pre, rest ) = s.split_at( idx );
+			if let ( Ok( prefix ), Ok( unit ) ) = ( Prefix::from_symbol( pre ), Self::from_symbol( rest ) ) {
+				return Ok( ( prefix, unit ) );
+			}
+		}
+
+		Self::from_name( s )
+			.map( |x| ( Prefix::Nothing, x ) )
+			.ok_or_else( || UnitError::ParseFailure( s.to_string() ) )
+	}
 }
 
 impl FromStr for Unit {
 	type Err = UnitError;
 
+	/// Parses a unit from its name or its symbol. The name is case-insensitive and might be given in plural or British spelling, like "Metres" or "seconds". The symbol is case-insensitive as well. Use `Unit::from_symbol()` to tell apart symbols differing only in case.
 	fn from_str( s: &str ) -> Result<Self, Self::Err> {
-		let result = match s.to_lowercase().as_str() {
-			"ampere" | "a" => Self::Ampere,
-			"candela" | "cd" => Self::Candela,
-			"kelvin" | "k" => Self::Kelvin,
-			"kilogram" | "kg" => Self::Kilogram,
-			"meter" | "m" => Self::Meter,
-			"mole" | "mol" => Self::Mole,
-			"second" | "s" => Self::Second,
-			"gram" | "g" => Self::Gram,
-			"tonne" | "t" => Self::Tonne,
-			"astronomical unit" | "au" => Self::AstronomicalUnit,
-			"lightyear" | "ly" => Self::Lightyear,
-			"parsec" | "pc" => Self::Parsec,
-			"pascal" | "pa" => Self::Pascal,
-			"bar" => Self::Bar,
-			"sievert" | "sv" => Self::Sievert,
+		if let Some( x ) = Self::from_name( s ) {
+			return Ok( x );
+		}
+
+		let result = match normalize_name( s ).as_str() {
+			"a" => Self::Ampere,
+			"cd" => Self::Candela,
+			"k" => Self::Kelvin,
+			"kg" => Self::Kilogram,
+			"m" => Self::Meter,
+			"mol" => Self::Mole,
+			"s" => Self::Second,
+			"°c" => Self::Celsius,
+			"°f" => Self::Fahrenheit,
+			"g" => Self::Gram,
+			"t" => Self::Tonne,
+			"lb" | "lbs" => Self::Pound,
+			"oz" => Self::Ounce,
+			"st" => Self::Stone,
+			"au" => Self::AstronomicalUnit,
+			"ly" => Self::Lightyear,
+			"pc" => Self::Parsec,
+			"in" => Self::Inch,
+			"ft" => Self::Foot,
+			"yd" => Self::Yard,
+			"mi" => Self::Mile,
+			"min" => Self::Minute,
+			"h" => Self::Hour,
+			"d" => Self::Day,
+			// The symbol "a" is taken by the ampere, since parsing is case-insensitive.
+			"yr" => Self::Year,
+			"pa" => Self::Pascal,
+			"atm" => Self::Atmosphere,
+			"mmhg" => Self::MillimeterMercury,
+			"psi" => Self::Psi,
+			"sv" => Self::Sievert,
+			"gy" => Self::Gray,
+			"bq" => Self::Becquerel,
+			"lm" => Self::Lumen,
+			"lx" => Self::Lux,
+			"v" => Self::Volt,
+			"w" => Self::Watt,
+			"j" => Self::Joule,
+			"ev" => Self::Electronvolt,
+			"cal" => Self::Calorie,
+			"c" => Self::Coulomb,
+			"rad" => Self::Radian,
+			"deg" | "°" => Self::Degree,
+			"gon" => Self::Gradian,
+			"l" => Self::Liter,
+			"hz" => Self::Hertz,
+			// The symbol "b" is taken by the byte, since parsing is case-insensitive.
+			"b" => Self::Byte,
+			"%" => Self::Percent,
+			"‰" => Self::PerMille,
 			_ => return Err( UnitError::ParseFailure( s.to_string() ) ),
 		};
 
@@ -259,10 +1132,42 @@ impl FromStr for Unit {
 	}
 }
 
+impl TryFrom<&str> for Unit {
+	type Error = UnitError;
+
+	/// Parses a unit like `Unit::from_str()`. If `value` is not a valid unit, the returned error lists the names and symbols of units differing in at most two characters as suggestions (`UnitError::ParseFailureSuggest`). Without any suggestion, `UnitError::ParseFailure` is returned.
+	///
+	/// # Example
+	/// ```
+	/// # use sinum::{Unit, UnitError};
+	/// assert_eq!( Unit::try_from( "metres" ).unwrap(), Unit::Meter );
+	///
+	/// match Unit::try_from( "ampre" ) {
+	///     Err( UnitError::ParseFailureSuggest { suggestions, .. } ) => assert_eq!( suggestions, vec![ "ampere" ] ),
+	///     _ => panic!( "Expected suggestions" ),
+	/// }
+	/// ```
+	fn try_from( value: &str ) -> Result<Self, Self::Error> {
+		Self::from_str( value ).map_err( |_| {
+			let suggestions = suggestions( value );
+			if suggestions.is_empty() {
+				UnitError::ParseFailure( value.to_string() )
+			} else {
+				UnitError::ParseFailureSuggest { input: value.to_string(), suggestions }
+			}
+		} )
+	}
+}
+
 impl fmt::Display for Unit {
 	fn fmt( &self, f: &mut fmt::Formatter ) -> fmt::Result {
 		match self {
 			Self::Custom( x ) => write!( f, "{}", x ),
+			Self::Compound( parts ) => write!( f, "{}", parts.iter()
+				.map( |( u, e )| format!( "{}{}", u, superscript( *e ) ) )
+				.collect::<Vec<String>>()
+				.join( "·" )
+			),
 			// Base units
 			Self::Ampere =>    write!( f, "ampere" ),
 			Self::Candela =>   write!( f, "candela" ),
@@ -271,17 +1176,64 @@ impl fmt::Display for Unit {
 			Self::Meter =>     write!( f, "meter" ),
 			Self::Mole =>      write!( f, "mol" ),
 			Self::Second =>    write!( f, "second" ),
+			// Additional temperature units
+			Self::Celsius =>   write!( f, "degree Celsius" ),
+			Self::Fahrenheit => write!( f, "degree Fahrenheit" ),
 			// Additional mass units
 			Self::Gram =>      write!( f, "gram" ),
 			Self::Tonne =>     write!( f, "tonne" ),
+			// Imperial mass units
+			Self::Pound =>     write!( f, "pound" ),
+			Self::Ounce =>     write!( f, "ounce" ),
+			Self::Stone =>     write!( f, "stone" ),
 			// Additional length units
 			Self::AstronomicalUnit => write!( f, "astronomical unit" ),
 			Self::Lightyear => write!( f, "lightyear" ),
 			Self::Parsec =>    write!( f, "parsec" ),
+			// Imperial length units
+			Self::Inch =>      write!( f, "inch" ),
+			Self::Foot =>      write!( f, "foot" ),
+			Self::Yard =>      write!( f, "yard" ),
+			Self::Mile =>      write!( f, "mile" ),
+			// Additional time units
+			Self::Minute =>    write!( f, "minute" ),
+			Self::Hour =>      write!( f, "hour" ),
+			Self::Day =>       write!( f, "day" ),
+			Self::Year =>      write!( f, "year" ),
 			//
 			Self::Pascal =>    write!( f, "pascal" ),
 			Self::Bar =>       write!( f, "bar" ),
+			Self::Atmosphere => write!( f, "atmosphere" ),
+			Self::MillimeterMercury => write!( f, "millimeter of mercury" ),
+			Self::Torr =>      write!( f, "torr" ),
+			Self::Psi =>       write!( f, "pound per square inch" ),
 			Self::Sievert =>   write!( f, "sievert" ),
+			Self::Gray =>      write!( f, "gray" ),
+			Self::Becquerel => write!( f, "becquerel" ),
+			Self::Lumen =>     write!( f, "lumen" ),
+			Self::Lux =>       write!( f, "lux" ),
+			// Electrical units
+			Self::Volt =>      write!( f, "volt" ),
+			Self::Watt =>      write!( f, "watt" ),
+			Self::Joule =>     write!( f, "joule" ),
+			Self::Electronvolt => write!( f, "electronvolt" ),
+			Self::Calorie =>   write!( f, "calorie" ),
+			Self::Coulomb =>   write!( f, "coulomb" ),
+			// Angle units
+			Self::Radian =>    write!( f, "radian" ),
+			Self::Degree =>    write!( f, "degree" ),
+			Self::Gradian =>   write!( f, "gradian" ),
+			// Volume units
+			Self::Liter =>     write!( f, "liter" ),
+			// Frequency units
+			Self::Hertz =>     write!( f, "hertz" ),
+			// Information units
+			Self::Bit =>       write!( f, "bit" ),
+			Self::Byte =>      write!( f, "byte" ),
+			// Dimensionless units
+			Self::One =>       write!( f, "one" ),
+			Self::Percent =>   write!( f, "percent" ),
+			Self::PerMille =>  write!( f, "per mille" ),
 		}
 	}
 }
@@ -318,17 +1270,64 @@ impl DisplayLocale for Unit {
 			Self::Meter =>     LOCALES.lookup( locale, "meter" ),
 			Self::Mole =>      LOCALES.lookup( locale, "mol" ),
 			Self::Second =>    LOCALES.lookup( locale, "second" ),
+			// Additional temperature units
+			Self::Celsius =>   LOCALES.lookup( locale, "celsius" ),
+			Self::Fahrenheit => LOCALES.lookup( locale, "fahrenheit" ),
 			// Additional mass units
 			Self::Gram =>      LOCALES.lookup( locale, "gram" ),
 			Self::Tonne =>     LOCALES.lookup( locale, "tonne" ),
+			// Imperial mass units
+			Self::Pound =>     LOCALES.lookup( locale, "pound" ),
+			Self::Ounce =>     LOCALES.lookup( locale, "ounce" ),
+			Self::Stone =>     LOCALES.lookup( locale, "stone" ),
 			// Additional length units
 			Self::AstronomicalUnit => LOCALES.lookup( locale, "astronomical_unit" ),
 			Self::Lightyear => LOCALES.lookup( locale, "lightyear" ),
 			Self::Parsec =>    LOCALES.lookup( locale, "parsec" ),
+			// Imperial length units
+			Self::Inch =>      LOCALES.lookup( locale, "inch" ),
+			Self::Foot =>      LOCALES.lookup( locale, "foot" ),
+			Self::Yard =>      LOCALES.lookup( locale, "yard" ),
+			Self::Mile =>      LOCALES.lookup( locale, "mile" ),
+			// Additional time units
+			Self::Minute =>    LOCALES.lookup( locale, "minute" ),
+			Self::Hour =>      LOCALES.lookup( locale, "hour" ),
+			Self::Day =>       LOCALES.lookup( locale, "day" ),
+			Self::Year =>      LOCALES.lookup( locale, "year" ),
 			//
 			Self::Pascal =>    LOCALES.lookup( locale, "pascal" ),
 			Self::Bar =>       LOCALES.lookup( locale, "bar" ),
+			Self::Atmosphere => LOCALES.lookup( locale, "atmosphere" ),
+			Self::MillimeterMercury => LOCALES.lookup( locale, "millimeter_mercury" ),
+			Self::Torr =>      LOCALES.lookup( locale, "torr" ),
+			Self::Psi =>       LOCALES.lookup( locale, "psi" ),
 			Self::Sievert =>   LOCALES.lookup( locale, "sievert" ),
+			Self::Gray =>      LOCALES.lookup( locale, "gray" ),
+			Self::Becquerel => LOCALES.lookup( locale, "becquerel" ),
+			Self::Lumen =>     LOCALES.lookup( locale, "lumen" ),
+			Self::Lux =>       LOCALES.lookup( locale, "lux" ),
+			// Electrical units
+			Self::Volt =>      LOCALES.lookup( locale, "volt" ),
+			Self::Watt =>      LOCALES.lookup( locale, "watt" ),
+			Self::Joule =>     LOCALES.lookup( locale, "joule" ),
+			Self::Electronvolt => LOCALES.lookup( locale, "electronvolt" ),
+			Self::Calorie =>   LOCALES.lookup( locale, "calorie" ),
+			Self::Coulomb =>   LOCALES.lookup( locale, "coulomb" ),
+			// Angle units
+			Self::Radian =>    LOCALES.lookup( locale, "radian" ),
+			Self::Degree =>    LOCALES.lookup( locale, "degree" ),
+			Self::Gradian =>   LOCALES.lookup( locale, "gradian" ),
+			// Volume units
+			Self::Liter =>     LOCALES.lookup( locale, "liter" ),
+			// Frequency units
+			Self::Hertz =>     LOCALES.lookup( locale, "hertz" ),
+			// Information units
+			Self::Bit =>       LOCALES.lookup( locale, "bit" ),
+			Self::Byte =>      LOCALES.lookup( locale, "byte" ),
+			// Dimensionless units
+			Self::One =>       LOCALES.lookup( locale, "one" ),
+			Self::Percent =>   LOCALES.lookup( locale, "percent" ),
+			Self::PerMille =>  LOCALES.lookup( locale, "per_mille" ),
 			//
 			_ => self.to_string(),
 		}
@@ -339,7 +1338,24 @@ impl DisplayLocale for Unit {
 impl Latex for Unit {}
 
 #[cfg( all( feature = "i18n", feature = "tex" ) )]
-impl LatexLocale for Unit {}
+impl LatexLocale for Unit {
+	/// Return a string that represents this `Unit` as translated word in LaTeX text mode. A unit with an empty name results in an empty string instead of an empty `\text{}`.
+	///
+	/// # Example
+	/// ```
+	/// # use unic_langid::langid;
+	/// # use sinum::{Unit, LatexLocale, TexOptions};
+	/// assert_eq!( Unit::Meter.to_latex_locale( &langid!( "de-DE" ), &TexOptions::new() ), r"\text{Meter}" );
+	/// assert_eq!( Unit::AstronomicalUnit.to_latex_locale( &langid!( "de-DE" ), &TexOptions::new() ), r"\text{Astronomische Einheit}" );
+	/// assert_eq!( Unit::Second.to_latex_locale( &langid!( "en-US" ), &TexOptions::new() ), r"\text{second}" );
+	/// ```
+	fn to_latex_locale( &self, locale: &LanguageIdentifier, _options: &TexOptions ) -> String {
+		match self.to_string_locale( locale ) {
+			x if x.is_empty() => x,
+			x => format!( r"\text{{{}}}", x ),
+		}
+	}
+}
 
 #[cfg( feature = "tex" )]
 impl LatexSym for Unit {
@@ -355,6 +1371,14 @@ impl LatexSym for Unit {
 	fn to_latex_sym( &self, _options: &TexOptions ) -> String {
 		match self {
 			Self::Custom( x ) => x.clone(),
+			Self::Compound( parts ) => parts.iter()
+				.enumerate()
+				.map( |( i, ( u, e ) )| match e {
+					// A prefix has to be placed in front of the first unit, so this unit never uses `\per`.
+					x if *x < 0 && i > 0 => format!( r"\per{}{}", u.to_latex_sym( _options ), latex_power( -x ) ),
+					x => format!( "{}{}", u.to_latex_sym( _options ), latex_power( *x ) ),
+				} )
+				.collect(),
 			// Base units
 			Self::Ampere =>    r"\ampere".to_string(),
 			Self::Candela =>   r"\candela".to_string(),
@@ -363,17 +1387,64 @@ impl LatexSym for Unit {
 			Self::Meter =>     r"\meter".to_string(),
 			Self::Mole =>      r"\mol".to_string(),
 			Self::Second =>    r"\second".to_string(),
+			// Additional temperature units
+			Self::Celsius =>   r"\degreeCelsius".to_string(),
+			Self::Fahrenheit => r"\fahrenheit".to_string(),
 			// Additional mass units
 			Self::Gram =>      r"\gram".to_string(),
 			Self::Tonne =>     r"\tonne".to_string(),
+			// Imperial mass units
+			Self::Pound =>     r"\pound".to_string(),
+			Self::Ounce =>     r"\ounce".to_string(),
+			Self::Stone =>     r"\stone".to_string(),
 			// Additional length units
 			Self::AstronomicalUnit => r"\astronomicalunit".to_string(),
 			Self::Lightyear => r"\lightyear".to_string(),
 			Self::Parsec =>    r"\parsec".to_string(),
+			// Imperial length units
+			Self::Inch =>      r"\inch".to_string(),
+			Self::Foot =>      r"\foot".to_string(),
+			Self::Yard =>      r"\yard".to_string(),
+			Self::Mile =>      r"\mile".to_string(),
+			// Additional time units
+			Self::Minute =>    r"\minute".to_string(),
+			Self::Hour =>      r"\hour".to_string(),
+			Self::Day =>       r"\day".to_string(),
+			Self::Year =>      r"\year".to_string(),
 			//
 			Self::Pascal =>    r"\pascal".to_string(),
 			Self::Bar =>       r"\bar".to_string(),
+			Self::Atmosphere => r"\atmosphere".to_string(),
+			Self::MillimeterMercury => r"\mmHg".to_string(),
+			Self::Torr =>      r"\torr".to_string(),
+			Self::Psi =>       r"\psi".to_string(),
 			Self::Sievert =>   r"\sievert".to_string(),
+			Self::Gray =>      r"\gray".to_string(),
+			Self::Becquerel => r"\becquerel".to_string(),
+			Self::Lumen =>     r"\lumen".to_string(),
+			Self::Lux =>       r"\lux".to_string(),
+			// Electrical units
+			Self::Volt =>      r"\volt".to_string(),
+			Self::Watt =>      r"\watt".to_string(),
+			Self::Joule =>     r"\joule".to_string(),
+			Self::Electronvolt => r"\electronvolt".to_string(),
+			Self::Calorie =>   r"\calorie".to_string(),
+			Self::Coulomb =>   r"\coulomb".to_string(),
+			// Angle units
+			Self::Radian =>    r"\radian".to_string(),
+			Self::Degree =>    r"\degree".to_string(),
+			Self::Gradian =>   r"\gon".to_string(),
+			// Volume units
+			Self::Liter =>     r"\litre".to_string(),
+			// Frequency units
+			Self::Hertz =>     r"\hertz".to_string(),
+			// Information units
+			Self::Bit =>       r"\bit".to_string(),
+			Self::Byte =>      r"\byte".to_string(),
+			// Dimensionless units
+			Self::One =>       "".to_string(),
+			Self::Percent =>   r"\percent".to_string(),
+			Self::PerMille =>  r"\permille".to_string(),
 		}
 	}
 }
@@ -381,6 +1452,110 @@ impl LatexSym for Unit {
 
 
 
+//=============================================================================
+// Functions
+
+
+/// Returns the unit name `name` normalized for parsing: lowercase, single spaces between words, American spelling ("metre" → "meter") and singular ("seconds" → "second", "inches" → "inch").
+fn normalize_name( name: &str ) -> String {
+	// Words ending in "s" without being a plural.
+	const SINGULAR_S: [&str; 1] = [ "celsius" ];
+
+	name.split_whitespace()
+		.map( |word| {
+			let word = word.to_lowercase();
+			let word = match word.strip_suffix( "s" ) {
+				Some( x ) if x.ends_with( "che" ) || x.ends_with( "she" ) => x[..x.len() - 1].to_string(),
+				Some( x ) if word.chars().count() > 3 && !SINGULAR_S.contains( &word.as_str() ) => x.to_string(),
+				_ => word,
+			};
+			match word.strip_suffix( "tre" ) {
+				Some( x ) => format!( "{}ter", x ),
+				None => word,
+			}
+		} )
+		.collect::<Vec<String>>()
+		.join( " " )
+}
+
+
+/// Returns the Levenshtein distance between `a` and `b`: the number of single character insertions, deletions or substitutions required to change `a` into `b`.
+fn levenshtein( a: &str, b: &str ) -> usize {
+	let b: Vec<char> = b.chars().collect();
+	let mut row: Vec<usize> = ( 0..=b.len() ).collect();
+
+	for ( i, ca ) in a.chars().enumerate() {
+		let mut diag = row[0];
+		row[0] = i + 1;
+		for ( j, cb ) in b.iter().enumerate() {
+			let above = row[j + 1];
+			row[j + 1] = ( above + 1 ).min( row[j] + 1 ).min( diag + usize::from( ca != *cb ) );
+			diag = above;
+		}
+	}
+
+	row[b.len()]
+}
+
+
+/// Returns the names and symbols of the units close to `input`, i.e. differing in at most two characters, ordered by their distance. Candidates are only suggested if they are not entirely replaced, so short symbols like "m" are not suggested for every short input.
+fn suggestions( input: &str ) -> Vec<String> {
+	let input = normalize_name( input );
+	let mut found: Vec<( usize, String )> = Vec::new();
+
+	for unit in Unit::ALL {
+		for candidate in [ unit.to_string(), unit.to_string_sym() ] {
+			let dist = levenshtein( &input, &candidate.to_lowercase() );
+			if dist <= 2 && dist < candidate.chars().count() && !found.iter().any( |( _, x )| *x == candidate ) {
+				found.push( ( dist, candidate ) );
+			}
+		}
+	}
+
+	found.sort_by_key( |( dist, _ )| *dist );
+	found.into_iter().map( |( _, x )| x ).collect()
+}
+
+
+/// Returns `exp` as superscript string as used in unit symbols. An exponent of 1 results in an empty string.
+fn superscript( exp: i8 ) -> String {
+	if exp == 1 {
+		return "".to_string();
+	}
+
+	exp.to_string()
+		.chars()
+		.map( |c| match c {
+			'-' => '⁻',
+			'0' => '⁰',
+			'1' => '¹',
+			'2' => '²',
+			'3' => '³',
+			'4' => '⁴',
+			'5' => '⁵',
+			'6' => '⁶',
+			'7' => '⁷',
+			'8' => '⁸',
+			_ => '⁹',
+		} )
+		.collect()
+}
+
+
+/// Returns the `{siunitx}` command raising a unit to the power of `exp`. An exponent of 1 results in an empty string.
+#[cfg( feature = "tex" )]
+fn latex_power( exp: i8 ) -> String {
+	match exp {
+		1 => "".to_string(),
+		2 => r"\squared".to_string(),
+		3 => r"\cubed".to_string(),
+		x => format!( r"\tothe{{{}}}", x ),
+	}
+}
+
+
+
+
 //=============================================================================
 // Testing
 
@@ -396,6 +1571,15 @@ mod tests {
 		assert_eq!( Unit::Tonne.factor(), 1e3 );
 	}
 
+	#[test]
+	fn unit_offset_to_base() {
+		assert_eq!( Unit::Kelvin.offset(), 0.0 );
+		assert_eq!( Unit::Celsius.offset(), 273.15 );
+		assert!( ( Unit::Fahrenheit.offset() - 255.372_222_222 ).abs() < 1e-9 );
+		assert_eq!( Unit::Celsius.base(), Unit::Kelvin );
+		assert_eq!( Unit::Fahrenheit.base(), Unit::Kelvin );
+	}
+
 	#[test]
 	fn unit_base() {
 		assert_eq!( Unit::Ampere.base(), Unit::Ampere );
@@ -410,4 +1594,470 @@ mod tests {
 		assert_eq!( Unit::Candela.to_string(), "candela".to_string() );
 		assert_eq!( Unit::Candela.to_string_sym(), "cd".to_string() );
 	}
+
+	#[test]
+	fn unit_parse_plural() {
+		assert_eq!( Unit::from_str( "meters" ).unwrap(), Unit::Meter );
+		assert_eq!( Unit::from_str( "Metres" ).unwrap(), Unit::Meter );
+		assert_eq!( Unit::from_str( "metre" ).unwrap(), Unit::Meter );
+		assert_eq!( Unit::from_str( "seconds" ).unwrap(), Unit::Second );
+		assert_eq!( Unit::from_str( "grams" ).unwrap(), Unit::Gram );
+		assert_eq!( Unit::from_str( "degrees  Celsius" ).unwrap(), Unit::Celsius );
+		assert_eq!( Unit::from_str( "celsius" ).unwrap(), Unit::Celsius );
+		assert_eq!( Unit::from_str( "s" ).unwrap(), Unit::Second );
+		assert!( Unit::from_str( "ms" ).is_err() );
+		assert_eq!( "2 seconds".parse::<crate::Qty>().unwrap(), crate::Qty::new( 2.0.into(), &Unit::Second ) );
+		assert_eq!( "9.9 meters".parse::<crate::Qty>().unwrap(), crate::Qty::new( 9.9.into(), &Unit::Meter ) );
+	}
+
+	#[test]
+	fn imperial_length_units() {
+		assert_eq!( Unit::Inch.factor(), 0.0254 );
+		assert_eq!( Unit::Mile.base(), Unit::Meter );
+		assert_eq!( Unit::Foot.to_string_sym(), "ft" );
+		assert_eq!( Unit::Yard.to_string(), "yard" );
+		assert_eq!( Unit::from_str( "inches" ).unwrap(), Unit::Inch );
+		assert_eq!( Unit::from_str( "feet" ).unwrap(), Unit::Foot );
+		assert_eq!( Unit::from_str( "mi" ).unwrap(), Unit::Mile );
+	}
+
+	#[test]
+	fn imperial_mass_units() {
+		assert_eq!( Unit::Pound.factor(), 0.45359237 );
+		assert_eq!( Unit::Stone.base(), Unit::Kilogram );
+		assert_eq!( Unit::Ounce.to_string_sym(), "oz" );
+		assert_eq!( Unit::from_str( "lbs" ).unwrap(), Unit::Pound );
+		assert_eq!( Unit::from_str( "ounces" ).unwrap(), Unit::Ounce );
+	}
+
+	#[test]
+	fn unit_quantity() {
+		assert_eq!( Unit::Tonne.quantity(), Unit::Gram.quantity() );
+		assert_eq!( Unit::Tonne.quantity(), PhysicalQuantity::Mass );
+		assert_eq!( Unit::Custom( "apple".to_string() ).quantity(), PhysicalQuantity::Custom );
+		assert_eq!( Unit::compound( vec![ ( Unit::Meter, 3 ) ] ).quantity(), Unit::Liter.quantity() );
+	}
+
+	#[test]
+	fn unit_all() {
+		assert_eq!( Unit::all().count(), Unit::ALL.len() );
+		assert!( Unit::all().all( |x| !matches!( x, Unit::Custom( _ ) | Unit::Compound( _ ) ) ) );
+		assert_eq!(
+			Unit::for_quantity( &PhysicalQuantity::Mass ),
+			vec![ Unit::Kilogram, Unit::Gram, Unit::Tonne, Unit::Pound, Unit::Ounce, Unit::Stone ]
+		);
+		assert_eq!( Unit::for_quantity( &PhysicalQuantity::Current ), vec![ Unit::Ampere ] );
+		assert!( Unit::for_quantity( &PhysicalQuantity::Compound( vec![ ( PhysicalQuantity::Length, 2 ) ] ) ).is_empty() );
+	}
+
+	#[test]
+	fn time_units() {
+		assert_eq!( Unit::Hour.factor(), 3600.0 );
+		assert_eq!( Unit::Year.factor(), 31_557_600.0 );
+		assert_eq!( Unit::Day.base(), Unit::Second );
+		assert_eq!( Unit::Minute.to_string_sym(), "min" );
+		assert_eq!( Unit::from_str( "hours" ).unwrap(), Unit::Hour );
+		assert_eq!( Unit::from_str( "min" ).unwrap(), Unit::Minute );
+		assert_eq!( Unit::from_str( "a" ).unwrap(), Unit::Ampere );
+	}
+
+	#[test]
+	fn volume_units() {
+		assert_eq!( Unit::Liter.factor(), 1e-3 );
+		assert_eq!( Unit::Liter.base(), Unit::compound( vec![ ( Unit::Meter, 3 ) ] ) );
+		assert_eq!( Unit::Liter.phys(), PhysicalQuantity::Volume );
+		assert_eq!( Unit::compound( vec![ ( Unit::Meter, 3 ) ] ).phys(), PhysicalQuantity::Volume );
+		assert_eq!(
+			Unit::compound( vec![ ( Unit::Liter, 1 ), ( Unit::Second, -1 ) ] ).phys(),
+			Unit::compound( vec![ ( Unit::Meter, 3 ), ( Unit::Second, -1 ) ] ).phys()
+		);
+		assert_eq!( Unit::Liter.to_string_sym(), "L" );
+		assert_eq!( Unit::from_str( "litres" ).unwrap(), Unit::Liter );
+		assert_eq!( Unit::from_str( "l" ).unwrap(), Unit::Liter );
+	}
+
+	#[test]
+	fn phys_dimensions() {
+		for phys in [
+			PhysicalQuantity::Pressure,
+			PhysicalQuantity::Radiation,
+			PhysicalQuantity::Voltage,
+			PhysicalQuantity::Power,
+			PhysicalQuantity::Energy,
+			PhysicalQuantity::Charge,
+			PhysicalQuantity::Volume,
+			PhysicalQuantity::Frequency,
+			PhysicalQuantity::Length,
+		] {
+			assert_eq!( PhysicalQuantity::from_dimensions( phys.dimensions() ), phys );
+		}
+
+		let newton_meter = Unit::compound( vec![ ( Unit::Kilogram, 1 ), ( Unit::Meter, 2 ), ( Unit::Second, -2 ) ] );
+		assert_eq!( newton_meter.phys(), PhysicalQuantity::Energy );
+		assert_eq!( Unit::compound( vec![ ( Unit::Joule, 1 ), ( Unit::Second, -1 ) ] ).phys(), PhysicalQuantity::Power );
+		assert_eq!( Unit::compound( vec![ ( Unit::Watt, 1 ), ( Unit::Ampere, -1 ) ] ).phys(), PhysicalQuantity::Voltage );
+	}
+
+	#[test]
+	fn pressure_units() {
+		assert_eq!( Unit::Atmosphere.factor(), 101_325.0 );
+		assert_eq!( Unit::Torr.base(), Unit::Pascal );
+		assert_eq!( Unit::for_quantity( &PhysicalQuantity::Pressure ), vec![ Unit::Pascal, Unit::Bar, Unit::Atmosphere, Unit::MillimeterMercury, Unit::Torr, Unit::Psi ] );
+		assert_eq!( Unit::MillimeterMercury.to_string_sym(), "mmHg" );
+		assert_eq!( Unit::Psi.to_string(), "pound per square inch" );
+		assert_eq!( Unit::from_str( "atm" ).unwrap(), Unit::Atmosphere );
+		assert_eq!( Unit::from_str( "mmHg" ).unwrap(), Unit::MillimeterMercury );
+		assert_eq!( Unit::from_str( "millimetres of mercury" ).unwrap(), Unit::MillimeterMercury );
+		assert_eq!( Unit::from_str( "Torr" ).unwrap(), Unit::Torr );
+		assert_eq!( Unit::from_str( "pounds per square inch" ).unwrap(), Unit::Psi );
+		assert_eq!( Unit::parse_with_prefix( "mTorr" ).unwrap(), ( Prefix::Milli, Unit::Torr ) );
+	}
+
+	#[test]
+	fn dimensionless_units() {
+		assert_eq!( Unit::Percent.factor(), 1e-2 );
+		assert_eq!( Unit::PerMille.base(), Unit::One );
+		assert_eq!( Unit::One.phys(), PhysicalQuantity::Dimensionless );
+		assert_eq!( Unit::for_quantity( &PhysicalQuantity::Dimensionless ), vec![ Unit::One, Unit::Percent, Unit::PerMille ] );
+		assert_eq!( Unit::Percent.to_string_sym(), "%" );
+		assert_eq!( Unit::One.to_string_sym(), "" );
+		assert_eq!( Unit::PerMille.to_string(), "per mille" );
+		assert_eq!( Unit::from_str( "%" ).unwrap(), Unit::Percent );
+		assert_eq!( Unit::from_str( "‰" ).unwrap(), Unit::PerMille );
+		assert_eq!( Unit::from_str( "percent" ).unwrap(), Unit::Percent );
+
+		assert_eq!( Unit::compound( vec![ ( Unit::Meter, 1 ), ( Unit::Meter, -1 ) ] ), Unit::One );
+		assert_eq!( Unit::compound( vec![ ( Unit::One, 1 ), ( Unit::Meter, 1 ) ] ), Unit::Meter );
+		assert_eq!( Unit::compound( vec![ ( Unit::Percent, 1 ), ( Unit::Second, -1 ) ] ).phys(), PhysicalQuantity::Frequency );
+		assert_eq!( PhysicalQuantity::Dimensionless.dimensions(), vec![] );
+	}
+
+	#[test]
+	fn phys_from_str() {
+		let all = [
+			PhysicalQuantity::Custom,
+			PhysicalQuantity::Current,
+			PhysicalQuantity::LuminousIntensity,
+			PhysicalQuantity::Temperature,
+			PhysicalQuantity::Mass,
+			PhysicalQuantity::Length,
+			PhysicalQuantity::Amount,
+			PhysicalQuantity::Time,
+			PhysicalQuantity::Pressure,
+			PhysicalQuantity::Radiation,
+			PhysicalQuantity::AbsorbedDose,
+			PhysicalQuantity::Radioactivity,
+			PhysicalQuantity::LuminousFlux,
+			PhysicalQuantity::Illuminance,
+			PhysicalQuantity::Voltage,
+			PhysicalQuantity::Power,
+			PhysicalQuantity::Energy,
+			PhysicalQuantity::Charge,
+			PhysicalQuantity::Angle,
+			PhysicalQuantity::Volume,
+			PhysicalQuantity::Frequency,
+			PhysicalQuantity::Information,
+			PhysicalQuantity::Dimensionless,
+		];
+		for phys in all {
+			assert_eq!( PhysicalQuantity::from_str( &phys.to_string() ).unwrap(), phys );
+		}
+
+		assert_eq!( PhysicalQuantity::from_str( "current" ).unwrap(), PhysicalQuantity::Current );
+		assert_eq!( PhysicalQuantity::from_str( "Temperature" ).unwrap(), PhysicalQuantity::Temperature );
+		assert_eq!( PhysicalQuantity::from_str( " time " ).unwrap(), PhysicalQuantity::Time );
+		assert_eq!( PhysicalQuantity::from_str( "amount" ).unwrap(), PhysicalQuantity::Amount );
+		assert!( matches!( PhysicalQuantity::from_str( "length²" ), Err( UnitError::QuantityParseFailure( _ ) ) ) );
+		assert!( PhysicalQuantity::from_str( "" ).is_err() );
+		assert_eq!( PhysicalQuantity::from_str( "apple" ).unwrap_err().to_string(), "Not a valid physical quantity: apple" );
+	}
+
+	#[test]
+	fn angle_units() {
+		assert_eq!( Unit::Radian.factor(), 1.0 );
+		assert_eq!( Unit::Degree.factor(), PI / 180.0 );
+		assert_eq!( Unit::Gradian.factor(), PI / 200.0 );
+		assert_eq!( Unit::Degree.base(), Unit::Radian );
+		assert_eq!( Unit::Gradian.base(), Unit::Radian );
+		assert_eq!( Unit::for_quantity( &PhysicalQuantity::Angle ), vec![ Unit::Radian, Unit::Degree, Unit::Gradian ] );
+		assert_eq!( Unit::Radian.to_string_sym(), "rad" );
+		assert_eq!( Unit::Gradian.to_string_sym(), "gon" );
+		assert_eq!( Unit::Gradian.to_string(), "gradian" );
+		assert_eq!( Unit::from_str( "radians" ).unwrap(), Unit::Radian );
+		assert_eq!( Unit::from_str( "gon" ).unwrap(), Unit::Gradian );
+		assert_eq!( Unit::parse_with_prefix( "mrad" ).unwrap(), ( Prefix::Milli, Unit::Radian ) );
+		assert!( Unit::Radian.space_before_symbol() );
+	}
+
+	#[test]
+	fn photometric_units() {
+		assert_eq!( Unit::Lumen.factor(), 1.0 );
+		assert_eq!( Unit::Lux.base(), Unit::Lux );
+		assert_eq!( Unit::Lumen.phys(), PhysicalQuantity::LuminousFlux );
+		assert_eq!( Unit::Lux.phys(), PhysicalQuantity::Illuminance );
+		assert_ne!( Unit::Lumen.phys(), Unit::Candela.phys() );
+		assert_ne!( Unit::Lumen.phys(), Unit::Lux.phys() );
+		assert_eq!( Unit::Lumen.to_string_sym(), "lm" );
+		assert_eq!( Unit::Lux.to_string_sym(), "lx" );
+		assert_eq!( Unit::Lux.to_string(), "lux" );
+		assert_eq!( Unit::from_str( "lm" ).unwrap(), Unit::Lumen );
+		assert_eq!( Unit::from_str( "lumens" ).unwrap(), Unit::Lumen );
+		assert_eq!( Unit::from_str( "lux" ).unwrap(), Unit::Lux );
+		assert_eq!( Unit::parse_with_prefix( "klx" ).unwrap(), ( Prefix::Kilo, Unit::Lux ) );
+		assert_eq!( Unit::parse_with_prefix( "mlm" ).unwrap(), ( Prefix::Milli, Unit::Lumen ) );
+		assert_eq!( Unit::compound( vec![ ( Unit::Lumen, 1 ), ( Unit::Meter, -2 ) ] ).phys(), PhysicalQuantity::Illuminance );
+	}
+
+	#[test]
+	fn unit_try_from_suggest() {
+		assert_eq!( Unit::try_from( "ampere" ).unwrap(), Unit::Ampere );
+		assert_eq!( Unit::try_from( "Metre" ).unwrap(), Unit::Meter );
+
+		match Unit::try_from( "ampre" ) {
+			Err( UnitError::ParseFailureSuggest { input, suggestions } ) => {
+				assert_eq!( input, "ampre" );
+				assert_eq!( suggestions[0], "ampere" );
+			},
+			x => panic!( "Expected suggestions, got {:?}", x ),
+		}
+		match Unit::try_from( "secnd" ) {
+			Err( UnitError::ParseFailureSuggest { suggestions, .. } ) => assert!( suggestions.contains( &"second".to_string() ) ),
+			x => panic!( "Expected suggestions, got {:?}", x ),
+		}
+		assert!( matches!( Unit::try_from( "xyzzyfoo" ), Err( UnitError::ParseFailure( _ ) ) ) );
+		assert_eq!(
+			Unit::try_from( "ampre" ).unwrap_err().to_string(),
+			"Not a valid unit: ampre (did you mean: ampere)"
+		);
+	}
+
+	#[test]
+	fn levenshtein_distance() {
+		assert_eq!( levenshtein( "", "" ), 0 );
+		assert_eq!( levenshtein( "ampre", "ampere" ), 1 );
+		assert_eq!( levenshtein( "kitten", "sitting" ), 3 );
+		assert_eq!( levenshtein( "µm", "m" ), 1 );
+		assert_eq!( levenshtein( "abc", "" ), 3 );
+	}
+
+	#[test]
+	fn information_units() {
+		assert_eq!( Unit::Bit.factor(), 1.0 );
+		assert_eq!( Unit::Byte.factor(), 8.0 );
+		assert_eq!( Unit::Byte.base(), Unit::Bit );
+		assert_eq!( Unit::Byte.phys(), PhysicalQuantity::Information );
+		assert_eq!( Unit::for_quantity( &PhysicalQuantity::Information ), vec![ Unit::Bit, Unit::Byte ] );
+		assert_eq!( Unit::Byte.to_string_sym(), "B" );
+		assert_eq!( Unit::Bit.to_string_sym(), "bit" );
+		assert_eq!( Unit::Byte.to_string(), "byte" );
+		assert_eq!( Unit::from_str( "B" ).unwrap(), Unit::Byte );
+		assert_eq!( Unit::from_str( "bytes" ).unwrap(), Unit::Byte );
+		assert_eq!( Unit::from_str( "bits" ).unwrap(), Unit::Bit );
+		assert_eq!( Unit::parse_with_prefix( "kB" ).unwrap(), ( Prefix::Kilo, Unit::Byte ) );
+		assert_eq!( Unit::parse_with_prefix( "Gbit" ).unwrap(), ( Prefix::Giga, Unit::Bit ) );
+		assert_eq!( Unit::from_str( "bar" ).unwrap(), Unit::Bar );
+		assert_eq!( PhysicalQuantity::Information.minimum(), Some( 0.0 ) );
+	}
+
+	#[test]
+	fn energy_units() {
+		assert_eq!( Unit::Electronvolt.factor(), 1.602176634e-19 );
+		assert_eq!( Unit::Calorie.factor(), 4.184 );
+		assert_eq!( Unit::Electronvolt.base(), Unit::Joule );
+		assert_eq!( Unit::Calorie.base(), Unit::Joule );
+		assert_eq!( Unit::for_quantity( &PhysicalQuantity::Energy ), vec![ Unit::Joule, Unit::Electronvolt, Unit::Calorie ] );
+		assert_eq!( Unit::Electronvolt.to_string_sym(), "eV" );
+		assert_eq!( Unit::Calorie.to_string_sym(), "cal" );
+		assert_eq!( Unit::Electronvolt.to_string(), "electronvolt" );
+		assert_eq!( Unit::from_str( "eV" ).unwrap(), Unit::Electronvolt );
+		assert_eq!( Unit::from_str( "calories" ).unwrap(), Unit::Calorie );
+		assert_eq!( Unit::parse_with_prefix( "keV" ).unwrap(), ( Prefix::Kilo, Unit::Electronvolt ) );
+		assert_eq!( Unit::parse_with_prefix( "MeV" ).unwrap(), ( Prefix::Mega, Unit::Electronvolt ) );
+		assert_eq!( Unit::parse_with_prefix( "kcal" ).unwrap(), ( Prefix::Kilo, Unit::Calorie ) );
+	}
+
+	#[test]
+	fn radiation_units() {
+		assert_eq!( Unit::Gray.factor(), 1.0 );
+		assert_eq!( Unit::Becquerel.base(), Unit::Becquerel );
+		assert_eq!( Unit::Gray.phys(), PhysicalQuantity::AbsorbedDose );
+		assert_eq!( Unit::Becquerel.phys(), PhysicalQuantity::Radioactivity );
+		assert_ne!( Unit::Gray.phys(), Unit::Sievert.phys() );
+		assert_ne!( Unit::Becquerel.phys(), Unit::Hertz.phys() );
+		assert_eq!( Unit::Gray.phys().dimensions(), Unit::Sievert.phys().dimensions() );
+		assert_eq!( Unit::Becquerel.phys().dimensions(), Unit::Hertz.phys().dimensions() );
+		assert_eq!( Unit::Gray.to_string_sym(), "Gy" );
+		assert_eq!( Unit::Becquerel.to_string(), "becquerel" );
+		assert_eq!( Unit::from_str( "Gy" ).unwrap(), Unit::Gray );
+		assert_eq!( Unit::from_str( "becquerels" ).unwrap(), Unit::Becquerel );
+		assert_eq!( Unit::parse_with_prefix( "kBq" ).unwrap(), ( Prefix::Kilo, Unit::Becquerel ) );
+		assert_eq!( Unit::parse_with_prefix( "mGy" ).unwrap(), ( Prefix::Milli, Unit::Gray ) );
+	}
+
+	#[test]
+	fn frequency_units() {
+		assert_eq!( Unit::Hertz.factor(), 1.0 );
+		assert_eq!( Unit::Hertz.phys(), PhysicalQuantity::Frequency );
+		assert_eq!( Unit::compound( vec![ ( Unit::Second, -1 ) ] ).phys(), PhysicalQuantity::Frequency );
+		assert_eq!( Unit::compound( vec![ ( Unit::Hertz, 1 ), ( Unit::Second, 1 ) ] ).phys(), PhysicalQuantity::Dimensionless );
+		assert_eq!( Unit::Hertz.to_string_sym(), "Hz" );
+		assert_eq!( Unit::from_str( "hz" ).unwrap(), Unit::Hertz );
+	}
+
+	#[test]
+	fn unit_parse_with_prefix() {
+		assert_eq!( Unit::parse_with_prefix( "km" ).unwrap(), ( Prefix::Kilo, Unit::Meter ) );
+		assert_eq!( Unit::parse_with_prefix( "µA" ).unwrap(), ( Prefix::Micro, Unit::Ampere ) );
+		assert_eq!( Unit::parse_with_prefix( "MPa" ).unwrap(), ( Prefix::Mega, Unit::Pascal ) );
+		assert_eq!( Unit::parse_with_prefix( "kg" ).unwrap(), ( Prefix::Nothing, Unit::Kilogram ) );
+		assert_eq!( Unit::parse_with_prefix( "mg" ).unwrap(), ( Prefix::Milli, Unit::Gram ) );
+
+		// Alternative spellings of micro
+		assert_eq!( Unit::parse_with_prefix( "uA" ).unwrap(), ( Prefix::Micro, Unit::Ampere ) );
+		assert_eq!( Unit::parse_with_prefix( "um" ).unwrap(), ( Prefix::Micro, Unit::Meter ) );
+		assert_eq!( Unit::parse_with_prefix( "\u{03BC}m" ).unwrap(), ( Prefix::Micro, Unit::Meter ) );
+		assert_eq!( Unit::parse_with_prefix( "ug" ).unwrap(), ( Prefix::Micro, Unit::Gram ) );
+
+		// Ambiguous strings
+		assert_eq!( Unit::parse_with_prefix( "m" ).unwrap(), ( Prefix::Nothing, Unit::Meter ) );
+		assert_eq!( Unit::parse_with_prefix( "mm" ).unwrap(), ( Prefix::Milli, Unit::Meter ) );
+		assert_eq!( Unit::parse_with_prefix( "mol" ).unwrap(), ( Prefix::Nothing, Unit::Mole ) );
+		assert_eq!( Unit::parse_with_prefix( "mmol" ).unwrap(), ( Prefix::Milli, Unit::Mole ) );
+		assert_eq!( Unit::parse_with_prefix( "pc" ).unwrap(), ( Prefix::Nothing, Unit::Parsec ) );
+		assert_eq!( Unit::parse_with_prefix( "dam" ).unwrap(), ( Prefix::Deca, Unit::Meter ) );
+		assert_eq!( Unit::parse_with_prefix( "dm" ).unwrap(), ( Prefix::Deci, Unit::Meter ) );
+
+		// Symbols are case-sensitive
+		assert_eq!( Unit::parse_with_prefix( "pA" ).unwrap(), ( Prefix::Pico, Unit::Ampere ) );
+		assert_eq!( Unit::parse_with_prefix( "pC" ).unwrap(), ( Prefix::Pico, Unit::Coulomb ) );
+		assert_eq!( Unit::parse_with_prefix( "EV" ).unwrap(), ( Prefix::Exa, Unit::Volt ) );
+		assert_eq!( Unit::parse_with_prefix( "Min" ).unwrap(), ( Prefix::Mega, Unit::Inch ) );
+		assert_eq!( Unit::parse_with_prefix( "a" ).unwrap(), ( Prefix::Nothing, Unit::Year ) );
+		assert_eq!( Unit::parse_with_prefix( "ml" ).unwrap(), ( Prefix::Milli, Unit::Liter ) );
+
+		assert_eq!( Unit::parse_with_prefix( "meter" ).unwrap(), ( Prefix::Nothing, Unit::Meter ) );
+		for s in [ "", "xm", "kkm", "kxyz", "k m", "Mb", "kb", "KG" ] {
+			assert!( Unit::parse_with_prefix( s ).is_err(), "{}", s );
+		}
+	}
+
+	#[test]
+	fn unit_family() {
+		assert_eq!( Unit::Kilogram.family(), vec![ Unit::Gram, Unit::Kilogram, Unit::Tonne ] );
+		assert_eq!( Unit::Tonne.family(), Unit::Gram.family() );
+		assert_eq!( Unit::Mile.family(), vec![ Unit::Inch, Unit::Foot, Unit::Yard, Unit::Mile ] );
+		assert_eq!( Unit::Meter.family(), vec![ Unit::Meter ] );
+		assert_eq!( Unit::Celsius.family(), vec![ Unit::Celsius ] );
+
+		for unit in Unit::Second.family() {
+			assert_eq!( unit.phys(), Unit::Second.phys() );
+		}
+		for win in Unit::Ounce.family().windows( 2 ) {
+			assert!( win[0].factor() < win[1].factor() );
+		}
+	}
+
+	#[test]
+	fn unit_compound() {
+		let area = Unit::compound( vec![ ( Unit::Meter, 1 ), ( Unit::Meter, 1 ) ] );
+		assert_eq!( area, Unit::Compound( vec![ ( Unit::Meter, 2 ) ] ) );
+		assert_eq!( area.to_string_sym(), "m²".to_string() );
+		assert_eq!( area.to_string(), "meter²".to_string() );
+		assert_eq!( area.phys(), PhysicalQuantity::Compound( vec![ ( PhysicalQuantity::Length, 2 ) ] ) );
+
+		let speed = Unit::compound( vec![ ( Unit::Meter, 1 ), ( Unit::Second, -1 ) ] );
+		assert_eq!( speed.to_string_sym(), "m·s⁻¹".to_string() );
+		assert_eq!( speed.phys(), Unit::compound( vec![ ( Unit::Second, -1 ), ( Unit::Parsec, 1 ) ] ).phys() );
+
+		assert_eq!( Unit::compound( vec![ ( area.clone(), 1 ), ( Unit::Meter, -1 ) ] ), Unit::Meter );
+		assert_eq!( Unit::compound( vec![ ( Unit::Second, 1 ), ( Unit::Second, -1 ) ] ), Unit::One );
+
+		// Only `product()` merges different units of the same physical quantity.
+		assert_eq!( Unit::compound( vec![ ( Unit::Tonne, 1 ), ( Unit::Kilogram, 1 ) ] ).to_string_sym(), "t·kg".to_string() );
+		assert_eq!( Unit::product( vec![ ( Unit::Tonne, 1 ), ( Unit::Kilogram, 1 ) ] ).to_string_sym(), "t²".to_string() );
+	}
+
+	#[test]
+	fn unit_compound_factor() {
+		assert_eq!( Unit::compound( vec![ ( Unit::Tonne, 2 ) ] ).factor(), 1e6 );
+		assert_eq!( Unit::compound( vec![ ( Unit::Bar, 1 ), ( Unit::Gram, -1 ) ] ).factor(), 1e8 );
+		assert_eq!( Unit::compound( vec![ ( Unit::Tonne, 1 ), ( Unit::Second, -1 ) ] ).base(), Unit::compound( vec![ ( Unit::Kilogram, 1 ), ( Unit::Second, -1 ) ] ) );
+	}
+
+	#[cfg( feature = "tex" )]
+	#[test]
+	fn dimensionless_latex() {
+		assert_eq!( Unit::Percent.to_latex_sym( &TexOptions::none() ), r"\percent".to_string() );
+		assert_eq!( Unit::Percent.latex_declaration(), None );
+		assert_eq!( Unit::PerMille.latex_declaration().unwrap(), r"\DeclareSIUnit{\permille}{\text{\textperthousand}}".to_string() );
+		assert_eq!( Unit::One.to_latex_sym( &TexOptions::none() ), "".to_string() );
+	}
+
+	#[cfg( feature = "tex" )]
+	#[test]
+	fn unit_compound_latex() {
+		assert_eq!( Unit::compound( vec![ ( Unit::Meter, 2 ) ] ).to_latex_sym( &TexOptions::none() ), r"\meter\squared".to_string() );
+		assert_eq!( Unit::compound( vec![ ( Unit::Meter, 1 ), ( Unit::Second, -2 ) ] ).to_latex_sym( &TexOptions::none() ), r"\meter\per\second\squared".to_string() );
+		assert_eq!( Unit::compound( vec![ ( Unit::Second, -1 ) ] ).to_latex_sym( &TexOptions::none() ), r"\second\tothe{-1}".to_string() );
+	}
+
+	#[cfg( feature = "tex" )]
+	#[test]
+	fn unit_from_latex_sym() {
+		for unit in Unit::ALL {
+			assert_eq!( Unit::from_latex_sym( &unit.to_latex_sym( &TexOptions::none() ) ).unwrap(), unit );
+		}
+		for unit in [
+			Unit::compound( vec![ ( Unit::Meter, 2 ) ] ),
+			Unit::compound( vec![ ( Unit::Meter, 1 ), ( Unit::Second, -2 ) ] ),
+			Unit::compound( vec![ ( Unit::Second, -1 ) ] ),
+			Unit::compound( vec![ ( Unit::Kilogram, 1 ), ( Unit::Meter, 2 ), ( Unit::Second, -3 ) ] ),
+			Unit::compound( vec![ ( Unit::Joule, 1 ), ( Unit::Mole, -1 ), ( Unit::Kelvin, -1 ) ] ),
+			Unit::compound( vec![ ( Unit::Meter, 5 ), ( Unit::Second, -4 ) ] ),
+		] {
+			assert_eq!( Unit::from_latex_sym( &unit.to_latex_sym( &TexOptions::none() ) ).unwrap(), unit );
+		}
+
+		assert_eq!( Unit::from_latex_sym( r"\second\tothe{-1}" ).unwrap(), Unit::compound( vec![ ( Unit::Second, -1 ) ] ) );
+		assert_eq!( Unit::from_latex_sym( "" ).unwrap(), Unit::One );
+		for s in [ "meter", r"\kilo\meter", r"\meters", r"\squared", r"\meter\per", r"\meter\per\per\second", r"\meter\squared\cubed", r"\meter\tothe{x}" ] {
+			assert!( Unit::from_latex_sym( s ).is_err(), "{}", s );
+		}
+	}
+
+	#[test]
+	fn electrical_units() {
+		for unit in [ Unit::Volt, Unit::Watt, Unit::Joule, Unit::Coulomb ] {
+			assert_eq!( Unit::from_str( &unit.to_string_sym() ).unwrap(), unit );
+			assert_eq!( Unit::from_str( &unit.to_string() ).unwrap(), unit );
+		}
+		assert_ne!( Unit::Volt.phys(), Unit::Watt.phys() );
+		assert_ne!( Unit::Joule.phys(), Unit::Coulomb.phys() );
+	}
+
+	#[cfg( all( feature = "i18n", feature = "tex" ) )]
+	#[test]
+	fn unit_latex_locale() {
+		use unic_langid::langid;
+
+		let english = langid!( "en-US" );
+		let german = langid!( "de-DE" );
+		let french = langid!( "fr-FR" );
+
+		assert_eq!( Unit::Meter.to_latex_locale( &german, &TexOptions::new() ), r"\text{Meter}" );
+		assert_eq!( Unit::Kilogram.to_latex_locale( &german, &TexOptions::new() ), r"\text{Kilogramm}" );
+		assert_eq!( Unit::Ampere.to_latex_locale( &english, &TexOptions::none() ), r"\text{ampere}" );
+		assert_eq!( Unit::Joule.to_latex_locale( &french, &TexOptions::new() ), format!( r"\text{{{}}}", Unit::Joule.to_string_locale( &french ) ) );
+		assert_eq!( Unit::Custom( "".to_string() ).to_latex_locale( &german, &TexOptions::new() ), "" );
+		assert_ne!( Unit::Meter.to_latex_locale( &german, &TexOptions::new() ), Unit::Meter.to_latex_sym( &TexOptions::new() ) );
+	}
+
+	#[cfg( feature = "tex" )]
+	#[test]
+	fn electrical_units_latex() {
+		assert_eq!( Unit::Volt.to_latex_sym( &TexOptions::none() ), r"\volt".to_string() );
+		assert_eq!( Unit::Watt.to_latex_sym( &TexOptions::none() ), r"\watt".to_string() );
+		assert_eq!( Unit::Joule.to_latex_sym( &TexOptions::none() ), r"\joule".to_string() );
+		assert_eq!( Unit::Coulomb.to_latex_sym( &TexOptions::none() ), r"\coulomb".to_string() );
+	}
 }